@@ -1,21 +1,34 @@
+use std::cmp::Ordering;
+
 use cosmwasm_std::{
-    BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, from_binary, MessageInfo, Order,
-    Reply, Response, StdError, StdResult, Storage, SubMsg, to_binary, Uint128, WasmMsg,
+    Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, from_binary, MessageInfo, Order,
+    Reply, Response, StdError, StdResult, Storage, SubMsg, Timestamp, to_binary, Uint128, WasmMsg,
 };
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{entry_point, Timestamp};
+use cosmwasm_std::entry_point;
 
+use cw0::{calc_range_start, calc_range_start_string};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
-use cw2::set_contract_version;
-use cw_storage_plus::Map;
+use cw2::{get_contract_version, set_contract_version};
+use cw0::maybe_addr;
+use fees::{split_fees, HUNDRED_PERCENT, NINETY_NINE_NINE_PERCENT};
+use cw_storage_plus::{Bound, Map, U64Key};
+use cw721::OwnerOfResponse;
+use cw721_base::{ExecuteMsg as Cw721ExecuteMsg, Extension, MintMsg, QueryMsg as Cw721QueryMsg};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, ProxyQueryMsgs, QueryMsg, ReceivedMsg};
+use crate::event::{
+    AutoStakeSetEvent, BondCreatedEvent, BondReleasedEvent, ClubAssignedEvent, ClubBoughtEvent, ClubListedEvent, ClubListingCancelledEvent,
+    ClubReleasedEvent, PauseSetEvent, RewardAddedEvent, RewardClaimedEvent, RewardDistributedEvent, RewardsCompoundedEvent, StakeAddedEvent,
+    StakeMovedEvent, StakingEvent,
+};
+use crate::fee_oracle::{FeeOracle, ProxyFeeOracle};
+use crate::msg::{ClubInfoResponse, EarlyUnbondQuoteResponse, ExecuteMsg, FeeQuoteResponse, InstantiateMsg, MigrateMsg, QueryMsg, ReceivedMsg};
 use crate::state::{
-    CLUB_BONDING_DETAILS, CLUB_OWNERSHIP_DETAILS, CLUB_PREVIOUS_OWNER_DETAILS, CLUB_REWARD_NEXT_TIMESTAMP, CLUB_STAKING_DETAILS,
-    CLUB_STAKING_SNAPSHOT, ClubBondingDetails, ClubOwnershipDetails,
-    ClubPreviousOwnerDetails, ClubStakingDetails, Config, CONFIG, REWARD, REWARD_GIVEN_IN_CURRENT_TIMESTAMP,
-    WINNING_CLUB_DETAILS_SNAPSHOT, WinningClubDetails,
+    CLUB_BONDING_DETAILS, CLUB_LISTINGS, CLUB_OWNERSHIP_DETAILS, CLUB_PREVIOUS_OWNER_DETAILS, CLUB_REGISTRY, CLUB_REWARD_INDEX, CLUB_REWARD_NEXT_TIMESTAMP, club_staking_details,
+    CLUB_RANKING, CLUB_REWARD_HISTORY, CLUB_STAKERS, CLUB_STAKE_HISTORY, CLUB_STAKING_SNAPSHOT, ClubBondingDetails, ClubListing, ClubOwnershipDetails, ClubProfile, ClubRewardRecord, ClubStakeStanding,
+    ClubPreviousOwnerDetails, ClubStakingDetails, Config, CONFIG, DEFAULT_MOVE_STAKE_COOLDOWN, EarlyUnbondPolicy, LEGACY_CLUB_STAKING_DETAILS, LEGACY_CONFIG, LEGACY_REWARD_PERIOD_HISTORY, LegacyRewardPeriodRecord, LAST_STAKE_MOVE, PAUSE_STATUS, PauseStatus, PenaltyCurve, PenaltyDestination, STAKE_ID, ADMIN, PENDING_ADMIN, Role, ROLES, REWARD, REWARD_GIVEN_IN_CURRENT_TIMESTAMP, RewardSplitPolicy,
+    REWARD_DISTRIBUTION_PROGRESS, RewardDistributionProgress, REWARD_PERIOD, REWARD_PERIOD_HISTORY, RewardPeriodRecord, StakerRewardRecord, WINNING_CLUB_DETAILS_HISTORY, WINNING_CLUB_DETAILS_SNAPSHOT, WinningClubDetails, WinningClubStrategy,
 };
 
 // version info for migration info
@@ -28,7 +41,6 @@ const IMMEDIATE_WITHDRAWAL: bool = true;
 const NO_IMMEDIATE_WITHDRAWAL: bool = false;
 const DONT_CHANGE_AUTO_STAKE_SETTING: bool = false;
 const SET_AUTO_STAKE: bool = true;
-const MAX_UFURY_COUNT: i128 = 420000000000000;
 // Reward to club owner for buying - 0 tokens
 const CLUB_BUYING_REWARD_AMOUNT: u128 = 0u128;
 
@@ -47,12 +59,17 @@ const CLUB_STAKING_DURATION: u64 = 0u64;
 // const CLUB_BONDING_DURATION: u64 = 3600u64;
 // - now part of instantiation msg.bonding_duration
 
-const HUNDRED_PERCENT: u128 = 10000u128;
-const NINETY_NINE_NINE_PERCENT: u128 = 9990u128;
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+// bounds on the length of a normalized club name
+const MIN_CLUB_NAME_LENGTH: usize = 3;
+const MAX_CLUB_NAME_LENGTH: usize = 32;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
@@ -63,6 +80,10 @@ pub fn instantiate(
     if next_reward_time.seconds() == 0u64 {
         next_reward_time = _env.block.time.minus_seconds(1);
     }
+    let reward_split_policy = msg.reward_split_policy.unwrap_or_default();
+    validate_reward_split_policy(&reward_split_policy)?;
+    let winning_club_strategy = msg.winning_club_strategy.unwrap_or_default();
+    validate_winning_club_strategy(&winning_club_strategy)?;
     let config = Config {
         admin_address: deps.api.addr_validate(&msg.admin_address)?,
         minting_contract_address: deps.api.addr_validate(&msg.minting_contract_address)?,
@@ -80,15 +101,21 @@ pub fn instantiate(
         transaction_fees: msg.transaction_fees,
         control_fees: msg.control_fees,
         max_bonding_limit_per_user: msg.max_bonding_limit_per_user,
-        usdc_ibc_symbol: msg.usdc_ibc_symbol
+        usdc_ibc_symbol: msg.usdc_ibc_symbol,
+        reward_split_policy: reward_split_policy,
+        winning_club_strategy: winning_club_strategy,
+        fury_fee_discount: Uint128::zero(),
+        early_unbond_policy: EarlyUnbondPolicy::default(),
+        move_stake_cooldown: DEFAULT_MOVE_STAKE_COOLDOWN,
+        club_nft_address: None,
     };
     CONFIG.save(deps.storage, &config)?;
+    // the admin holds the roles until it grants them to other wallets
+    ADMIN.set(deps.branch(), Some(config.admin_address.clone()))?;
+    ROLES.save(deps.storage, (Role::Distributor.as_str(), &config.admin_address), &Empty {})?;
+    ROLES.save(deps.storage, (Role::Assigner.as_str(), &config.admin_address), &Empty {})?;
 
     CLUB_REWARD_NEXT_TIMESTAMP.save(deps.storage, &config.club_reward_next_timestamp)?;
-    println!(
-        "now = {:?} next_timestamp = {:?} periodicity = {:?}",
-        _env.block.time, config.club_reward_next_timestamp, config.reward_periodicity
-    );
     Ok(Response::default())
 }
 
@@ -98,6 +125,19 @@ pub fn execute(
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    check_not_paused(deps.storage, &msg)?;
+    check_no_reward_distribution_in_progress(deps.storage, &msg)?;
+    let fees = collect_native_fees(deps.as_ref(), &info, &msg)?;
+    let response = execute_msg(deps, env, info, msg)?;
+    Ok(response.add_submessages(fees.messages).add_attributes(fees.attributes))
+}
+
+fn execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::StakeOnAClub {
@@ -108,6 +148,13 @@ pub fn execute(
         } => {
             stake_on_a_club(deps, env, info, staker, club_name, amount, auto_stake)
         }
+        ExecuteMsg::MoveStake {
+            from_club,
+            to_club,
+            amount,
+        } => {
+            move_stake(deps, env, info, from_club, to_club, amount)
+        }
         ExecuteMsg::AssignStakesToAClub {
             stake_list,
             club_name
@@ -135,6 +182,24 @@ pub fn execute(
         ExecuteMsg::ReleaseClub { owner, club_name } => {
             release_club(deps, env, info, owner, club_name)
         }
+        ExecuteMsg::ListClub {
+            owner,
+            club_name,
+            asking_price,
+            expiry,
+        } => {
+            list_club(deps, env, info, owner, club_name, asking_price, expiry)
+        }
+        ExecuteMsg::CancelClubListing { owner, club_name } => {
+            cancel_club_listing(deps, info, owner, club_name)
+        }
+        ExecuteMsg::BuyListedClub {
+            buyer,
+            club_name,
+            auto_stake,
+        } => {
+            buy_listed_club(deps, env, info, buyer, club_name, auto_stake)
+        }
         ExecuteMsg::ClaimOwnerRewards { owner, club_name } => {
             claim_owner_rewards(deps, env, info, owner, club_name)
         }
@@ -155,37 +220,417 @@ pub fn execute(
             amount,
             immediate_withdrawal,
         ),
-        ExecuteMsg::CalculateAndDistributeRewards {
-            staker_list,
+        ExecuteMsg::ClaimMatured {
             club_name,
-            is_first_batch,
-            is_final_batch,
-        } => {
-            calculate_and_distribute_rewards(deps, env, info, staker_list, club_name, is_first_batch, is_final_batch)
+            start_after,
+            limit,
+        } => claim_matured(deps, env, info, club_name, start_after, limit),
+        ExecuteMsg::CalculateAndDistributeRewards { limit } => {
+            calculate_and_distribute_rewards(deps, env, info, limit)
         }
         ExecuteMsg::ClaimStakerRewards { staker, club_name } => {
             claim_staker_rewards(deps, info, staker, club_name)
         }
+        ExecuteMsg::SetAutoStake { club_name, auto_stake } => {
+            set_auto_stake(deps, info, club_name, auto_stake)
+        }
+        ExecuteMsg::CompoundRewards { club_name } => compound_rewards(deps, info, club_name),
         ExecuteMsg::IncreaseRewardAmount {
             reward_from,
             amount,
         } => {
             increase_reward_amount(deps, env, info, reward_from, amount)
         }
+        ExecuteMsg::UpdateRewardPolicy { reward_split_policy } => {
+            update_reward_policy(deps, info, reward_split_policy)
+        }
+        ExecuteMsg::UpdateWinningClubStrategy { winning_club_strategy } => {
+            update_winning_club_strategy(deps, info, winning_club_strategy)
+        }
+        ExecuteMsg::SetClubRanking { club_ranking } => {
+            set_club_ranking(deps, info, club_ranking)
+        }
+        ExecuteMsg::SetPaused {
+            buy,
+            stake,
+            withdraw,
+            claim,
+            distribute,
+        } => {
+            set_paused(deps, info, buy, stake, withdraw, claim, distribute)
+        }
+        ExecuteMsg::ProposeNewAdmin { new_admin } => {
+            propose_new_admin(deps, info, new_admin)
+        }
+        ExecuteMsg::AcceptAdmin {} => accept_admin(deps, info),
+        ExecuteMsg::GrantRole { role, address } => {
+            grant_role(deps, info, role, address)
+        }
+        ExecuteMsg::RevokeRole { role, address } => {
+            revoke_role(deps, info, role, address)
+        }
+        ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount } => {
+            update_fury_fee_discount(deps, info, fury_fee_discount)
+        }
+        ExecuteMsg::UpdateEarlyUnbondPolicy { early_unbond_policy } => {
+            update_early_unbond_policy(deps, info, early_unbond_policy)
+        }
+        ExecuteMsg::UpdateMoveStakeCooldown { move_stake_cooldown } => {
+            update_move_stake_cooldown(deps, info, move_stake_cooldown)
+        }
+        ExecuteMsg::RegisterClub { club_name, profile } => {
+            register_club(deps, info, club_name, profile)
+        }
+        ExecuteMsg::UpdateClubProfile { club_name, profile } => {
+            update_club_profile(deps, info, club_name, profile)
+        }
+        ExecuteMsg::UpdateClubNftAddress {
+            club_nft_address,
+            start_after,
+            limit,
+        } => update_club_nft_address(deps, info, club_nft_address, start_after, limit),
+        ExecuteMsg::Receive(message) => received_message(deps, env, info, message),
+    }
+}
+
+/// Checks the fee sent along with msg in the stable denom and forwards it to the platform fees collector
+fn collect_native_fees(deps: Deps, info: &MessageInfo, msg: &ExecuteMsg) -> Result<Response, ContractError> {
+    let required_ust_fees = query_platform_fees(deps, to_binary(msg)?)?;
+    if required_ust_fees.is_zero() {
+        return Ok(Response::new());
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let mut fees = Uint128::zero();
+    for fund in info.funds.clone() {
+        if fund.denom == config.usdc_ibc_symbol {
+            fees = fees.checked_add(fund.amount).map_err(StdError::from)?;
+        }
+    }
+    let adjusted_ust_fees =
+        required_ust_fees.multiply_ratio(NINETY_NINE_NINE_PERCENT, HUNDRED_PERCENT);
+    if fees < adjusted_ust_fees {
+        return Err(ContractError::InsufficientFees {
+            required: required_ust_fees,
+            received: fees,
+        });
+    }
+    let send_bank: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: config.platform_fees_collector_wallet.into_string(),
+        amount: info.funds.clone(),
+    });
+    return Ok(Response::new()
+        .add_message(send_bank)
+        .add_attribute("fee_asset", config.usdc_ibc_symbol)
+        .add_attribute("fee_amount", fees.to_string()));
+}
+
+/// Checks the fee paid in fury for msg and forwards it to the platform fees collector
+fn collect_fury_fees(deps: Deps, fury_fees: Uint128, msg: &ExecuteMsg) -> Result<Response, ContractError> {
+    let required_fury_fees = query_fury_fees(deps, to_binary(msg)?)?;
+    let adjusted_fury_fees =
+        required_fury_fees.multiply_ratio(NINETY_NINE_NINE_PERCENT, HUNDRED_PERCENT);
+    if fury_fees < adjusted_fury_fees {
+        return Err(ContractError::InsufficientFuryFees {
+            required: required_fury_fees,
+            received: fury_fees,
+        });
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let mut rsp = Response::new();
+    if !fury_fees.is_zero() {
+        let transfer_msg = Cw20ExecuteMsg::Transfer {
+            recipient: config.platform_fees_collector_wallet.into_string(),
+            amount: fury_fees,
+        };
+        rsp = rsp.add_message(WasmMsg::Execute {
+            contract_addr: config.minting_contract_address.to_string(),
+            msg: to_binary(&transfer_msg)?,
+            funds: vec![],
+        });
+    }
+    return Ok(rsp
+        .add_attribute("fee_asset", "fury")
+        .add_attribute("fee_amount", fury_fees.to_string()));
+}
+
+fn assert_admin(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !ADMIN.is_admin(deps, sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn has_role(storage: &dyn Storage, role: Role, address: &Addr) -> bool {
+    ROLES.has(storage, (role.as_str(), address))
+}
+
+fn propose_new_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_admin: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let new_admin_addr = deps.api.addr_validate(&new_admin)?;
+    PENDING_ADMIN.save(deps.storage, &new_admin_addr)?;
+    return Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("admin", info.sender)
+        .add_attribute("pending_admin", new_admin_addr));
+}
+
+fn accept_admin(
+    mut deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending_admin = PENDING_ADMIN.may_load(deps.storage)?;
+    if pending_admin != Some(info.sender.clone()) {
+        return Err(ContractError::NotPendingAdmin {
+            address: info.sender.to_string(),
+        });
+    }
+    ADMIN.set(deps.branch(), Some(info.sender.clone()))?;
+    PENDING_ADMIN.remove(deps.storage);
+    let mut config = CONFIG.load(deps.storage)?;
+    config.admin_address = info.sender.clone();
+    CONFIG.save(deps.storage, &config)?;
+    return Ok(Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("admin", info.sender));
+}
+
+fn grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let addr = deps.api.addr_validate(&address)?;
+    ROLES.save(deps.storage, (role.as_str(), &addr), &Empty {})?;
+    return Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", addr));
+}
+
+fn revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let addr = deps.api.addr_validate(&address)?;
+    ROLES.remove(deps.storage, (role.as_str(), &addr));
+    return Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", addr));
+}
+
+/// Rejects the message when the administrator has paused its operation.
+/// Cancelling a listing, releasing a club and admin settings are never paused
+fn check_not_paused(storage: &dyn Storage, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    let pause_status = PAUSE_STATUS.may_load(storage)?.unwrap_or_default();
+    let (paused, operation) = match msg {
+        ExecuteMsg::BuyAClub { .. }
+        | ExecuteMsg::AssignAClub { .. }
+        | ExecuteMsg::ListClub { .. }
+        | ExecuteMsg::BuyListedClub { .. } => (pause_status.buy, "buy"),
+        ExecuteMsg::StakeOnAClub { .. }
+        | ExecuteMsg::MoveStake { .. }
+        | ExecuteMsg::SetAutoStake { .. }
+        | ExecuteMsg::CompoundRewards { .. }
+        | ExecuteMsg::AssignStakesToAClub { .. } => (pause_status.stake, "stake"),
+        ExecuteMsg::StakeWithdrawFromAClub { .. }
+        | ExecuteMsg::ClaimMatured { .. } => (pause_status.withdraw, "withdraw"),
+        ExecuteMsg::ClaimOwnerRewards { .. }
+        | ExecuteMsg::ClaimPreviousOwnerRewards { .. }
+        | ExecuteMsg::ClaimStakerRewards { .. } => (pause_status.claim, "claim"),
+        ExecuteMsg::IncreaseRewardAmount { .. }
+        | ExecuteMsg::CalculateAndDistributeRewards { .. } => (pause_status.distribute, "distribute"),
+        _ => (false, ""),
+    };
+    if paused {
+        return Err(ContractError::Paused {
+            operation: operation.to_string(),
+        });
     }
+    Ok(())
+}
+
+/// A state migration, applied when migrating from a version older than `version`
+struct MigrationStep {
+    version: &'static str,
+    migrate: fn(DepsMut) -> Result<(), ContractError>,
 }
 
+/// Migration steps in the order they are applied
+const MIGRATION_STEPS: &[MigrationStep] = &[
+    MigrationStep { version: "0.10.0", migrate: migrate_to_0_10_0 },
+    MigrationStep { version: "0.11.0", migrate: migrate_to_0_11_0 },
+    MigrationStep { version: "0.12.0", migrate: migrate_to_0_12_0 },
+    MigrationStep { version: "0.13.0", migrate: migrate_to_0_13_0 },
+];
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
-    Ok(Response::default())
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous = get_contract_version(deps.storage)?;
+    if previous.contract != CONTRACT_NAME {
+        return Err(ContractError::CannotMigrate {
+            previous_contract: previous.contract,
+        });
+    }
+    let previous_version = parse_version(&previous.version)?;
+    if previous_version > parse_version(CONTRACT_VERSION)? {
+        return Err(ContractError::CannotMigrateVersion {
+            previous_version: previous.version,
+            new_version: CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    let mut rsp = Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("previous_version", previous.version.clone());
+    for step in MIGRATION_STEPS {
+        if previous_version < parse_version(step.version)? {
+            (step.migrate)(deps.branch())?;
+            rsp = rsp.add_attribute("migration_step", step.version);
+        }
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(rsp.add_attribute("new_version", CONTRACT_VERSION))
+}
+
+/// Splits a version like 0.10.0 into its numbers, which compare in version order
+fn parse_version(version: &str) -> StdResult<Vec<u64>> {
+    version
+        .split('.')
+        .map(|part| {
+            part.parse::<u64>().map_err(|_| {
+                StdError::generic_err(format!("Invalid contract version {}", version))
+            })
+        })
+        .collect()
+}
+
+/// Backfills the reward split policy and winning club strategy in Config,
+/// and moves the stakes to club_staking_details()
+fn migrate_to_0_10_0(deps: DepsMut) -> Result<(), ContractError> {
+    let legacy_config = LEGACY_CONFIG.load(deps.storage)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            admin_address: legacy_config.admin_address,
+            minting_contract_address: legacy_config.minting_contract_address,
+            astro_proxy_address: legacy_config.astro_proxy_address,
+            club_fee_collector_wallet: legacy_config.club_fee_collector_wallet,
+            club_reward_next_timestamp: legacy_config.club_reward_next_timestamp,
+            reward_periodicity: legacy_config.reward_periodicity,
+            club_price: legacy_config.club_price,
+            bonding_duration: legacy_config.bonding_duration,
+            owner_release_locking_duration: legacy_config.owner_release_locking_duration,
+            platform_fees_collector_wallet: legacy_config.platform_fees_collector_wallet,
+            platform_fees: legacy_config.platform_fees,
+            transaction_fees: legacy_config.transaction_fees,
+            control_fees: legacy_config.control_fees,
+            max_bonding_limit_per_user: legacy_config.max_bonding_limit_per_user,
+            usdc_ibc_symbol: legacy_config.usdc_ibc_symbol,
+            reward_split_policy: RewardSplitPolicy::default(),
+            winning_club_strategy: WinningClubStrategy::default(),
+            fury_fee_discount: Uint128::zero(),
+            early_unbond_policy: EarlyUnbondPolicy::default(),
+            move_stake_cooldown: DEFAULT_MOVE_STAKE_COOLDOWN,
+            club_nft_address: None,
+        },
+    )?;
+    migrate_club_staking_details(deps.storage)?;
+    Ok(())
 }
-pub fn uusd(
-    deps: &DepsMut,
-) -> Result<String, ContractError> {
+
+/// Moves the administrator from Config to ADMIN, and grants it the roles
+/// that were reserved to the administrator so far
+fn migrate_to_0_11_0(mut deps: DepsMut) -> Result<(), ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    return Ok(config.usdc_ibc_symbol)
+    ADMIN.set(deps.branch(), Some(config.admin_address.clone()))?;
+    ROLES.save(deps.storage, (Role::Distributor.as_str(), &config.admin_address), &Empty {})?;
+    ROLES.save(deps.storage, (Role::Assigner.as_str(), &config.admin_address), &Empty {})?;
+    Ok(())
 }
 
+/// Registers the clubs bought before the club registry existed, so that they can still be staked on
+fn migrate_to_0_12_0(deps: DepsMut) -> Result<(), ContractError> {
+    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|k| String::from_utf8(k).unwrap())
+        .collect();
+    for club_name in all_clubs {
+        // the clubs stay stored under their old name, so it must already be the normalized
+        // one for the club to be found again, which also rules out two clubs colliding
+        let normalized = normalize_club_name(&club_name)?;
+        if normalized != club_name {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: format!("Club {} is not stored under its normalized name {}", club_name, normalized),
+            }));
+        }
+        if !CLUB_REGISTRY.has(deps.storage, club_name.clone()) {
+            let profile = ClubProfile {
+                display_name: club_name.clone(),
+                active: true,
+                ..ClubProfile::default()
+            };
+            CLUB_REGISTRY.save(deps.storage, club_name, &profile)?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves the club rewards of each reward period out of REWARD_PERIOD_HISTORY
+/// to CLUB_REWARD_HISTORY, as rewards are now distributed a page of clubs at a time
+fn migrate_to_0_13_0(deps: DepsMut) -> Result<(), ContractError> {
+    let legacy_records: Vec<LegacyRewardPeriodRecord> = LEGACY_REWARD_PERIOD_HISTORY
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<_>>()?;
+    for record in legacy_records {
+        let reward_period = U64Key::new(record.reward_period);
+        for club_reward in record.club_rewards.iter() {
+            CLUB_REWARD_HISTORY.save(deps.storage, (reward_period.clone(), &club_reward.club_name), club_reward)?;
+        }
+        REWARD_PERIOD_HISTORY.save(
+            deps.storage,
+            reward_period,
+            &RewardPeriodRecord {
+                reward_period: record.reward_period,
+                timestamp: record.timestamp,
+                total_reward: record.total_reward,
+                reward_given: record.reward_given,
+                undistributed_reward: record.undistributed_reward,
+                winner_list: record.winner_list,
+                total_stake_across_all_clubs: record.total_stake_across_all_clubs,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Moves the stakes saved as a Vec per club and staker to club_staking_details(),
+/// giving each stake its own stake id
+fn migrate_club_staking_details(storage: &mut dyn Storage) -> StdResult<()> {
+    let legacy_stakes: Vec<(Vec<u8>, Vec<ClubStakingDetails>)> = LEGACY_CLUB_STAKING_DETAILS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (key, stakes) in legacy_stakes {
+        // the key is the length prefixed club name followed by the staker address
+        let club_name_len = u16::from_be_bytes([key[0], key[1]]) as usize;
+        let club_name = String::from_utf8(key[2..2 + club_name_len].to_vec())?;
+        let staker = String::from_utf8(key[2 + club_name_len..].to_vec())?;
+        // legacy stakes have no stake id yet, so save_club_stakes assigns one
+        save_club_stakes(storage, club_name.clone(), staker.clone(), &stakes)?;
+        LEGACY_CLUB_STAKING_DETAILS.remove(storage, (&club_name, &staker));
+    }
+    Ok(())
+}
 
 fn received_message(
     deps: DepsMut,
@@ -199,6 +644,26 @@ fn received_message(
         ReceivedMsg::IncreaseRewardAmount(irac) => {
             increase_reward_amount(deps, env, info, irac.reward_from, amount)
         }
+        ReceivedMsg::PayFeesInFury(pfc) => {
+            let config = CONFIG.load(deps.storage)?;
+            if info.sender != config.minting_contract_address {
+                return Err(ContractError::Unauthorized {});
+            }
+            if let ExecuteMsg::Receive(_) = pfc.msg {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Fees can not be paid for a received message"),
+                }));
+            }
+            check_not_paused(deps.storage, &pfc.msg)?;
+            check_no_reward_distribution_in_progress(deps.storage, &pfc.msg)?;
+            let fees = collect_fury_fees(deps.as_ref(), amount, &pfc.msg)?;
+            let sender_info = MessageInfo {
+                sender: deps.api.addr_validate(&message.sender)?,
+                funds: vec![],
+            };
+            let response = execute_msg(deps, env, sender_info, pfc.msg)?;
+            Ok(response.add_submessages(fees.messages).add_attributes(fees.attributes))
+        }
     }
     // Err(ContractError::Std(StdError::GenericErr {
     //     msg: format!("received_message where msg = {:?}", msg),
@@ -254,12 +719,20 @@ fn claim_previous_owner_rewards(
             msg: String::from("Not a valid previous owner for the club"),
         }));
     }
-    transfer_from_contract_to_wallet(
+    let mut rsp = transfer_from_contract_to_wallet(
         deps.storage,
         previous_owner.clone(),
         amount,
         "previous_owners_reward".to_string(),
-    )
+    )?;
+    RewardClaimedEvent {
+        claimant_type: "previous_owner",
+        claimant: &previous_owner,
+        club_name: None,
+        amount,
+    }
+        .add_event(&mut rsp);
+    Ok(rsp)
 }
 
 fn claim_owner_rewards(
@@ -269,6 +742,7 @@ fn claim_owner_rewards(
     owner: String,
     club_name: String,
 ) -> Result<Response, ContractError> {
+    let club_name = normalize_club_name(&club_name)?;
     let mut amount = Uint128::zero();
     let mut transfer_confirmed = false;
     let owner_addr = deps.api.addr_validate(&owner)?;
@@ -290,7 +764,7 @@ fn claim_owner_rewards(
 
     if !(ownership_details.is_none()) {
         for owner_detail in ownership_details {
-            if owner_detail.owner_address == owner.clone() {
+            if current_club_owner(deps.as_ref(), &owner_detail)? == owner {
                 if Uint128::zero() == owner_detail.reward_amount {
                     return Err(ContractError::Std(StdError::GenericErr {
                         msg: String::from("No rewards for this owner"),
@@ -309,7 +783,8 @@ fn claim_owner_rewards(
                         club_name: owner_detail.club_name,
                         start_timestamp: owner_detail.start_timestamp,
                         locking_period: owner_detail.locking_period,
-                        owner_address: owner_detail.owner_address,
+                        // the NFT may have been traded since the club was bought
+                        owner_address: owner.clone(),
                         price_paid: owner_detail.price_paid,
                         reward_amount: Uint128::zero(),
                         owner_released: owner_detail.owner_released,
@@ -325,12 +800,20 @@ fn claim_owner_rewards(
             msg: String::from("Not a valid owner for the club"),
         }));
     }
-    transfer_from_contract_to_wallet(
+    let mut rsp = transfer_from_contract_to_wallet(
         deps.storage,
         owner.clone(),
         amount,
         "owner_reward".to_string(),
-    )
+    )?;
+    RewardClaimedEvent {
+        claimant_type: "owner",
+        claimant: &owner,
+        club_name: Some(&club_name),
+        amount,
+    }
+        .add_event(&mut rsp);
+    Ok(rsp)
 }
 
 fn periodically_refund_stakeouts(
@@ -358,7 +841,6 @@ fn buy_a_club(
         return Err(ContractError::Unauthorized {});
     }
 
-    println!("seller_opt = {:?}", seller_opt);
     let seller;
     match seller_opt.clone() {
         Some(s) => seller = s,
@@ -367,6 +849,8 @@ fn buy_a_club(
 
     let config = CONFIG.load(deps.storage)?;
 
+    let club_name = assert_club_active(deps.storage, &club_name)?;
+
     let club_price = config.club_price;
     if price != club_price {
         return Err(ContractError::Std(StdError::GenericErr {
@@ -374,39 +858,6 @@ fn buy_a_club(
         }));
     }
 
-    let required_ust_fees: Uint128;
-    //To bypass calls from unit tests
-    if info.sender.clone().into_string() == String::from("owner001")
-        || info.sender.clone().into_string() == String::from("owner002")
-        || info.sender.clone().into_string() == String::from("owner003")
-    {
-        required_ust_fees = Uint128::zero();
-    } else {
-        required_ust_fees = query_platform_fees(
-            deps.as_ref(),
-            to_binary(&ExecuteMsg::BuyAClub {
-                buyer: buyer.clone(),
-                club_name: club_name.clone(),
-                seller: seller_opt,
-                auto_stake: auto_stake,
-            })?,
-        )?;
-    }
-    let mut fees = Uint128::zero();
-    for fund in info.funds.clone() {
-        if fund.denom == uusd(&deps)? {
-            fees = fees.checked_add(fund.amount).unwrap();
-        }
-    }
-    let adjusted_ust_fees = required_ust_fees
-        * (Uint128::from(NINETY_NINE_NINE_PERCENT))
-        / (Uint128::from(HUNDRED_PERCENT));
-    if fees < adjusted_ust_fees {
-        return Err(ContractError::InsufficientFees {
-            required: required_ust_fees,
-            received: fees,
-        });
-    }
     let buyer_addr = deps.api.addr_validate(&buyer)?;
 
     let ownership_details;
@@ -420,19 +871,10 @@ fn buy_a_club(
         }
     }
 
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-
-    for one_club_name in all_clubs {
-        let one_ownership_details =
-            CLUB_OWNERSHIP_DETAILS.load(deps.storage, one_club_name.clone())?;
-        if buyer == one_ownership_details.owner_address {
-            return Err(ContractError::Std(StdError::GenericErr {
-                msg: String::from("buyer already owns this club"),
-            }));
-        }
+    if owns_a_club(deps.as_ref(), &buyer)? {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("buyer already owns this club"),
+        }));
     }
 
     let mut previous_owners_reward_amount = Uint128::from(0u128);
@@ -443,24 +885,15 @@ fn buy_a_club(
             let mut current_time = env.block.time;
             let mut release_start_time = owner.start_timestamp;
             let mut release_locking_duration = owner.locking_period;
-            println!(
-                "release_start_time = {:?} locking_duration = {:?} current time = {:?}",
-                release_start_time, release_locking_duration, current_time
-            );
             if owner.owner_released == false {
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Owner has not released the club"),
                 }));
             } else if current_time > release_start_time.plus_seconds(release_locking_duration) {
-                println!("Release time for the club has expired");
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Release time for the club has expired"),
                 }));
-            } else if owner.owner_address != String::default() && owner.owner_address != seller {
-                println!(
-                    "owner.owner_address = {:?} and seller = {:?}",
-                    owner.owner_address, seller
-                );
+            } else if owner.owner_address != String::default() && current_club_owner(deps.as_ref(), &owner)? != seller {
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Seller is not the owner for the club"),
                 }));
@@ -470,15 +903,12 @@ fn buy_a_club(
 
             // Evaluate previous owner rewards
             previous_owners_reward_amount = owner.reward_amount;
-            println!("prv own amount picked {:?}", previous_owners_reward_amount);
             let mut previous_reward = Uint128::zero();
-            println!("prv own amount avl {:?}", previous_owners_reward_amount);
             if previous_owners_reward_amount != Uint128::zero() {
                 let pod = CLUB_PREVIOUS_OWNER_DETAILS.may_load(deps.storage, seller.clone())?;
                 match pod {
                     Some(pod) => {
                         previous_reward = pod.reward_amount;
-                        println!("prv own existing reward {:?}", previous_reward);
                     }
                     None => {}
                 }
@@ -496,6 +926,7 @@ fn buy_a_club(
         }
     }
 
+    let nft_msg = club_nft_msg(deps.as_ref(), &club_name, &buyer)?;
     // Now save the ownership details
     CLUB_OWNERSHIP_DETAILS.save(
         deps.storage,
@@ -511,10 +942,12 @@ fn buy_a_club(
             total_staked_amount: total_staked_amount,
         },
     )?;
+    // a listing by the previous owner no longer applies
+    CLUB_LISTINGS.remove(deps.storage, club_name.clone());
 
     let mut stakes = Vec::new();
     let mut user_stake_exists = false;
-    let all_stakes = CLUB_STAKING_DETAILS.may_load(deps.storage, (&club_name.clone(), &buyer.clone()))?;
+    let all_stakes = may_load_club_stakes(deps.storage, club_name.clone(), buyer.clone())?;
     match all_stakes {
         Some(some_stakes) => {
             stakes = some_stakes;
@@ -551,14 +984,18 @@ fn buy_a_club(
     };
 
     let send_wasm: CosmosMsg = CosmosMsg::Wasm(exec);
-    let send_bank: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: config.platform_fees_collector_wallet.into_string(),
-        amount: info.funds,
-    });
     let data_msg = format!("Club fees {} received", price).into_bytes();
-    return Ok(Response::new()
+    let mut rsp = Response::new();
+    ClubBoughtEvent {
+        club_name: &club_name,
+        buyer: &buyer,
+        seller: Some(seller.as_str()).filter(|s| !s.is_empty()),
+        price,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
         .add_message(send_wasm)
-        .add_message(send_bank)
+        .add_messages(nft_msg)
         .add_attribute("action", "buy_a_club")
         .add_attribute("buyer", buyer)
         .add_attribute("club_name", club_name)
@@ -576,11 +1013,11 @@ fn assign_a_club(
     auto_stake: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
+    if !has_role(deps.storage, Role::Assigner, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
+    let club_name = assert_club_active(deps.storage, &club_name)?;
 
-    println!("seller_opt = {:?}", seller_opt);
     let seller;
     match seller_opt.clone() {
         Some(s) => seller = s,
@@ -600,19 +1037,10 @@ fn assign_a_club(
         }
     }
 
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-
-    for one_club_name in all_clubs {
-        let one_ownership_details =
-            CLUB_OWNERSHIP_DETAILS.load(deps.storage, one_club_name.clone())?;
-        if buyer == one_ownership_details.owner_address {
-            return Err(ContractError::Std(StdError::GenericErr {
-                msg: String::from("buyer already owns this club"),
-            }));
-        }
+    if owns_a_club(deps.as_ref(), &buyer)? {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("buyer already owns this club"),
+        }));
     }
 
     let mut previous_owners_reward_amount = Uint128::from(0u128);
@@ -623,24 +1051,15 @@ fn assign_a_club(
             let mut current_time = env.block.time;
             let mut release_start_time = owner.start_timestamp;
             let mut release_locking_duration = owner.locking_period;
-            println!(
-                "release_start_time = {:?} locking_duration = {:?} current time = {:?}",
-                release_start_time, release_locking_duration, current_time
-            );
             if owner.owner_released == false {
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Owner has not released the club"),
                 }));
             } else if current_time > release_start_time.plus_seconds(release_locking_duration) {
-                println!("Release time for the club has expired");
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Release time for the club has expired"),
                 }));
-            } else if owner.owner_address != String::default() && owner.owner_address != seller {
-                println!(
-                    "owner.owner_address = {:?} and seller = {:?}",
-                    owner.owner_address, seller
-                );
+            } else if owner.owner_address != String::default() && current_club_owner(deps.as_ref(), &owner)? != seller {
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Seller is not the owner for the club"),
                 }));
@@ -650,15 +1069,12 @@ fn assign_a_club(
 
             // Evaluate previous owner rewards
             previous_owners_reward_amount = owner.reward_amount;
-            println!("prv own amount picked {:?}", previous_owners_reward_amount);
             let mut previous_reward = Uint128::zero();
-            println!("prv own amount avl {:?}", previous_owners_reward_amount);
             if previous_owners_reward_amount != Uint128::zero() {
                 let pod = CLUB_PREVIOUS_OWNER_DETAILS.may_load(deps.storage, seller.clone())?;
                 match pod {
                     Some(pod) => {
                         previous_reward = pod.reward_amount;
-                        println!("prv own existing reward {:?}", previous_reward);
                     }
                     None => {}
                 }
@@ -676,6 +1092,7 @@ fn assign_a_club(
         }
     }
 
+    let nft_msg = club_nft_msg(deps.as_ref(), &club_name, &buyer)?;
     // Now save the ownership details
     CLUB_OWNERSHIP_DETAILS.save(
         deps.storage,
@@ -691,10 +1108,12 @@ fn assign_a_club(
             total_staked_amount: total_staked_amount,
         },
     )?;
+    // a listing by the previous owner no longer applies
+    CLUB_LISTINGS.remove(deps.storage, club_name.clone());
 
     let mut stakes = Vec::new();
     let mut user_stake_exists = false;
-    let all_stakes = CLUB_STAKING_DETAILS.may_load(deps.storage, (&club_name.clone(), &buyer.clone()))?;
+    let all_stakes = may_load_club_stakes(deps.storage, club_name.clone(), buyer.clone())?;
     match all_stakes {
         Some(some_stakes) => {
             stakes = some_stakes;
@@ -719,7 +1138,13 @@ fn assign_a_club(
         )?;
     }
 
-    return Ok(Response::default());
+    let mut rsp = Response::new();
+    ClubAssignedEvent {
+        club_name: &club_name,
+        owner: &buyer,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp.add_messages(nft_msg));
 }
 
 #[entry_point]
@@ -736,6 +1161,7 @@ fn release_club(
     seller: String,
     club_name: String,
 ) -> Result<Response, ContractError> {
+    let club_name = normalize_club_name(&club_name)?;
     let seller_addr = deps.api.addr_validate(&seller)?;
     //Check if seller is same as invoker
     if seller_addr != info.sender {
@@ -759,7 +1185,7 @@ fn release_club(
         }));
     }
     for owner in ownership_details {
-        if owner.owner_address != seller_addr {
+        if current_club_owner(deps.as_ref(), &owner)? != seller_addr {
             return Err(ContractError::Std(StdError::GenericErr {
                 msg: String::from("Releaser is not the owner for the club"),
             }));
@@ -781,63 +1207,265 @@ fn release_club(
             )?;
         }
     }
-    return Ok(Response::default());
+    let mut rsp = Response::new();
+    ClubReleasedEvent {
+        club_name: &club_name,
+        owner: &seller,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp);
 }
 
-fn stake_on_a_club(
+fn list_club(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    staker: String,
+    seller: String,
     club_name: String,
-    amount: Uint128,
-    auto_stake: bool,
+    asking_price: Uint128,
+    expiry: Timestamp,
 ) -> Result<Response, ContractError> {
-    if info.sender != staker {
+    let club_name = normalize_club_name(&club_name)?;
+    let seller_addr = deps.api.addr_validate(&seller)?;
+    //Check if seller is same as invoker
+    if seller_addr != info.sender {
         return Err(ContractError::Unauthorized {});
     }
-
-    let config = CONFIG.load(deps.storage)?;
-
-    let staker_addr = deps.api.addr_validate(&staker)?;
-    let contract_address = env.clone().contract.address.into_string();
-
-    let required_ust_fees: Uint128;
-    //To bypass calls from unit tests
-    if info.sender.clone().into_string() == String::from("staker001")
-        || info.sender.clone().into_string() == String::from("staker002")
-        || info.sender.clone().into_string() == String::from("staker003")
-        || info.sender.clone().into_string() == String::from("staker004")
-        || info.sender.clone().into_string() == String::from("staker005")
-        || info.sender.clone().into_string() == String::from("staker006")
-    {
-        required_ust_fees = Uint128::zero();
-    } else {
-        required_ust_fees = query_platform_fees(
-            deps.as_ref(),
-            to_binary(&ExecuteMsg::StakeOnAClub {
-                staker: staker.clone(),
-                club_name: club_name.clone(),
-                amount: amount,
-                auto_stake: auto_stake,
-            })?,
-        )?;
-    }
-    let mut fees = Uint128::zero();
-    for fund in info.funds.clone() {
-        if fund.denom == uusd(&deps)? {
-            fees = fees.checked_add(fund.amount).unwrap();
+    let ownership_details = CLUB_OWNERSHIP_DETAILS.may_load(deps.storage, club_name.clone())?;
+    let owner_address = match ownership_details {
+        Some(owner) => Some(current_club_owner(deps.as_ref(), &owner)?),
+        None => None,
+    };
+    match owner_address {
+        Some(owner_address) if owner_address == seller_addr => {}
+        _ => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Lister is not the owner for the club"),
+            }));
         }
     }
-    let adjusted_ust_fees = required_ust_fees
-        * (Uint128::from(NINETY_NINE_NINE_PERCENT))
-        / (Uint128::from(HUNDRED_PERCENT));
-    if fees < adjusted_ust_fees {
-        return Err(ContractError::InsufficientFees {
-            required: required_ust_fees,
-            received: fees,
-        });
+    if asking_price.is_zero() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Asking price must be more than 0"),
+        }));
     }
+    if expiry <= env.block.time {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Listing expiry must be in the future"),
+        }));
+    }
+
+    CLUB_LISTINGS.save(
+        deps.storage,
+        club_name.clone(),
+        &ClubListing {
+            club_name: club_name.clone(),
+            seller: seller.clone(),
+            asking_price: asking_price,
+            expiry: expiry,
+        },
+    )?;
+    let mut rsp = Response::new();
+    ClubListedEvent {
+        club_name: &club_name,
+        seller: &seller,
+        asking_price,
+        expiry,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
+        .add_attribute("action", "list_club")
+        .add_attribute("seller", seller)
+        .add_attribute("club_name", club_name)
+        .add_attribute("asking_price", asking_price.to_string())
+        .add_attribute("expiry", expiry.to_string()));
+}
+
+fn cancel_club_listing(
+    deps: DepsMut,
+    info: MessageInfo,
+    seller: String,
+    club_name: String,
+) -> Result<Response, ContractError> {
+    let club_name = normalize_club_name(&club_name)?;
+    let seller_addr = deps.api.addr_validate(&seller)?;
+    //Check if seller is same as invoker
+    if seller_addr != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let listing = CLUB_LISTINGS.may_load(deps.storage, club_name.clone())?;
+    match listing {
+        Some(listing) if listing.seller == seller => {}
+        _ => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No listing by this seller for the club"),
+            }));
+        }
+    }
+    CLUB_LISTINGS.remove(deps.storage, club_name.clone());
+    let mut rsp = Response::new();
+    ClubListingCancelledEvent {
+        club_name: &club_name,
+        seller: &seller,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
+        .add_attribute("action", "cancel_club_listing")
+        .add_attribute("seller", seller)
+        .add_attribute("club_name", club_name));
+}
+
+fn buy_listed_club(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    buyer: String,
+    club_name: String,
+    auto_stake: bool,
+) -> Result<Response, ContractError> {
+    if info.sender != buyer {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let buyer_addr = deps.api.addr_validate(&buyer)?;
+    let club_name = assert_club_active(deps.storage, &club_name)?;
+
+    let listing = CLUB_LISTINGS.may_load(deps.storage, club_name.clone())?;
+    let listing = match listing {
+        Some(listing) => listing,
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Club is not listed for sale"),
+            }));
+        }
+    };
+    if env.block.time > listing.expiry {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Listing for the club has expired"),
+        }));
+    }
+    let owner = CLUB_OWNERSHIP_DETAILS.load(deps.storage, club_name.clone())?;
+    if current_club_owner(deps.as_ref(), &owner)? != listing.seller {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Seller is not the owner for the club"),
+        }));
+    }
+
+    if owns_a_club(deps.as_ref(), &buyer)? {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("buyer already owns this club"),
+        }));
+    }
+
+    // Rewards not yet claimed by the seller move to the previous owner details
+    if owner.reward_amount != Uint128::zero() {
+        let previous_reward = CLUB_PREVIOUS_OWNER_DETAILS
+            .may_load(deps.storage, listing.seller.clone())?
+            .map(|pod| pod.reward_amount)
+            .unwrap_or_default();
+        CLUB_PREVIOUS_OWNER_DETAILS.save(
+            deps.storage,
+            listing.seller.clone(),
+            &ClubPreviousOwnerDetails {
+                previous_owner_address: listing.seller.clone(),
+                reward_amount: previous_reward + owner.reward_amount,
+            },
+        )?;
+    }
+
+    let nft_msg = club_nft_msg(deps.as_ref(), &club_name, &buyer)?;
+    // Now save the ownership details
+    CLUB_OWNERSHIP_DETAILS.save(
+        deps.storage,
+        club_name.clone(),
+        &ClubOwnershipDetails {
+            club_name: club_name.clone(),
+            start_timestamp: env.block.time,
+            locking_period: config.owner_release_locking_duration,
+            owner_address: buyer_addr.to_string(),
+            price_paid: listing.asking_price,
+            reward_amount: Uint128::from(CLUB_BUYING_REWARD_AMOUNT),
+            owner_released: false,
+            total_staked_amount: owner.total_staked_amount,
+        },
+    )?;
+    CLUB_LISTINGS.remove(deps.storage, club_name.clone());
+
+    let buyer_stakes = may_load_club_stakes(deps.storage, club_name.clone(), buyer.clone())?;
+    if buyer_stakes.unwrap_or_default().is_empty() {
+        // Now save the staking details for the owner - with 0 stake
+        save_staking_details(
+            deps.storage,
+            env,
+            buyer.clone(),
+            club_name.clone(),
+            Uint128::zero(),
+            auto_stake,
+            INCREASE_STAKE,
+        )?;
+    }
+
+    let platform_fees = listing.asking_price.multiply_ratio(config.platform_fees, HUNDRED_PERCENT);
+    let seller_proceeds = listing.asking_price - platform_fees;
+
+    let mut rsp = Response::new();
+    ClubBoughtEvent {
+        club_name: &club_name,
+        buyer: &buyer,
+        seller: Some(&listing.seller),
+        price: listing.asking_price,
+    }
+        .add_event(&mut rsp);
+    let transfer_msg = Cw20ExecuteMsg::TransferFrom {
+        owner: info.sender.clone().into_string(),
+        recipient: listing.seller.clone(),
+        amount: seller_proceeds,
+    };
+    rsp = rsp.add_message(WasmMsg::Execute {
+        contract_addr: config.minting_contract_address.to_string(),
+        msg: to_binary(&transfer_msg).unwrap(),
+        funds: vec![],
+    });
+    if platform_fees > Uint128::zero() {
+        let transfer_msg = Cw20ExecuteMsg::TransferFrom {
+            owner: info.sender.into_string(),
+            recipient: config.platform_fees_collector_wallet.to_string(),
+            amount: platform_fees,
+        };
+        rsp = rsp.add_message(WasmMsg::Execute {
+            contract_addr: config.minting_contract_address.to_string(),
+            msg: to_binary(&transfer_msg).unwrap(),
+            funds: vec![],
+        });
+    }
+    return Ok(rsp
+        .add_messages(nft_msg)
+        .add_attribute("action", "buy_listed_club")
+        .add_attribute("buyer", buyer)
+        .add_attribute("seller", listing.seller)
+        .add_attribute("club_name", club_name)
+        .add_attribute("price", listing.asking_price.to_string())
+        .add_attribute("platform_fees", platform_fees.to_string()));
+}
+
+fn stake_on_a_club(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    staker: String,
+    club_name: String,
+    amount: Uint128,
+    auto_stake: bool,
+) -> Result<Response, ContractError> {
+    if info.sender != staker {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let club_name = assert_club_active(deps.storage, &club_name)?;
+
+    let staker_addr = deps.api.addr_validate(&staker)?;
+    let contract_address = env.clone().contract.address.into_string();
 
     //check if the club_name is available for staking
     let ownership_details;
@@ -881,14 +1509,16 @@ fn stake_on_a_club(
     };
 
     let send_wasm: CosmosMsg = CosmosMsg::Wasm(exec);
-    let send_bank: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: config.platform_fees_collector_wallet.into_string(),
-        amount: info.funds,
-    });
     let data_msg = format!("Club stake {} received", amount).into_bytes();
-    return Ok(Response::new()
+    let mut rsp = Response::new();
+    StakeAddedEvent {
+        club_name: &club_name,
+        staker: &staker,
+        amount,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
         .add_message(send_wasm)
-        .add_message(send_bank)
         .add_attribute("action", "stake_on_a_club")
         .add_attribute("staker", staker)
         .add_attribute("club_name", club_name)
@@ -896,6 +1526,114 @@ fn stake_on_a_club(
         .set_data(data_msg));
 }
 
+fn move_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from_club: String,
+    to_club: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let staker = info.sender.to_string();
+
+    if config.move_stake_cooldown > 0 {
+        let last_move = LAST_STAKE_MOVE.may_load(deps.storage, &staker)?;
+        if let Some(last_move) = last_move {
+            if env.block.time < last_move.plus_seconds(config.move_stake_cooldown) {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Stake was moved too recently"),
+                }));
+            }
+        }
+        // the winning clubs must not be swung by moves just before the distribution,
+        // once the distribution is overdue there is no window left to protect
+        let next_reward_time = CLUB_REWARD_NEXT_TIMESTAMP.may_load(deps.storage)?.unwrap_or_default();
+        if env.block.time < next_reward_time
+            && env.block.time.plus_seconds(config.move_stake_cooldown) >= next_reward_time
+        {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Stake can not be moved this close to the reward distribution"),
+            }));
+        }
+    }
+    let from_club = normalize_club_name(&from_club)?;
+    let to_club = assert_club_active(deps.storage, &to_club)?;
+    if from_club == to_club {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Stake can only be moved to another club"),
+        }));
+    }
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    if !CLUB_OWNERSHIP_DETAILS.has(deps.storage, to_club.clone()) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("The club is not available for staking"),
+        }));
+    }
+
+    settle_staker_rewards(deps.storage, from_club.clone(), staker.clone())?;
+    let from_stake = may_load_club_stakes(deps.storage, from_club.clone(), staker.clone())?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|stake| stake.staker_address == staker);
+    let auto_stake = match from_stake {
+        Some(stake) if stake.staked_amount >= amount => stake.auto_stake,
+        Some(_) => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Excess amount demanded for moving"),
+            }));
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("User has not staked in this club"),
+            }));
+        }
+    };
+    // an existing stake in the destination club keeps its own setting
+    let auto_stake = may_load_club_stakes(deps.storage, to_club.clone(), staker.clone())?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|stake| stake.staker_address == staker)
+        .map_or(auto_stake, |stake| stake.auto_stake);
+
+    save_staking_details(
+        deps.storage,
+        env.clone(),
+        staker.clone(),
+        from_club.clone(),
+        amount,
+        DONT_CHANGE_AUTO_STAKE_SETTING,
+        DECREASE_STAKE,
+    )?;
+    save_staking_details(
+        deps.storage,
+        env.clone(),
+        staker.clone(),
+        to_club.clone(),
+        amount,
+        auto_stake,
+        INCREASE_STAKE,
+    )?;
+    LAST_STAKE_MOVE.save(deps.storage, &staker, &env.block.time)?;
+
+    let mut rsp = Response::new();
+    StakeMovedEvent {
+        staker: &staker,
+        from_club: &from_club,
+        to_club: &to_club,
+        amount,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
+        .add_attribute("action", "move_stake")
+        .add_attribute("staker", staker)
+        .add_attribute("from_club", from_club)
+        .add_attribute("to_club", to_club)
+        .add_attribute("amount", amount.to_string()));
+}
+
 fn assign_stakes_to_a_club(
     deps: DepsMut,
     env: Env,
@@ -904,13 +1642,14 @@ fn assign_stakes_to_a_club(
     club_name: String,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
+    if !has_role(deps.storage, Role::Assigner, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
+    let club_name = assert_club_active(deps.storage, &club_name)?;
     let contract_address = env.clone().contract.address.into_string();
 
     for stake in stake_list.clone() {
-        if stake.club_name != club_name {
+        if normalize_club_name(&stake.club_name)? != club_name {
             return Err(ContractError::Std(StdError::GenericErr {
                 msg: String::from("Passed club names do not match"),
             }));
@@ -937,6 +1676,7 @@ fn assign_stakes_to_a_club(
     }
     let owner = ownership_details.unwrap();
 
+    let mut rsp = Response::new();
     let mut total_amount = Uint128::zero();
     for stake in stake_list {
         let mut staker = stake.staker_address.clone();
@@ -954,6 +1694,12 @@ fn assign_stakes_to_a_club(
             auto_stake,
             INCREASE_STAKE,
         )?;
+        StakeAddedEvent {
+            club_name: &club_name,
+            staker: &staker,
+            amount,
+        }
+            .add_event(&mut rsp);
     }
 
     let transfer_msg = Cw20ExecuteMsg::TransferFrom {
@@ -969,7 +1715,7 @@ fn assign_stakes_to_a_club(
 
     let send_wasm: CosmosMsg = CosmosMsg::Wasm(exec);
     let data_msg = format!("Assign Stakes To Club {} received", total_amount).into_bytes();
-    return Ok(Response::new()
+    return Ok(rsp
         .add_message(send_wasm)
         .add_attribute("action", "assign_stakes_to_a_club")
         .add_attribute("club_name", club_name)
@@ -986,6 +1732,7 @@ fn withdraw_stake_from_a_club(
     withdrawal_amount: Uint128,
     immediate_withdrawal: bool,
 ) -> Result<Response, ContractError> {
+    let club_name = normalize_club_name(&club_name)?;
     let config = CONFIG.load(deps.storage)?;
     let staker_addr = deps.api.addr_validate(&staker)?;
     //Check if withdrawer is same as invoker
@@ -1005,45 +1752,10 @@ fn withdraw_stake_from_a_club(
         }
     }
 
-    let required_ust_fees: Uint128;
-    //To bypass calls from unit tests
-    if info.sender.clone().into_string() == String::from("staker001")
-        || info.sender.clone().into_string() == String::from("staker002")
-        || info.sender.clone().into_string() == String::from("staker003")
-        || info.sender.clone().into_string() == String::from("staker004")
-        || info.sender.clone().into_string() == String::from("staker005")
-        || info.sender.clone().into_string() == String::from("staker006")
-    {
-        required_ust_fees = Uint128::zero();
-    } else {
-        required_ust_fees = query_platform_fees(
-            deps.as_ref(),
-            to_binary(&ExecuteMsg::StakeWithdrawFromAClub {
-                staker: staker.clone(),
-                club_name: club_name.clone(),
-                amount: withdrawal_amount,
-                immediate_withdrawal,
-            })?,
-        )?;
-    }
-    let mut fees = Uint128::zero();
-    for fund in info.funds.clone() {
-        if fund.denom == uusd(&deps)? {
-            fees = fees.checked_add(fund.amount).unwrap();
-        }
-    }
-    let adjusted_ust_fees = required_ust_fees
-        * (Uint128::from(NINETY_NINE_NINE_PERCENT))
-        / (Uint128::from(HUNDRED_PERCENT));
-    if fees < adjusted_ust_fees {
-        return Err(ContractError::InsufficientFees {
-            required: required_ust_fees,
-            received: fees,
-        });
-    }
+    settle_staker_rewards(deps.storage, club_name.clone(), staker.clone())?;
 
     let mut stakes = Vec::new();
-    let all_stakes = CLUB_STAKING_DETAILS.may_load(deps.storage, (&club_name.clone(), &staker.clone()))?;
+    let all_stakes = may_load_club_stakes(deps.storage, club_name.clone(), staker.clone())?;
     match all_stakes {
         Some(some_stakes) => {
             stakes = some_stakes;
@@ -1073,90 +1785,19 @@ fn withdraw_stake_from_a_club(
     let mut transfer_confirmed = false;
     let mut action = "withdraw_stake".to_string();
     let mut burn_amount = Uint128::zero();
+    let mut penalty_to_reward_pool = Uint128::zero();
     if ownership_details.is_some() {
-        let owner = ownership_details.unwrap();
-        let mut unbonded_amount = Uint128::zero();
-        let mut bonded_amount = Uint128::zero();
-        let mut amount_remaining = withdrawal_amount.clone();
-
         if immediate_withdrawal == IMMEDIATE_WITHDRAWAL {
-            // parse bonding to check maturity and sort with descending order of timestamp
-            let mut bonds = Vec::new();
-            let mut all_bonds = CLUB_BONDING_DETAILS.may_load(deps.storage, (&club_name.clone(), &staker.clone()))?;
-            let mut s_bonds = Vec::new();
-            match all_bonds {
-                Some(some_bonds) => {
-                    bonds = some_bonds;
-                    for bond in bonds {
-                        s_bonds.push((bond.bonding_start_timestamp.seconds(), bond.clone()));
-                    }
-                }
-                None => {}
-            }
-
-            //  sort using first element, ie timestamp
-            s_bonds.sort_by(|a, b| b.0.cmp(&a.0));
-
-            let existing_bonds = s_bonds.clone();
-            let mut updated_bonds = Vec::new();
-
-            // PRE-MATURITY BOND are extracted here
-            // let mut bonded_bonds = Vec::new();
-
-            for bond in existing_bonds {
-                let mut updated_bond = bond.1.clone();
-                if staker_addr == bond.1.bonder_address {
-                    println!(
-                        "staker {:?} timestamp  {:?} amount {:?}",
-                        staker_addr, bond.1.bonding_start_timestamp, bond.1.bonded_amount
-                    );
-                    if bond.1.bonding_start_timestamp
-                        < env.block.time.minus_seconds(bond.1.bonding_duration)
-                    {
-                        if amount_remaining > Uint128::zero() {
-                            if bond.1.bonded_amount > amount_remaining {
-                                unbonded_amount += amount_remaining;
-                                updated_bond.bonded_amount -= amount_remaining;
-                                amount_remaining = Uint128::zero();
-                                updated_bonds.push(updated_bond);
-                            } else {
-                                unbonded_amount += bond.1.bonded_amount;
-                                amount_remaining -= bond.1.bonded_amount;
-                            }
-                        } else {
-                            updated_bonds.push(updated_bond);
-                        }
-                    } else {
-                        // PRE-MATURITY BOND ENCASH AT DISCOUNT - enable the following line
-                        // bonded_bonds.push(updated_bond);
-                        // PRE-MATURITY BOND ENCASH AT DISCOUNT - bypased or masked using this line
-                        updated_bonds.push(updated_bond);
-                    }
-                } else {
-                    updated_bonds.push(updated_bond);
-                }
-            }
+            let all_bonds = CLUB_BONDING_DETAILS.load_claims(deps.storage, &club_name, &staker_addr)?;
+            let (quote, updated_bonds) = plan_immediate_unbonding(
+                all_bonds,
+                &staker_addr,
+                withdrawal_amount,
+                env.block.time,
+                &config.early_unbond_policy,
+            );
 
-            // // This section Checks the Pre-Maturity Bonds for possible encashment
-            // for bond in bonded_bonds {
-            //     let mut updated_bond = bond.clone();
-            //     if amount_remaining > Uint128::zero() {
-            //         if bond.bonded_amount > amount_remaining {
-            //             bonded_amount = amount_remaining;
-            //             updated_bond.bonded_amount -= amount_remaining;
-            //             amount_remaining = Uint128::zero();
-            //             updated_bonds.push(updated_bond);
-            //         } else {
-            //             bonded_amount += bond.bonded_amount;
-            //             amount_remaining -= bond.bonded_amount;
-            //         }
-            //     } else {
-            //         updated_bonds.push(updated_bond);
-            //     }
-            // }
-
-
-            CLUB_BONDING_DETAILS.save(deps.storage, (&club_name.clone(), &staker.clone()), &updated_bonds)?;
+            CLUB_BONDING_DETAILS.save_claims(deps.storage, &club_name, &staker_addr, &updated_bonds)?;
 
             // update the staking details
             save_staking_details(
@@ -1164,27 +1805,27 @@ fn withdraw_stake_from_a_club(
                 env.clone(),
                 staker.clone(),
                 club_name.clone(),
-                (withdrawal_amount - unbonded_amount) - bonded_amount,
+                quote.shortfall,
                 DONT_CHANGE_AUTO_STAKE_SETTING,
                 DECREASE_STAKE,
             )?;
 
-            // // PRE-MATURITY Withdrawal directly from Basic Stake , not even into Bonding - commented out to bypass 
-            if withdrawal_amount > unbonded_amount {
-                println!("Not Sufficient Matured Unstaked Bonds");
-                // // Deduct 10% and burn it
-                //     burn_amount = (withdrawal_amount - unbonded_amount)
-                //         .checked_mul(Uint128::from(10u128))
-                //         .unwrap_or_default()
-                //         .checked_div(Uint128::from(100u128))
-                //         .unwrap_or_default();
+            if !quote.shortfall.is_zero() {
                 return Err(ContractError::Std(StdError::GenericErr {
                     msg: String::from("Not Sufficient Matured Unstaked Bonds"),
                 }));
             };
 
-            // // Continue if reached here
-            // Remaining 90% transfer to staker wallet
+            match config.early_unbond_policy.destination {
+                PenaltyDestination::Burn => {
+                    burn_amount = quote.penalty;
+                }
+                PenaltyDestination::RewardPool => {
+                    let existing_reward = REWARD.may_load(deps.storage)?.unwrap_or_default();
+                    REWARD.save(deps.storage, &(existing_reward + quote.penalty))?;
+                    penalty_to_reward_pool = quote.penalty;
+                }
+            }
             transfer_confirmed = true;
         } else {
             if withdrawal_amount_in_excess {
@@ -1193,47 +1834,41 @@ fn withdraw_stake_from_a_club(
                 }));
             }
 
-            let all_bonds = CLUB_BONDING_DETAILS.may_load(deps.storage, (&club_name.clone(), &staker.clone()))?.unwrap_or_default();
-            let bonds_for_staker = all_bonds.len() as u64;
-            if config.max_bonding_limit_per_user <= bonds_for_staker {
-                println!("bonds for this staker = {:?}", bonds_for_staker);
-                return Err(ContractError::Std(StdError::GenericErr {
-                    msg: String::from("Too many bonded stakes for this staker"),
-                }));
-            }
-
             let action = "withdrawn_stake_bonded".to_string();
-            // update the staking details
-            save_staking_details(
+            // Move the withdrawn stakes to bonding list
+            save_bonding_details(
                 deps.storage,
                 env.clone(),
-                staker.clone(),
+                &staker_addr,
                 club_name.clone(),
                 withdrawal_amount,
-                DONT_CHANGE_AUTO_STAKE_SETTING,
-                DECREASE_STAKE,
+                config.bonding_duration,
+                config.max_bonding_limit_per_user,
             )?;
 
-            // Move the withdrawn stakes to bonding list
-            save_bonding_details(
+            // update the staking details
+            save_staking_details(
                 deps.storage,
                 env.clone(),
                 staker.clone(),
                 club_name.clone(),
                 withdrawal_amount,
-                config.bonding_duration,
+                DONT_CHANGE_AUTO_STAKE_SETTING,
+                DECREASE_STAKE,
             )?;
 
             let mut rsp = Response::new();
-            let send_bank: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
-                to_address: config.platform_fees_collector_wallet.into_string(),
-                amount: info.funds,
-            });
+            BondCreatedEvent {
+                club_name: &club_name,
+                bonder: &staker,
+                amount: withdrawal_amount,
+                matures_at: env.block.time.plus_seconds(config.bonding_duration),
+            }
+                .add_event(&mut rsp);
 
-            // early exit with only state change and platform fee transfer - no token exchange
+            // early exit with only state change - no token exchange
             let data_msg = format!("Amount {} bonded", withdrawal_amount).into_bytes();
             rsp = rsp
-                .add_message(send_bank)
                 .add_attribute("action", action)
                 .add_attribute("bonded", withdrawal_amount.clone().to_string())
                 .set_data(data_msg);
@@ -1252,6 +1887,13 @@ fn withdraw_stake_from_a_club(
     }
 
     let mut rsp = Response::new();
+    BondReleasedEvent {
+        club_name: &club_name,
+        bonder: &staker,
+        amount: withdrawal_amount,
+        penalty: burn_amount + penalty_to_reward_pool,
+    }
+        .add_event(&mut rsp);
 
     // transfer_with_burn(deps.storage, staker.clone(), withdrawal_amount, burn_amount, "staking_withdraw".to_string())
     if burn_amount > Uint128::zero() {
@@ -1268,9 +1910,12 @@ fn withdraw_stake_from_a_club(
             .add_message(burn_wasm)
             .add_attribute("burnt", burn_amount.to_string());
     }
+    if penalty_to_reward_pool > Uint128::zero() {
+        rsp = rsp.add_attribute("penalty_to_reward_pool", penalty_to_reward_pool.to_string());
+    }
     let transfer_msg = Cw20ExecuteMsg::Transfer {
         recipient: staker,
-        amount: withdrawal_amount - burn_amount,
+        amount: withdrawal_amount - burn_amount - penalty_to_reward_pool,
     };
     let exec = WasmMsg::Execute {
         contract_addr: config.minting_contract_address.to_string(),
@@ -1278,23 +1923,156 @@ fn withdraw_stake_from_a_club(
         funds: vec![],
     };
     let send_wasm: CosmosMsg = CosmosMsg::Wasm(exec);
-    let send_bank: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: config.platform_fees_collector_wallet.into_string(),
-        amount: info.funds,
-    });
 
     let data_msg = format!("Amount {} transferred", withdrawal_amount).into_bytes();
     rsp = rsp
         .add_message(send_wasm)
-        .add_message(send_bank)
         .add_attribute("action", action)
         .add_attribute("withdrawn", withdrawal_amount.clone().to_string())
         .set_data(data_msg);
     return Ok(rsp);
 }
 
-fn save_staking_details(
-    storage: &mut dyn Storage,
+/// Plans an immediate withdrawal from the bonds of a staker. Matured bonds are used first, newest first,
+/// then bonds that have not matured yet, closest to maturity first, at the early unbond penalty.
+/// Returns the split of the withdrawal and the bonds left after it.
+fn plan_immediate_unbonding(
+    bonds: Vec<ClubBondingDetails>,
+    staker: &Addr,
+    withdrawal_amount: Uint128,
+    now: Timestamp,
+    early_unbond_policy: &EarlyUnbondPolicy,
+) -> (EarlyUnbondQuoteResponse, Vec<ClubBondingDetails>) {
+    let mut quote = EarlyUnbondQuoteResponse::default();
+    let mut amount_remaining = withdrawal_amount;
+
+    // sort with descending order of timestamp
+    let mut s_bonds = bonds;
+    s_bonds.sort_by(|a, b| b.bonding_start_timestamp.cmp(&a.bonding_start_timestamp));
+
+    let mut updated_bonds = Vec::new();
+    for bond in s_bonds {
+        let matured = bond.bonding_start_timestamp < now.minus_seconds(bond.bonding_duration);
+        if *staker == bond.bonder_address && matured && amount_remaining > Uint128::zero() {
+            if bond.bonded_amount > amount_remaining {
+                let mut updated_bond = bond.clone();
+                updated_bond.bonded_amount -= amount_remaining;
+                quote.matured_amount += amount_remaining;
+                amount_remaining = Uint128::zero();
+                updated_bonds.push(updated_bond);
+            } else {
+                quote.matured_amount += bond.bonded_amount;
+                amount_remaining -= bond.bonded_amount;
+            }
+        } else {
+            updated_bonds.push(bond);
+        }
+    }
+
+    if early_unbond_policy.curve != PenaltyCurve::Disabled && amount_remaining > Uint128::zero() {
+        let mut early_bonds: Vec<usize> = (0..updated_bonds.len())
+            .filter(|&i| {
+                let bond = &updated_bonds[i];
+                *staker == bond.bonder_address
+                    && bond.bonding_start_timestamp >= now.minus_seconds(bond.bonding_duration)
+            })
+            .collect();
+        early_bonds.sort_by_key(|&i| {
+            updated_bonds[i].bonding_start_timestamp.seconds() + updated_bonds[i].bonding_duration
+        });
+        for i in early_bonds {
+            if amount_remaining.is_zero() {
+                break;
+            }
+            let bond = &mut updated_bonds[i];
+            let unbonded_amount = std::cmp::min(bond.bonded_amount, amount_remaining);
+            let remaining_seconds = (bond.bonding_start_timestamp.seconds() + bond.bonding_duration)
+                .saturating_sub(now.seconds());
+            let penalty = early_unbond_penalty(&early_unbond_policy.curve, remaining_seconds, bond.bonding_duration);
+            quote.early_amount += unbonded_amount;
+            quote.penalty += unbonded_amount.multiply_ratio(penalty, HUNDRED_PERCENT);
+            bond.bonded_amount -= unbonded_amount;
+            amount_remaining -= unbonded_amount;
+        }
+        updated_bonds.retain(|bond| !bond.bonded_amount.is_zero());
+    }
+
+    quote.shortfall = amount_remaining;
+    quote.amount_received = quote.matured_amount + quote.early_amount - quote.penalty;
+    (quote, updated_bonds)
+}
+
+/// Penalty in percentage multiplied by 100 for unbonding remaining_seconds before maturity
+fn early_unbond_penalty(curve: &PenaltyCurve, remaining_seconds: u64, bonding_duration: u64) -> Uint128 {
+    match curve {
+        PenaltyCurve::Disabled => Uint128::zero(),
+        PenaltyCurve::Linear { max_penalty } => {
+            if bonding_duration == 0 {
+                return Uint128::zero();
+            }
+            max_penalty.multiply_ratio(std::cmp::min(remaining_seconds, bonding_duration), bonding_duration)
+        }
+        PenaltyCurve::Stepped { steps } => steps
+            .iter()
+            .filter(|step| step.min_remaining_seconds <= remaining_seconds)
+            .max_by_key(|step| step.min_remaining_seconds)
+            .map(|step| step.penalty)
+            .unwrap_or_default(),
+    }
+}
+
+/// Releases the matured bonds of the sender in one club, or in a page of clubs
+fn claim_matured(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    club_name: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let club_names = match club_name {
+        Some(club_name) => vec![normalize_club_name(&club_name)?],
+        None => club_names_page(deps.storage, start_after, limit),
+    };
+    let mut rsp = Response::new();
+    let mut claimed_amount = Uint128::zero();
+    for club_name in club_names {
+        let amount = CLUB_BONDING_DETAILS.claim_tokens(deps.storage, &club_name, &info.sender, &env.block, None)?;
+        if !amount.is_zero() {
+            BondReleasedEvent {
+                club_name: &club_name,
+                bonder: info.sender.as_str(),
+                amount,
+                penalty: Uint128::zero(),
+            }
+                .add_event(&mut rsp);
+        }
+        claimed_amount += amount;
+    }
+    if claimed_amount.is_zero() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("No matured bonds to claim"),
+        }));
+    }
+
+    let transfer_msg = Cw20ExecuteMsg::Transfer {
+        recipient: info.sender.to_string(),
+        amount: claimed_amount,
+    };
+    let exec = WasmMsg::Execute {
+        contract_addr: config.minting_contract_address.to_string(),
+        msg: to_binary(&transfer_msg).unwrap(),
+        funds: vec![],
+    };
+    return Ok(rsp
+        .add_message(exec)
+        .add_attribute("action", "claim_matured")
+        .add_attribute("claimed", claimed_amount.to_string()));
+}
+
+fn save_staking_details(
+    storage: &mut dyn Storage,
     env: Env,
     staker: String,
     club_name: String,
@@ -1302,9 +2080,12 @@ fn save_staking_details(
     auto_stake: bool,
     increase_stake: bool,
 ) -> Result<Response, ContractError> {
+    // credit the rewards earned so far before the staked amount changes
+    settle_staker_rewards(storage, club_name.clone(), staker.clone())?;
+
     // Get the exising stakes for this club
     let mut stakes = Vec::new();
-    let all_stakes = CLUB_STAKING_DETAILS.may_load(storage, (&club_name.clone(), &staker.clone()))?;
+    let all_stakes = may_load_club_stakes(storage, club_name.clone(), staker.clone())?;
     match all_stakes {
         Some(some_stakes) => {
             stakes = some_stakes;
@@ -1341,9 +2122,11 @@ fn save_staking_details(
     }
     if already_staked == true {
         // save the modified stakes - with updation or removal of existing stake
-        CLUB_STAKING_DETAILS.save(storage, (&club_name.clone(), &staker.clone()), &updated_stakes)?;
+        save_club_stakes(storage, club_name.clone(), staker.clone(), &updated_stakes)?;
     } else if increase_stake == INCREASE_STAKE {
         stakes.push(ClubStakingDetails {
+            // assigned by save_club_stakes
+            stake_id: 0,
             staker_address: staker.clone(),
             staking_start_timestamp: env.block.time,
             staked_amount: amount,
@@ -1351,8 +2134,10 @@ fn save_staking_details(
             club_name: club_name.clone(),
             reward_amount: Uint128::from(CLUB_STAKING_REWARD_AMOUNT), // ensure that the first time reward amount is set to 0
             auto_stake: auto_stake,
+            // rewards distributed before this stake are not applicable to it
+            reward_index: CLUB_REWARD_INDEX.may_load(storage, club_name.clone())?.unwrap_or_default(),
         });
-        CLUB_STAKING_DETAILS.save(storage, (&club_name.clone(), &staker.clone()), &stakes)?;
+        save_club_stakes(storage, club_name.clone(), staker.clone(), &stakes)?;
     }
 
     // Now update the total stake for this club
@@ -1384,28 +2169,20 @@ fn save_staking_details(
 fn save_bonding_details(
     storage: &mut dyn Storage,
     env: Env,
-    bonder: String,
+    bonder: &Addr,
     club_name: String,
     bonded_amount: Uint128,
     duration: u64,
+    max_bonds: u64,
 ) -> Result<Response, ContractError> {
-    // Get the exising bonds for this club
-    let mut bonds = Vec::new();
-    let all_bonds = CLUB_BONDING_DETAILS.may_load(storage, (&club_name.clone(), &bonder.clone()))?;
-    match all_bonds {
-        Some(some_bonds) => {
-            bonds = some_bonds;
-        }
-        None => {}
-    }
-    bonds.push(ClubBondingDetails {
-        bonder_address: bonder.clone(),
+    let bond = ClubBondingDetails {
+        bonder_address: bonder.to_string(),
         bonding_start_timestamp: env.block.time,
         bonded_amount: bonded_amount,
         bonding_duration: duration,
         club_name: club_name.clone(),
-    });
-    CLUB_BONDING_DETAILS.save(storage, (&club_name.clone(), &bonder.clone()), &bonds)?;
+    };
+    CLUB_BONDING_DETAILS.create_claim(storage, &club_name, bonder, bond, Some(max_bonds))?;
     return Ok(Response::default());
 }
 
@@ -1418,7 +2195,7 @@ fn increase_reward_amount(
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     // For SECURITY This message MUST only come from the Admin
-    if info.sender != config.admin_address {
+    if !has_role(deps.storage, Role::Distributor, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
     let existing_reward = REWARD.may_load(deps.storage)?.unwrap_or_default();
@@ -1432,7 +2209,424 @@ fn increase_reward_amount(
     // transfer_from_wallet_to_contract(deps.storage, config.admin_address.to_string(), amount);
     // NOTHING required to transfer anything staking fund has arrived in the staking contract
 
-    return Ok(Response::default());
+    let mut rsp = Response::new();
+    RewardAddedEvent {
+        reward_from: &reward_from,
+        amount,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp);
+}
+
+fn update_reward_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    reward_split_policy: RewardSplitPolicy,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    validate_reward_split_policy(&reward_split_policy)?;
+    config.reward_split_policy = reward_split_policy.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "update_reward_policy")
+        .add_attribute("all_stakers_share", reward_split_policy.all_stakers_share.to_string())
+        .add_attribute("winning_club_stakers_share", reward_split_policy.winning_club_stakers_share.to_string())
+        .add_attribute("winning_club_owners_share", reward_split_policy.winning_club_owners_share.to_string())
+        .add_attribute("non_winning_club_owners_share", reward_split_policy.non_winning_club_owners_share.to_string()));
+}
+
+fn update_fury_fee_discount(
+    deps: DepsMut,
+    info: MessageInfo,
+    fury_fee_discount: Uint128,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    if fury_fee_discount > Uint128::from(HUNDRED_PERCENT) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Fury fee discount can not be more than 100%"),
+        }));
+    }
+    config.fury_fee_discount = fury_fee_discount;
+    CONFIG.save(deps.storage, &config)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "update_fury_fee_discount")
+        .add_attribute("fury_fee_discount", fury_fee_discount.to_string()));
+}
+
+fn update_early_unbond_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    early_unbond_policy: EarlyUnbondPolicy,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    validate_early_unbond_policy(&early_unbond_policy)?;
+    config.early_unbond_policy = early_unbond_policy.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "update_early_unbond_policy")
+        .add_attribute("early_unbond_policy", format!("{:?}", early_unbond_policy)));
+}
+
+fn update_move_stake_cooldown(
+    deps: DepsMut,
+    info: MessageInfo,
+    move_stake_cooldown: u64,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    config.move_stake_cooldown = move_stake_cooldown;
+    CONFIG.save(deps.storage, &config)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "update_move_stake_cooldown")
+        .add_attribute("move_stake_cooldown", move_stake_cooldown.to_string()));
+}
+
+fn register_club(
+    deps: DepsMut,
+    info: MessageInfo,
+    club_name: String,
+    profile: ClubProfile,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let club_name = normalize_club_name(&club_name)?;
+    validate_club_profile(&profile)?;
+    if CLUB_REGISTRY.has(deps.storage, club_name.clone()) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Club is already registered"),
+        }));
+    }
+    CLUB_REGISTRY.save(deps.storage, club_name.clone(), &profile)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "register_club")
+        .add_attribute("club_name", club_name));
+}
+
+fn update_club_profile(
+    deps: DepsMut,
+    info: MessageInfo,
+    club_name: String,
+    profile: ClubProfile,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let club_name = normalize_club_name(&club_name)?;
+    validate_club_profile(&profile)?;
+    if !CLUB_REGISTRY.has(deps.storage, club_name.clone()) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Club is not registered"),
+        }));
+    }
+    CLUB_REGISTRY.save(deps.storage, club_name.clone(), &profile)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "update_club_profile")
+        .add_attribute("club_name", club_name)
+        .add_attribute("active", profile.active.to_string()));
+}
+
+fn update_club_nft_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    club_nft_address: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    config.club_nft_address = match club_nft_address.clone() {
+        Some(address) => Some(deps.api.addr_validate(&address)?),
+        None => None,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let mut rsp = Response::new()
+        .add_attribute("action", "update_club_nft_address")
+        .add_attribute("club_nft_address", club_nft_address.unwrap_or_default());
+    if let Some(club_nft_address) = config.club_nft_address {
+        // clubs owned so far get their NFT from the new contract, unless minted by an earlier page
+        for club_name in club_names_page(deps.storage, start_after, limit) {
+            let owner: StdResult<OwnerOfResponse> = deps.querier.query_wasm_smart(
+                club_nft_address.clone(),
+                &Cw721QueryMsg::OwnerOf {
+                    token_id: club_name.clone(),
+                    include_expired: None,
+                },
+            );
+            if owner.is_ok() {
+                continue;
+            }
+            let club = CLUB_OWNERSHIP_DETAILS.load(deps.storage, club_name)?;
+            let mint_msg = club_nft_mint_msg(deps.storage, &club_nft_address, &club.club_name, &club.owner_address)?;
+            rsp = rsp.add_message(mint_msg);
+        }
+    }
+    Ok(rsp)
+}
+
+/// Mints the NFT of a club for its first owner, or transfers it to the new owner, when club NFTs are enabled.
+/// The transfer requires the current holder to have approved this contract for the NFT
+fn club_nft_msg(deps: Deps, club_name: &str, new_owner: &str) -> StdResult<Option<CosmosMsg>> {
+    let config = CONFIG.load(deps.storage)?;
+    let club_nft_address = match config.club_nft_address {
+        Some(address) => address,
+        None => return Ok(None),
+    };
+    if !CLUB_OWNERSHIP_DETAILS.has(deps.storage, club_name.to_string()) {
+        return club_nft_mint_msg(deps.storage, &club_nft_address, club_name, new_owner).map(Some);
+    }
+    let transfer_msg = Cw721ExecuteMsg::<Extension>::TransferNft {
+        recipient: new_owner.to_string(),
+        token_id: club_name.to_string(),
+    };
+    Ok(Some(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: club_nft_address.to_string(),
+        msg: to_binary(&transfer_msg)?,
+        funds: vec![],
+    })))
+}
+
+fn club_nft_mint_msg(
+    storage: &dyn Storage,
+    club_nft_address: &Addr,
+    club_name: &str,
+    owner: &str,
+) -> StdResult<CosmosMsg> {
+    let profile = CLUB_REGISTRY.load(storage, club_name.to_string())?;
+    let mint_msg = Cw721ExecuteMsg::Mint(MintMsg::<Extension> {
+        token_id: club_name.to_string(),
+        owner: owner.to_string(),
+        name: profile.display_name,
+        description: Some(format!("{} - {}", profile.sport, profile.league)),
+        image: profile.logo_url,
+        extension: None,
+    });
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: club_nft_address.to_string(),
+        msg: to_binary(&mint_msg)?,
+        funds: vec![],
+    }))
+}
+
+/// With club NFTs enabled the holder of the NFT owns the club, otherwise the recorded owner
+fn current_club_owner(deps: Deps, ownership_details: &ClubOwnershipDetails) -> StdResult<String> {
+    let config = CONFIG.load(deps.storage)?;
+    match config.club_nft_address {
+        Some(club_nft_address) => {
+            let owner: StdResult<OwnerOfResponse> = deps.querier.query_wasm_smart(
+                club_nft_address,
+                &Cw721QueryMsg::OwnerOf {
+                    token_id: ownership_details.club_name.clone(),
+                    include_expired: None,
+                },
+            );
+            match owner {
+                Ok(owner) => Ok(owner.owner),
+                // not minted yet while UpdateClubNftAddress is paging through the clubs
+                Err(_) => Ok(ownership_details.owner_address.clone()),
+            }
+        }
+        None => Ok(ownership_details.owner_address.clone()),
+    }
+}
+
+/// Whether the address owns any club, see current_club_owner
+fn owns_a_club(deps: Deps, address: &str) -> StdResult<bool> {
+    for item in CLUB_OWNERSHIP_DETAILS.range(deps.storage, None, None, Order::Ascending) {
+        let (_, ownership_details) = item?;
+        if current_club_owner(deps, &ownership_details)? == address {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Club names are kept in upper case and made of ASCII letters, digits and underscores
+fn normalize_club_name(club_name: &str) -> StdResult<String> {
+    let normalized = club_name.trim().to_uppercase();
+    if normalized.len() < MIN_CLUB_NAME_LENGTH
+        || normalized.len() > MAX_CLUB_NAME_LENGTH
+        || !normalized.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(StdError::GenericErr {
+            msg: format!(
+                "Club name must have {} to {} letters, digits or underscores",
+                MIN_CLUB_NAME_LENGTH, MAX_CLUB_NAME_LENGTH
+            ),
+        });
+    }
+    Ok(normalized)
+}
+
+fn validate_club_profile(profile: &ClubProfile) -> Result<(), ContractError> {
+    if profile.display_name.trim().is_empty() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Club display name can not be empty"),
+        }));
+    }
+    Ok(())
+}
+
+/// Only registered and active clubs can be bought or staked on.
+/// Returns the normalized club name the club is registered under
+fn assert_club_active(storage: &dyn Storage, club_name: &str) -> Result<String, ContractError> {
+    let club_name = normalize_club_name(club_name)?;
+    match CLUB_REGISTRY.may_load(storage, club_name.clone())? {
+        None => Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Club is not registered"),
+        })),
+        Some(profile) if !profile.active => Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Club is not active"),
+        })),
+        Some(_) => Ok(club_name),
+    }
+}
+
+fn validate_early_unbond_policy(early_unbond_policy: &EarlyUnbondPolicy) -> Result<(), ContractError> {
+    let penalties = match &early_unbond_policy.curve {
+        PenaltyCurve::Disabled => vec![],
+        PenaltyCurve::Linear { max_penalty } => vec![*max_penalty],
+        PenaltyCurve::Stepped { steps } => steps.iter().map(|step| step.penalty).collect(),
+    };
+    if penalties.iter().any(|penalty| *penalty > Uint128::from(HUNDRED_PERCENT)) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Early unbond penalty can not be more than 100%"),
+        }));
+    }
+    Ok(())
+}
+
+fn set_paused(
+    deps: DepsMut,
+    info: MessageInfo,
+    buy: Option<bool>,
+    stake: Option<bool>,
+    withdraw: Option<bool>,
+    claim: Option<bool>,
+    distribute: Option<bool>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let previous_status = PAUSE_STATUS.may_load(deps.storage)?.unwrap_or_default();
+    let mut pause_status = previous_status.clone();
+    pause_status.buy = buy.unwrap_or(pause_status.buy);
+    pause_status.stake = stake.unwrap_or(pause_status.stake);
+    pause_status.withdraw = withdraw.unwrap_or(pause_status.withdraw);
+    pause_status.claim = claim.unwrap_or(pause_status.claim);
+    pause_status.distribute = distribute.unwrap_or(pause_status.distribute);
+    PAUSE_STATUS.save(deps.storage, &pause_status)?;
+
+    let switches = [
+        ("buy", previous_status.buy, pause_status.buy),
+        ("stake", previous_status.stake, pause_status.stake),
+        ("withdraw", previous_status.withdraw, pause_status.withdraw),
+        ("claim", previous_status.claim, pause_status.claim),
+        ("distribute", previous_status.distribute, pause_status.distribute),
+    ];
+    let mut rsp = Response::new();
+    for paused in [true, false] {
+        let operations: Vec<&str> = switches
+            .iter()
+            .filter(|(_, was_paused, is_paused)| *was_paused != paused && *is_paused == paused)
+            .map(|(operation, _, _)| *operation)
+            .collect();
+        if !operations.is_empty() {
+            PauseSetEvent {
+                paused,
+                operations: &operations,
+            }
+                .add_event(&mut rsp);
+        }
+    }
+    return Ok(rsp
+        .add_attribute("action", "set_paused")
+        .add_attribute("buy", pause_status.buy.to_string())
+        .add_attribute("stake", pause_status.stake.to_string())
+        .add_attribute("withdraw", pause_status.withdraw.to_string())
+        .add_attribute("claim", pause_status.claim.to_string())
+        .add_attribute("distribute", pause_status.distribute.to_string()));
+}
+
+fn validate_reward_split_policy(
+    reward_split_policy: &RewardSplitPolicy,
+) -> Result<(), ContractError> {
+    let total = reward_split_policy.all_stakers_share
+        .checked_add(reward_split_policy.winning_club_stakers_share)
+        .and_then(|t| t.checked_add(reward_split_policy.winning_club_owners_share))
+        .and_then(|t| t.checked_add(reward_split_policy.non_winning_club_owners_share))
+        .map_err(StdError::from)?;
+    if total != Uint128::from(HUNDRED_PERCENT) {
+        return Err(ContractError::InvalidRewardSplitPolicy { total });
+    }
+    Ok(())
+}
+
+fn update_winning_club_strategy(
+    deps: DepsMut,
+    info: MessageInfo,
+    winning_club_strategy: WinningClubStrategy,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_admin(deps.as_ref(), &info.sender)?;
+    validate_winning_club_strategy(&winning_club_strategy)?;
+    config.winning_club_strategy = winning_club_strategy.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "update_winning_club_strategy")
+        .add_attribute("winning_club_strategy", format!("{:?}", winning_club_strategy)));
+}
+
+fn validate_winning_club_strategy(
+    winning_club_strategy: &WinningClubStrategy,
+) -> Result<(), ContractError> {
+    match winning_club_strategy {
+        WinningClubStrategy::TopClubsByIncrementalStake { count: 0 }
+        | WinningClubStrategy::AdminRanking { count: 0 } => {
+            Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Number of winning clubs must be more than 0"),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn set_club_ranking(
+    deps: DepsMut,
+    info: MessageInfo,
+    club_ranking: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !has_role(deps.storage, Role::Distributor, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let club_ranking = club_ranking
+        .iter()
+        .map(|club_name| normalize_club_name(club_name))
+        .collect::<StdResult<Vec<String>>>()?;
+    for (i, club_name) in club_ranking.iter().enumerate() {
+        if !CLUB_OWNERSHIP_DETAILS.has(deps.storage, club_name.clone()) {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: format!("Unknown club {} in ranking", club_name),
+            }));
+        }
+        if club_ranking[..i].contains(club_name) {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: format!("Club {} ranked more than once", club_name),
+            }));
+        }
+    }
+    CLUB_RANKING.save(deps.storage, &club_ranking)?;
+
+    return Ok(Response::new()
+        .add_attribute("action", "set_club_ranking")
+        .add_attribute("club_ranking", club_ranking.join(",")));
 }
 
 fn claim_staker_rewards(
@@ -1441,6 +2635,7 @@ fn claim_staker_rewards(
     staker: String,
     club_name: String,
 ) -> Result<Response, ContractError> {
+    let club_name = normalize_club_name(&club_name)?;
     let mut transfer_confirmed = false;
     let mut amount = Uint128::zero();
     let staker_addr = deps.api.addr_validate(&staker)?;
@@ -1449,32 +2644,11 @@ fn claim_staker_rewards(
         return Err(ContractError::Unauthorized {});
     }
 
-    let required_ust_fees = query_platform_fees(
-        deps.as_ref(),
-        to_binary(&ExecuteMsg::ClaimStakerRewards {
-            staker: staker.clone(),
-            club_name: club_name.clone(),
-        })?,
-    )?;
-    let mut fees = Uint128::zero();
-    for fund in info.funds.clone() {
-        if fund.denom == uusd(&deps)? {
-            fees = fees.checked_add(fund.amount).unwrap();
-        }
-    }
-    let adjusted_ust_fees = required_ust_fees
-        * (Uint128::from(NINETY_NINE_NINE_PERCENT))
-        / (Uint128::from(HUNDRED_PERCENT));
-    if fees < adjusted_ust_fees {
-        return Err(ContractError::InsufficientFees {
-            required: required_ust_fees,
-            received: fees,
-        });
-    }
+    settle_staker_rewards(deps.storage, club_name.clone(), staker.clone())?;
 
     // Get the exising stakes for this club
     let mut stakes = Vec::new();
-    let all_stakes = CLUB_STAKING_DETAILS.may_load(deps.storage, (&club_name.clone(), &staker.clone()))?;
+    let all_stakes = may_load_club_stakes(deps.storage, club_name.clone(), staker.clone())?;
     match all_stakes {
         Some(some_stakes) => {
             stakes = some_stakes;
@@ -1494,8 +2668,7 @@ fn claim_staker_rewards(
         }
         updated_stakes.push(updated_stake);
     }
-    //CLUB_STAKING_DETAILS.save(deps.storage, (&club_name.clone(), &staker.clone()), &stakes)?;
-    CLUB_STAKING_DETAILS.save(deps.storage, (&club_name.clone(), &staker.clone()), &updated_stakes)?;
+    save_club_stakes(deps.storage, club_name.clone(), staker.clone(), &updated_stakes)?;
 
     if transfer_confirmed == false {
         return Err(ContractError::Std(StdError::GenericErr {
@@ -1520,352 +2693,661 @@ fn claim_staker_rewards(
         funds: vec![],
     };
     let send_wasm: CosmosMsg = CosmosMsg::Wasm(exec);
-    let send_bank: CosmosMsg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: config.platform_fees_collector_wallet.into_string(),
-        amount: info.funds,
-    });
     let data_msg = format!("Amount {} transferred", amount).into_bytes();
-    return Ok(Response::new()
+    let mut rsp = Response::new();
+    RewardClaimedEvent {
+        claimant_type: "staker",
+        claimant: &staker,
+        club_name: Some(&club_name),
+        amount,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
         .add_message(send_wasm)
-        .add_message(send_bank)
         .add_attribute("action", "staking_reward_claim")
         .add_attribute("staker", staker)
         .add_attribute("amount", amount.to_string())
         .set_data(data_msg));
 }
 
-fn calculate_and_distribute_rewards(
+fn set_auto_stake(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    staker_list: Vec<String>,
     club_name: String,
-    is_first_batch: bool,
-    is_final_batch: bool,
+    auto_stake: bool,
 ) -> Result<Response, ContractError> {
-    // Check if this is executed by main/transaction wallet
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Std(StdError::GenericErr {
-            msg: String::from("not authorised"),
-        }));
-    }
-    let total_reward = REWARD.may_load(deps.storage)?.unwrap_or_default();
-
-    let mut next_reward_time = CLUB_REWARD_NEXT_TIMESTAMP
-        .may_load(deps.storage)?
-        .unwrap_or_default();
-    println!(
-        "now = {:?} next_reward_time = {:?} periodicity = {:?} is_first_batch = {:?} is_final_batch = {:?}",
-        env.block.time, next_reward_time, config.reward_periodicity, is_first_batch, is_final_batch
-    );
-
-    let saved_next_reward_timestamp = next_reward_time;
+    let club_name = normalize_club_name(&club_name)?;
+    let staker = info.sender.to_string();
+    // rewards so far are credited under the previous setting
+    settle_staker_rewards(deps.storage, club_name.clone(), staker.clone())?;
 
-    if env.block.time < next_reward_time {
-        println!("Time for Reward not yet arrived");
+    let stakes = may_load_club_stakes(deps.storage, club_name.clone(), staker.clone())?.unwrap_or_default();
+    if stakes.is_empty() {
         return Err(ContractError::Std(StdError::GenericErr {
-            msg: String::from("Time for Reward not yet arrived"),
+            msg: String::from("User has not staked in this club"),
         }));
     }
-    if is_final_batch {
-        if next_reward_time < env.block.time {
-            next_reward_time = next_reward_time.plus_seconds(config.reward_periodicity);
-        }
-        println!("setting next_reward_time = {:?}", next_reward_time);
-        CLUB_REWARD_NEXT_TIMESTAMP.save(deps.storage, &next_reward_time)?;
-    }
+    let updated_stakes = stakes
+        .into_iter()
+        .map(|mut stake| {
+            stake.auto_stake = auto_stake;
+            stake
+        })
+        .collect();
+    save_club_stakes(deps.storage, club_name.clone(), staker.clone(), &updated_stakes)?;
 
-    // No need to calculate if there is no reward amount
-    if total_reward == Uint128::zero() {
-        return Ok(Response::new().add_attribute("response", "no accumulated rewards")
-            .add_attribute("next_timestamp", next_reward_time.to_string())
-        );
+    let mut rsp = Response::new();
+    AutoStakeSetEvent {
+        club_name: &club_name,
+        staker: &staker,
+        auto_stake,
     }
-    distribute_reward_to_club_stakers(deps, env, config.reward_periodicity, saved_next_reward_timestamp,
-                                      staker_list.clone(), club_name.clone(), total_reward, is_first_batch, is_final_batch)
+        .add_event(&mut rsp);
+    return Ok(rsp
+        .add_attribute("action", "set_auto_stake")
+        .add_attribute("staker", staker)
+        .add_attribute("club_name", club_name)
+        .add_attribute("auto_stake", auto_stake.to_string()));
 }
 
-fn distribute_reward_to_club_stakers(
+fn compound_rewards(
     deps: DepsMut,
-    env: Env,
-    reward_periodicity: u64,
-    saved_next_reward_timestamp: Timestamp,
-    staker_list: Vec<String>,
+    info: MessageInfo,
     club_name: String,
-    total_reward: Uint128,
-    is_first_batch: bool,
-    is_final_batch: bool,
 ) -> Result<Response, ContractError> {
-    let mut winning_clubs_info: WinningClubDetails;
-    if is_first_batch {
-        let response = get_winning_clubs_details(deps.storage)?;
-        winning_clubs_info = WinningClubDetails {
-            total_number_of_clubs: response.0,
-            total_stake_across_all_clubs: response.1,
-            total_stake_in_winning_club: response.2,
-            winner_list: response.3.clone(),
-        };
-        WINNING_CLUB_DETAILS_SNAPSHOT.save(deps.storage, &winning_clubs_info);
-    } else {
-        winning_clubs_info = WINNING_CLUB_DETAILS_SNAPSHOT.may_load(deps.storage)?.unwrap_or_default();
-    }
-    println!("winning_clubs_info = {:?}", winning_clubs_info);
-    let total_number_of_clubs = winning_clubs_info.total_number_of_clubs;
-    let total_stake_across_all_clubs = winning_clubs_info.total_stake_across_all_clubs;
-    let total_stake_in_winning_club = winning_clubs_info.total_stake_in_winning_club;
-    let winner_list = winning_clubs_info.winner_list.clone();
-    let is_club_a_winner = is_winning_club(club_name.clone(), winner_list.clone());
-    let club_details = query_club_ownership_details(deps.storage, club_name.clone())?;
-    let club_owner_address = club_details.owner_address.clone();
-    let num_of_winners = winner_list.len() as u64;
-    let other_club_count = total_number_of_clubs - num_of_winners;
+    let club_name = normalize_club_name(&club_name)?;
+    let staker = info.sender.to_string();
+    settle_staker_rewards(deps.storage, club_name.clone(), staker.clone())?;
 
-    if !is_club_a_winner && other_club_count <= 0 {
+    let stakes = may_load_club_stakes(deps.storage, club_name.clone(), staker.clone())?.unwrap_or_default();
+    let mut amount = Uint128::zero();
+    let mut updated_stakes = Vec::new();
+    for mut stake in stakes {
+        amount += stake.reward_amount;
+        stake.staked_amount += stake.reward_amount;
+        stake.reward_amount = Uint128::zero();
+        updated_stakes.push(stake);
+    }
+    if amount.is_zero() {
         return Err(ContractError::Std(StdError::GenericErr {
-            msg: String::from("computation error - should never happen"),
+            msg: String::from("No rewards for this user"),
         }));
     }
+    save_club_stakes(deps.storage, club_name.clone(), staker.clone(), &updated_stakes)?;
 
-    let mut owner_reward = Uint128::zero();
-    let mut reward_for_all_stakers_in_winning_club = Uint128::zero();
+    // the rewards are already held by the contract, only the club total changes
+    let mut club_details = query_club_ownership_details(deps.storage, club_name.clone())?;
+    club_details.total_staked_amount += amount;
+    CLUB_OWNERSHIP_DETAILS.save(deps.storage, club_name.clone(), &club_details)?;
 
-    if is_club_a_winner {
-        if other_club_count > 0 {
-            // distribute 1% equally to owners in this winning club
-            owner_reward = total_reward
-                .checked_div(Uint128::from(100u128))
-                .unwrap_or_default()
-                .checked_div(Uint128::from(num_of_winners))
-                .unwrap_or_default();
-            println!("club_name {:?} owner reward for this winner is {:?}", club_name.clone(), owner_reward);
-        } else {
-            // there are only winning clubs
-            // distribute 3% equally to owners in this winning club
-            owner_reward = total_reward
-                .checked_mul(Uint128::from(3u128))
-                .unwrap_or_default()
-                .checked_div(Uint128::from(100u128))
-                .unwrap_or_default()
-                .checked_div(Uint128::from(num_of_winners))
+    let mut rsp = Response::new();
+    RewardsCompoundedEvent {
+        club_name: &club_name,
+        staker: &staker,
+        amount,
+    }
+        .add_event(&mut rsp);
+    return Ok(rsp
+        .add_attribute("action", "compound_rewards")
+        .add_attribute("staker", staker)
+        .add_attribute("club_name", club_name)
+        .add_attribute("amount", amount.to_string()));
+}
+
+fn calculate_and_distribute_rewards(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    // Check if this is executed by main/transaction wallet
+    let config = CONFIG.load(deps.storage)?;
+    if !has_role(deps.storage, Role::Distributor, &info.sender) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("not authorised"),
+        }));
+    }
+    let mut progress = match REWARD_DISTRIBUTION_PROGRESS.may_load(deps.storage)? {
+        Some(progress) => progress,
+        None => {
+            let total_reward = REWARD.may_load(deps.storage)?.unwrap_or_default();
+
+            let mut next_reward_time = CLUB_REWARD_NEXT_TIMESTAMP
+                .may_load(deps.storage)?
                 .unwrap_or_default();
-            println!("all clubs are winners club_name {:?} owner reward for this winner is {:?}", club_name.clone(), owner_reward);
-        }
-        // distribute 19% to stakers in winning club
-        reward_for_all_stakers_in_winning_club = total_reward
-            .checked_mul(Uint128::from(19u128))
-            .unwrap_or_default()
-            .checked_div(Uint128::from(100u128))
-            .unwrap_or_default()
+
+            if env.block.time < next_reward_time {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Time for Reward not yet arrived"),
+                }));
+            }
+            if next_reward_time < env.block.time {
+                next_reward_time = next_reward_time.plus_seconds(config.reward_periodicity);
+            }
+
+            // No need to calculate if there is no reward amount
+            if total_reward == Uint128::zero() {
+                CLUB_REWARD_NEXT_TIMESTAMP.save(deps.storage, &next_reward_time)?;
+                return Ok(Response::new().add_attribute("response", "no accumulated rewards")
+                    .add_attribute("next_timestamp", next_reward_time.to_string())
+                );
+            }
+            RewardDistributionProgress {
+                total_reward,
+                next_timestamp: next_reward_time,
+                ..RewardDistributionProgress::default()
+            }
+        }
+    };
+
+    // each call takes the standings of, and then credits, at most limit clubs
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT).max(1) as usize;
+    if !progress.standings_taken {
+        take_club_standings(deps.storage, &config.winning_club_strategy, &mut progress, limit)?;
+    }
+    let mut completed = false;
+    if progress.standings_taken {
+        completed = distribute_reward_to_club_stakers(
+            deps.branch(),
+            env.block.time,
+            config.reward_split_policy,
+            &mut progress,
+            limit,
+        )?;
+    }
+    if !completed {
+        REWARD_DISTRIBUTION_PROGRESS.save(deps.storage, &progress)?;
+        return Ok(Response::new()
+            .add_attribute("action", "calculate_and_distribute_rewards")
+            .add_attribute("response", "reward distribution in progress")
+            .add_attribute("last_club", progress.last_club.unwrap_or_default()));
+    }
+    REWARD_DISTRIBUTION_PROGRESS.remove(deps.storage);
+
+    let total_reward = progress.total_reward;
+    let reward_given = progress.reward_given;
+    let next_reward_time = progress.next_timestamp;
+    CLUB_REWARD_NEXT_TIMESTAMP.save(deps.storage, &next_reward_time)?;
+    let mut rsp = Response::new();
+    RewardDistributedEvent {
+        reward_period: REWARD_PERIOD.load(deps.storage)?,
+        total_reward,
+        reward_given,
+        next_timestamp: next_reward_time,
+    }
+        .add_event(&mut rsp);
+    Ok(rsp
+        .add_attribute("action", "calculate_and_distribute_rewards")
+        .add_attribute("total_reward", total_reward.to_string())
+        .add_attribute("reward_given", reward_given.to_string())
+        .add_attribute("next_timestamp", next_reward_time.to_string()))
+}
+
+/// Rejects the messages changing the clubs or their stakes while a reward
+/// distribution is in progress, as the standings already taken would no longer hold
+fn check_no_reward_distribution_in_progress(storage: &dyn Storage, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    let changes_standings = match msg {
+        ExecuteMsg::BuyAClub { .. }
+        | ExecuteMsg::AssignAClub { .. }
+        | ExecuteMsg::ReleaseClub { .. }
+        | ExecuteMsg::StakeOnAClub { .. }
+        | ExecuteMsg::MoveStake { .. }
+        | ExecuteMsg::SetAutoStake { .. }
+        | ExecuteMsg::CompoundRewards { .. }
+        | ExecuteMsg::AssignStakesToAClub { .. }
+        | ExecuteMsg::StakeWithdrawFromAClub { .. }
+        | ExecuteMsg::ClaimStakerRewards { .. } => true,
+        _ => false,
+    };
+    if changes_standings && REWARD_DISTRIBUTION_PROGRESS.may_load(storage)?.is_some() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Reward distribution in progress"),
+        }));
+    }
+    Ok(())
+}
+
+/// Takes the stake standings of the next page of clubs for the reward distribution.
+/// Once all clubs are taken, picks the winning clubs and starts the reward period.
+fn take_club_standings(
+    storage: &mut dyn Storage,
+    strategy: &WinningClubStrategy,
+    progress: &mut RewardDistributionProgress,
+    limit: usize,
+) -> StdResult<()> {
+    let start = progress.last_club.clone().map(Bound::exclusive);
+    let mut clubs: Vec<(Vec<u8>, ClubOwnershipDetails)> = CLUB_OWNERSHIP_DETAILS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit + 1)
+        .collect::<StdResult<_>>()?;
+    let more_clubs = clubs.len() > limit;
+    clubs.truncate(limit);
+    let club_ranking = CLUB_RANKING.may_load(storage)?.unwrap_or_default();
+    for (_, club_details) in clubs {
+        let club_name = club_details.club_name.clone();
+        let standing = ClubStakeStanding {
+            club_name: club_name.clone(),
+            total_stake: club_details.total_staked_amount,
+            previous_stake: CLUB_STAKING_SNAPSHOT.may_load(storage, club_name.clone())?.unwrap_or_default(),
+        };
+        CLUB_STAKING_SNAPSHOT.save(storage, club_name.clone(), &standing.total_stake)?;
+        progress.total_number_of_clubs += 1;
+        progress.total_stake_across_all_clubs += standing.total_stake;
+        add_to_leaders(strategy, &mut progress.leaders, standing, &club_ranking);
+        progress.last_club = Some(club_name);
+    }
+    if more_clubs {
+        return Ok(());
+    }
+
+    let winners = select_winning_clubs(strategy, &progress.leaders, &club_ranking)?;
+    // a ranking is only applicable to the reward period it was submitted for
+    CLUB_RANKING.remove(storage);
+
+    let mut total_stake_in_winning_club = Uint128::zero();
+    for standing in progress.leaders.iter() {
+        if is_winning_club(standing.club_name.clone(), winners.clone()) {
+            total_stake_in_winning_club += standing.total_stake;
+        }
+    }
+    let winning_clubs_info = WinningClubDetails {
+        total_number_of_clubs: progress.total_number_of_clubs,
+        total_stake_across_all_clubs: progress.total_stake_across_all_clubs,
+        total_stake_in_winning_club,
+        winner_list: winners,
+    };
+    WINNING_CLUB_DETAILS_SNAPSHOT.save(storage, &winning_clubs_info)?;
+    let reward_period = REWARD_PERIOD.may_load(storage)?.unwrap_or_default() + 1;
+    REWARD_PERIOD.save(storage, &reward_period)?;
+    WINNING_CLUB_DETAILS_HISTORY.save(storage, U64Key::new(reward_period), &winning_clubs_info)?;
+
+    progress.standings_taken = true;
+    progress.last_club = None;
+    Ok(())
+}
+
+/// Keeps the clubs that can still be picked as winners once the standings of
+/// all clubs are taken, so that the standings need not be kept for every club
+fn add_to_leaders(
+    strategy: &WinningClubStrategy,
+    leaders: &mut Vec<ClubStakeStanding>,
+    standing: ClubStakeStanding,
+    club_ranking: &[String],
+) {
+    match strategy {
+        WinningClubStrategy::LargestIncrementalStake => {
+            keep_best(leaders, standing, |s| (s.incremental_stake(), s.total_stake))
+        }
+        WinningClubStrategy::LargestTotalStake => keep_best(leaders, standing, |s| s.total_stake),
+        WinningClubStrategy::TopClubsByIncrementalStake { count } => {
+            leaders.push(standing);
+            // clubs are taken in order of their name and sort is stable,
+            // so clubs with the same standing stay in order of their name
+            leaders.sort_by(|a, b| {
+                (b.incremental_stake(), b.total_stake).cmp(&(a.incremental_stake(), a.total_stake))
+            });
+            leaders.truncate(*count as usize);
+        }
+        WinningClubStrategy::AdminRanking { .. } => {
+            if club_ranking.contains(&standing.club_name) {
+                leaders.push(standing);
+            }
+        }
+    }
+}
+
+/// Keeps the clubs with the best standing as per key, all of them on a tie
+fn keep_best<K: Ord>(
+    leaders: &mut Vec<ClubStakeStanding>,
+    standing: ClubStakeStanding,
+    key: impl Fn(&ClubStakeStanding) -> K,
+) {
+    match leaders.first().map(|leader| key(leader).cmp(&key(&standing))) {
+        Some(Ordering::Greater) => {}
+        Some(Ordering::Equal) => leaders.push(standing),
+        _ => *leaders = vec![standing],
+    }
+}
+
+/// Moves the reward index of the next page of clubs forward by their share of
+/// the reward and credits the owner rewards. Stakers are not touched here, their
+/// share is settled against the index on their next stake, withdrawal or claim.
+/// Returns whether all clubs are credited, the remainder of the reward then stays
+/// in REWARD and the split is recorded in REWARD_PERIOD_HISTORY for audit.
+fn distribute_reward_to_club_stakers(
+    deps: DepsMut,
+    now: Timestamp,
+    reward_split_policy: RewardSplitPolicy,
+    progress: &mut RewardDistributionProgress,
+    limit: usize,
+) -> Result<bool, ContractError> {
+    let total_reward = progress.total_reward;
+    let winning_clubs_info = WINNING_CLUB_DETAILS_SNAPSHOT.load(deps.storage)?;
+    let reward_period = REWARD_PERIOD.load(deps.storage)?;
+
+    let total_number_of_clubs = winning_clubs_info.total_number_of_clubs;
+    let total_stake_across_all_clubs = winning_clubs_info.total_stake_across_all_clubs;
+    let winner_list = winning_clubs_info.winner_list.clone();
+    let num_of_winners = winner_list.len() as u64;
+    let other_club_count = total_number_of_clubs - num_of_winners;
+    let winner_owner_reward;
+    let mut non_winner_owner_reward = Uint128::zero();
+    if other_club_count > 0 {
+        // distribute winning club owners share equally to owners in winning clubs
+        winner_owner_reward = share_of_reward(total_reward, reward_split_policy.winning_club_owners_share)
             .checked_div(Uint128::from(num_of_winners))
             .unwrap_or_default();
-        println!("club_name {:?} stakers award in winner is {:?}", club_name.clone(), reward_for_all_stakers_in_winning_club);
-    } else {
-        // other_club_count must be greater than 0
-        // distribute 2% equally to owner in this non winning club
-        owner_reward = total_reward
-            .checked_mul(Uint128::from(2u128))
-            .unwrap_or_default()
-            .checked_div(Uint128::from(100u128))
-            .unwrap_or_default()
+        // distribute non winning club owners share equally to owners in non winning clubs
+        non_winner_owner_reward = share_of_reward(total_reward, reward_split_policy.non_winning_club_owners_share)
             .checked_div(Uint128::from(other_club_count))
             .unwrap_or_default();
-        println!("club_name {:?} owner reward for non winner is {:?}", club_name.clone(), owner_reward);
+    } else {
+        // there are only winning clubs
+        // distribute both owners shares equally to owners in winning clubs
+        winner_owner_reward = share_of_reward(
+            total_reward,
+            reward_split_policy.winning_club_owners_share + reward_split_policy.non_winning_club_owners_share,
+        )
+            .checked_div(Uint128::from(num_of_winners))
+            .unwrap_or_default();
     }
 
-    // distribute the 78% to all stakers
-    let all_stakers_reward = total_reward
-        .checked_mul(Uint128::from(78u128))
-        .unwrap_or_default()
-        .checked_div(Uint128::from(100u128))
+    // distribute winning club stakers share to stakers in winning clubs
+    let reward_for_all_stakers_in_winning_club = share_of_reward(total_reward, reward_split_policy.winning_club_stakers_share)
+        .checked_div(Uint128::from(num_of_winners))
         .unwrap_or_default();
 
-    let mut reward_given_so_far = Uint128::zero();
-    let mut stake_to_add_for_club = Uint128::zero();
-    for staker in staker_list {
-        let mut updated_stakes_for_this_staker = Vec::new();
-        let csd = CLUB_STAKING_DETAILS.may_load(deps.storage, (&club_name.clone(), &staker.clone()))?;
-        let staking_details;
-        match csd {
-            None => {}
-            Some(some_csd) => {
-                staking_details = some_csd;
-
-                for mut stake in staking_details {
-                    let mut updated_stake = stake.clone();
-                    println!("stake = {:?}", updated_stake);
-                    if updated_stake.staking_start_timestamp > env.block.time {
-                        continue;
-                    }
-                    if saved_next_reward_timestamp < env.block.time {
-                        updated_stake.staking_start_timestamp = saved_next_reward_timestamp.plus_seconds(reward_periodicity);
-                        println!("setting timestamp for stake = {:?}", updated_stake.staking_start_timestamp);
-                    } else {
-                        updated_stake.staking_start_timestamp = saved_next_reward_timestamp;
-                        println!("setting timestamp for stake = {:?}", updated_stake.staking_start_timestamp);
-                    }
-
-                    let auto_stake = updated_stake.auto_stake;
-
-                    // Calculate for All Staker - 78% proportional
-                    let mut reward_for_this_stake = all_stakers_reward
-                        .checked_mul(stake.staked_amount)
-                        .unwrap_or_default()
-                        .checked_div(total_stake_across_all_clubs)
-                        .unwrap_or_default();
-
-                    if is_club_a_winner {
-                        // Calculate for Winning Club Staker - 19% proportional
-                        reward_for_this_stake += reward_for_all_stakers_in_winning_club
-                            .checked_mul(stake.staked_amount)
-                            .unwrap_or_default()
-                            .checked_div(total_stake_in_winning_club)
-                            .unwrap_or_default();
-                    }
-
-                    if stake.staker_address == club_owner_address {
-                        // Calculate for Club Owner - (1% or 3% for winner owner) or 2% proportional for non-winner owner
-                        reward_for_this_stake += owner_reward;
-                    }
+    // distribute all stakers share to all stakers
+    let all_stakers_reward = share_of_reward(total_reward, reward_split_policy.all_stakers_share);
 
-                    reward_given_so_far += reward_for_this_stake;
+    let start = progress.last_club.clone().map(Bound::exclusive);
+    let mut clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|k| String::from_utf8(k).unwrap())
+        .collect();
+    let more_clubs = clubs.len() > limit;
+    clubs.truncate(limit);
+    for club_name in clubs {
+        let club_details = query_club_ownership_details(deps.storage, club_name.clone())?;
+        let stake_in_club = club_details.total_staked_amount;
+        let is_club_a_winner = is_winning_club(club_name.clone(), winner_list.clone());
+        let owner_address = current_club_owner(deps.as_ref(), &club_details)?;
+        let mut club_reward = ClubRewardRecord {
+            club_name: club_name.clone(),
+            owner_address: owner_address.clone(),
+            total_staked_amount: stake_in_club,
+            ..ClubRewardRecord::default()
+        };
 
-                    if auto_stake == SET_AUTO_STAKE {
-                        stake_to_add_for_club += reward_for_this_stake;
-                        updated_stake.staked_amount += reward_for_this_stake;
-                        updated_stake.staked_amount += updated_stake.reward_amount;
-                        updated_stake.reward_amount = Uint128::zero();
-                    } else {
-                        updated_stake.reward_amount += reward_for_this_stake;
-                    }
-                    updated_stakes_for_this_staker.push(updated_stake);
-                }
+        if stake_in_club > Uint128::zero() {
+            // All Staker - proportional
+            let mut index_increment = Decimal::from_ratio(all_stakers_reward, total_stake_across_all_clubs);
+            if is_club_a_winner {
+                // Winning Club Staker - proportional
+                index_increment = index_increment + Decimal::from_ratio(reward_for_all_stakers_in_winning_club, stake_in_club);
             }
+            let reward_index = CLUB_REWARD_INDEX.may_load(deps.storage, club_name.clone())?.unwrap_or_default();
+            CLUB_REWARD_INDEX.save(deps.storage, club_name.clone(), &(reward_index + index_increment))?;
+            club_reward.reward_index_increment = index_increment;
+            club_reward.stakers_reward = stake_in_club * index_increment;
+            progress.reward_given += club_reward.stakers_reward;
+        }
+
+        // Club Owner - equal share of the owners reward
+        let owner_reward = if is_club_a_winner {
+            winner_owner_reward
+        } else {
+            non_winner_owner_reward
+        };
+        if owner_reward > Uint128::zero() {
+            credit_owner_reward(deps.storage, club_name.clone(), owner_address, owner_reward)?;
+            club_reward.owner_reward = owner_reward;
+            progress.reward_given += owner_reward;
         }
-        CLUB_STAKING_DETAILS.save(deps.storage, (&club_name.clone(), &staker.clone()), &updated_stakes_for_this_staker)?;
+        CLUB_REWARD_HISTORY.save(deps.storage, (U64Key::new(reward_period), &club_name), &club_reward)?;
+        progress.last_club = Some(club_name);
+    }
+    if more_clubs {
+        return Ok(false);
     }
 
-    // Now update the total stake for this club
-    CLUB_OWNERSHIP_DETAILS.save(
+    let reward_given_so_far = progress.reward_given;
+    REWARD_GIVEN_IN_CURRENT_TIMESTAMP.save(deps.storage, &reward_given_so_far)?;
+
+    let mut new_reward = Uint128::zero();
+    if total_reward > reward_given_so_far {
+        new_reward = total_reward - reward_given_so_far;
+    }
+    // reward added while the distribution was in progress is kept for the next period
+    let reward = REWARD.may_load(deps.storage)?.unwrap_or_default();
+    REWARD.save(deps.storage, &reward.checked_sub(reward_given_so_far).unwrap_or_default())?;
+
+    REWARD_PERIOD_HISTORY.save(
         deps.storage,
-        club_name.clone(),
-        &ClubOwnershipDetails {
-            club_name: club_details.club_name.clone(),
-            start_timestamp: club_details.start_timestamp,
-            locking_period: club_details.locking_period,
-            owner_address: club_details.owner_address,
-            price_paid: club_details.price_paid,
-            reward_amount: club_details.reward_amount,
-            owner_released: club_details.owner_released,
-            total_staked_amount: club_details.total_staked_amount + stake_to_add_for_club,
+        U64Key::new(reward_period),
+        &RewardPeriodRecord {
+            reward_period: reward_period,
+            timestamp: now,
+            total_reward: total_reward,
+            reward_given: reward_given_so_far,
+            undistributed_reward: new_reward,
+            winner_list: winner_list,
+            total_stake_across_all_clubs: total_stake_across_all_clubs,
         },
     )?;
+    Ok(true)
+}
 
-    println!("club_name = {:?} total reward = {:?} reward so far = {:?} club stake increased by {:?}",
-             club_name.clone(), total_reward, reward_given_so_far, stake_to_add_for_club);
+/// Returns the share (percentage multiplied by 100) of the reward amount
+fn share_of_reward(reward: Uint128, share: Uint128) -> Uint128 {
+    reward
+        .checked_mul(share)
+        .unwrap_or_default()
+        .checked_div(Uint128::from(HUNDRED_PERCENT))
+        .unwrap_or_default()
+}
 
-    let mut reward_given_in_current_timestamp = REWARD_GIVEN_IN_CURRENT_TIMESTAMP.may_load(deps.storage)?.unwrap_or_default();
-    reward_given_in_current_timestamp += reward_given_so_far;
-    REWARD_GIVEN_IN_CURRENT_TIMESTAMP.save(deps.storage, &reward_given_in_current_timestamp)?;
-    println!("reward_given_in_current_timestamp = {:?}", reward_given_in_current_timestamp);
+/// Credits the owner reward to the stake of the club owner in this club,
+/// or to the ownership details when the owner holds no stake in it
+fn credit_owner_reward(
+    storage: &mut dyn Storage,
+    club_name: String,
+    owner_address: String,
+    owner_reward: Uint128,
+) -> Result<(), ContractError> {
+    settle_staker_rewards(storage, club_name.clone(), owner_address.clone())?;
 
-    if is_final_batch {
-        let mut new_reward = Uint128::zero();
-        if total_reward > reward_given_in_current_timestamp {
-            new_reward = total_reward - reward_given_in_current_timestamp;
+    let mut stakes = may_load_club_stakes(storage, club_name.clone(), owner_address.clone())?
+        .unwrap_or_default();
+    let mut club_details = query_club_ownership_details(storage, club_name.clone())?;
+    match stakes.first_mut() {
+        Some(stake) => {
+            if stake.auto_stake == SET_AUTO_STAKE {
+                stake.staked_amount += owner_reward;
+                club_details.total_staked_amount += owner_reward;
+            } else {
+                stake.reward_amount += owner_reward;
+            }
+            save_club_stakes(storage, club_name.clone(), owner_address.clone(), &stakes)?;
+        }
+        None => {
+            club_details.reward_amount += owner_reward;
         }
-        REWARD.save(deps.storage, &new_reward)?;
-        println!("new_reward = {:?} ", new_reward);
     }
-    Ok(Response::default())
+    CLUB_OWNERSHIP_DETAILS.save(storage, club_name, &club_details)?;
+    Ok(())
+}
+
+/// Applies the rewards accrued since the stake's checkpoint in reward_index,
+/// compounding them into the stake when auto_stake is set
+fn accrue_pending_rewards(
+    mut stake: ClubStakingDetails,
+    reward_index: Decimal,
+) -> ClubStakingDetails {
+    let pending_reward = stake.staked_amount * (reward_index - stake.reward_index);
+    stake.reward_index = reward_index;
+    if stake.auto_stake == SET_AUTO_STAKE {
+        stake.staked_amount += pending_reward;
+        stake.staked_amount += stake.reward_amount;
+        stake.reward_amount = Uint128::zero();
+    } else {
+        stake.reward_amount += pending_reward;
+    }
+    stake
 }
 
-fn get_winning_clubs_details(
+/// Settles the pending rewards for all stakes of the staker in this club
+/// against the club reward index. Must be called before the staked amount changes.
+fn settle_staker_rewards(
     storage: &mut dyn Storage,
-) -> StdResult<(u64, Uint128, Uint128, Vec<String>)> {
-    let mut max_incremental_stake_value = 0i128 - MAX_UFURY_COUNT;
-    let mut max_total_stake_value = Uint128::zero();
+    club_name: String,
+    staker: String,
+) -> Result<(), ContractError> {
+    let stakes = may_load_club_stakes(storage, club_name.clone(), staker.clone())?;
+    let stakes = match stakes {
+        Some(some_stakes) => some_stakes,
+        None => return Ok(()),
+    };
+    let reward_index = CLUB_REWARD_INDEX.may_load(storage, club_name.clone())?.unwrap_or_default();
 
-    let mut total_number_of_clubs = 0u64;
-    let mut total_stake_across_all_clubs = Uint128::zero();
-    let mut total_stake_in_winning_club = Uint128::zero();
-    let mut winners: Vec<String> = Vec::new();
+    let mut stake_to_add_for_club = Uint128::zero();
+    let mut updated_stakes = Vec::new();
+    for stake in stakes {
+        let staked_amount = stake.staked_amount;
+        let updated_stake = accrue_pending_rewards(stake, reward_index);
+        stake_to_add_for_club += updated_stake.staked_amount - staked_amount;
+        updated_stakes.push(updated_stake);
+    }
+    save_club_stakes(storage, club_name.clone(), staker.clone(), &updated_stakes)?;
 
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for club in all_clubs {
-        let club_details = query_club_ownership_details(storage, club.clone())?;
-        let stake_in_club = club_details.total_staked_amount;
-        total_stake_across_all_clubs += stake_in_club;
-        let staked_amount_u128: u128 = stake_in_club.into();
-        let staked_amount_i128 = staked_amount_u128 as i128;
-        let previous_amount = CLUB_STAKING_SNAPSHOT.may_load(storage, club.clone())?.unwrap_or_default();
-        let previous_amount_u128: u128 = previous_amount.into();
-        let previous_amount_i128 = previous_amount_u128 as i128;
-        let difference_amount = staked_amount_i128 - previous_amount_i128;
-
-        if difference_amount > max_incremental_stake_value {
-            // found a new max_incremental_stake
-            total_stake_in_winning_club = stake_in_club;
-            max_incremental_stake_value = difference_amount;
-            max_total_stake_value = stake_in_club;
-
-            // pop all pre-existing winners
-            let winner_len = winners.len();
-            let mut i = 0;
-            while i < winner_len {
-                winners.swap_remove(0);
-                i += 1;
-            }
+    if stake_to_add_for_club > Uint128::zero() {
+        let mut club_details = query_club_ownership_details(storage, club_name.clone())?;
+        club_details.total_staked_amount += stake_to_add_for_club;
+        CLUB_OWNERSHIP_DETAILS.save(storage, club_name, &club_details)?;
+    }
+    Ok(())
+}
 
-            // now add this stake to the winner list
-            winners.push(club.clone());
-        } else if difference_amount == max_incremental_stake_value {
-            if stake_in_club >= max_total_stake_value {
-                total_stake_in_winning_club = stake_in_club;
-                max_incremental_stake_value = difference_amount;
-                max_total_stake_value = stake_in_club;
-                if stake_in_club > max_total_stake_value {
-                    // found a new max_total_stake
-
-                    // pop all pre-existing winners
-                    let winner_len = winners.len();
-                    let mut i = 0;
-                    while i < winner_len {
-                        winners.swap_remove(0);
-                        i += 1;
-                    }
+/// Returns the stakes of the staker in this club, None when there are none
+fn may_load_club_stakes(
+    storage: &dyn Storage,
+    club_name: String,
+    staker: String,
+) -> StdResult<Option<Vec<ClubStakingDetails>>> {
+    let stakes: Vec<ClubStakingDetails> = club_staking_details()
+        .idx
+        .club_staker
+        .prefix((club_name.as_bytes().to_vec(), staker.as_bytes().to_vec()))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, stake)| stake))
+        .collect::<StdResult<_>>()?;
+    if stakes.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(stakes))
+}
 
-                    // now add this stake to the winner list
-                    winners.push(club.clone());
-                } else if stake_in_club == max_total_stake_value {
-                    // more than one winners have same total and incremental stake
+fn next_stake_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let stake_id = STAKE_ID.may_load(storage)?.unwrap_or_default() + 1;
+    STAKE_ID.save(storage, &stake_id)?;
+    Ok(stake_id)
+}
 
-                    // add this stake to the winner list
-                    winners.push(club.clone());
-                }
-                // else skip this club
-            }
+/// Saves the stakes of the staker in this club, assigning a stake id to new stakes,
+/// and records the staked amount against the current reward period for StakerRewardHistory
+fn save_club_stakes(
+    storage: &mut dyn Storage,
+    club_name: String,
+    staker: String,
+    stakes: &Vec<ClubStakingDetails>,
+) -> StdResult<()> {
+    let mut staked_amount = Uint128::zero();
+    for stake in stakes {
+        let mut stake = stake.clone();
+        if stake.stake_id == 0 {
+            stake.stake_id = next_stake_id(storage)?;
         }
-        // else skip this club
-
-        total_number_of_clubs += 1;
-        CLUB_STAKING_SNAPSHOT.save(storage, club.clone(), &stake_in_club)?;
+        club_staking_details().save(storage, U64Key::new(stake.stake_id), &stake)?;
+        staked_amount += stake.staked_amount;
     }
+    CLUB_STAKERS.save(storage, (&club_name, &staker), &Empty {})?;
+    let reward_period = REWARD_PERIOD.may_load(storage)?.unwrap_or_default();
+    CLUB_STAKE_HISTORY.save(
+        storage,
+        (&club_name, &staker, U64Key::new(reward_period)),
+        &staked_amount,
+    )
+}
 
-    println!("total_clubs = {:?}, total_stake = {:?}, winning_stake = {:?}, winners = {:?}",
-             total_number_of_clubs,
-             total_stake_across_all_clubs,
-             total_stake_in_winning_club,
-             winners);
-    Ok((total_number_of_clubs,
-        total_stake_across_all_clubs,
-        total_stake_in_winning_club,
-        winners))
+/// Returns the stakes as they would be after settling the pending rewards
+fn stakes_with_pending_rewards(
+    storage: &dyn Storage,
+    club_name: String,
+    stakes: Vec<ClubStakingDetails>,
+) -> StdResult<Vec<ClubStakingDetails>> {
+    let reward_index = CLUB_REWARD_INDEX.may_load(storage, club_name)?.unwrap_or_default();
+    Ok(stakes
+        .into_iter()
+        .map(|stake| accrue_pending_rewards(stake, reward_index))
+        .collect())
 }
 
+/// Picks the winning clubs of the reward period as per the configured strategy.
+/// Clubs with the same standing are all winners, except for the top clubs
+/// strategies which return at most count clubs.
+fn select_winning_clubs(
+    strategy: &WinningClubStrategy,
+    standings: &[ClubStakeStanding],
+    club_ranking: &[String],
+) -> StdResult<Vec<String>> {
+    let winners = match strategy {
+        WinningClubStrategy::LargestIncrementalStake => {
+            // largest increase in stake, tie broken on total stake
+            let best = standings
+                .iter()
+                .map(|s| (s.incremental_stake(), s.total_stake))
+                .max();
+            standings
+                .iter()
+                .filter(|s| Some((s.incremental_stake(), s.total_stake)) == best)
+                .map(|s| s.club_name.clone())
+                .collect()
+        }
+        WinningClubStrategy::LargestTotalStake => {
+            let best = standings.iter().map(|s| s.total_stake).max();
+            standings
+                .iter()
+                .filter(|s| Some(s.total_stake) == best)
+                .map(|s| s.club_name.clone())
+                .collect()
+        }
+        WinningClubStrategy::TopClubsByIncrementalStake { count } => {
+            // sort is stable, so clubs with the same standing stay in order of their name
+            let mut sorted: Vec<&ClubStakeStanding> = standings.iter().collect();
+            sorted.sort_by(|a, b| {
+                (b.incremental_stake(), b.total_stake).cmp(&(a.incremental_stake(), a.total_stake))
+            });
+            sorted
+                .into_iter()
+                .take(*count as usize)
+                .map(|s| s.club_name.clone())
+                .collect()
+        }
+        WinningClubStrategy::AdminRanking { count } => {
+            if club_ranking.is_empty() {
+                return Err(StdError::generic_err("Club ranking not submitted for this reward period"));
+            }
+            club_ranking
+                .iter()
+                .filter(|club| standings.iter().any(|s| &s.club_name == *club))
+                .take(*count as usize)
+                .cloned()
+                .collect()
+        }
+    };
+    Ok(winners)
+}
 
 fn is_winning_club(
     club_name: String,
@@ -1911,32 +3393,63 @@ fn transfer_from_contract_to_wallet(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::QueryPlatformFees { msg } => to_binary(&query_platform_fees(deps, msg)?),
+        QueryMsg::FeeQuote { msg } => to_binary(&query_fee_quote(deps, msg)?),
+        QueryMsg::QueryFuryFees { msg } => to_binary(&query_fury_fees(deps, msg)?),
         QueryMsg::ClubStakingDetails { club_name, user_list } => {
             to_binary(&query_club_staking_details(deps.storage, club_name, user_list)?)
         }
         QueryMsg::ClubOwnershipDetails { club_name } => {
-            to_binary(&query_club_ownership_details(deps.storage, club_name)?)
+            to_binary(&query_club_ownership_details(deps.storage, normalize_club_name(&club_name)?)?)
         }
         QueryMsg::ClubPreviousOwnershipDetails { previous_owner } => to_binary(
             &query_club_previous_owner_details(deps.storage, previous_owner)?,
         ),
-        QueryMsg::AllClubOwnershipDetails {} => {
-            to_binary(&query_all_club_ownership_details(deps.storage)?)
+        QueryMsg::AllClubOwnershipDetails { start_after, limit } => {
+            to_binary(&query_all_club_ownership_details(deps.storage, start_after, limit)?)
         }
-        QueryMsg::AllPreviousClubOwnershipDetails {} => {
-            to_binary(&query_all_previous_club_ownership_details(deps.storage)?)
+        QueryMsg::AllPreviousClubOwnershipDetails { start_after, limit } => {
+            to_binary(&query_all_previous_club_ownership_details(deps.storage, start_after, limit)?)
         }
         QueryMsg::ClubOwnershipDetailsForOwner { owner_address } => to_binary(
             &query_club_ownership_details_for_owner(deps.storage, owner_address)?,
         ),
-        QueryMsg::AllStakes { user_address_list } => to_binary(&query_all_stakes(deps.storage, user_address_list)?),
-        QueryMsg::AllStakesForUser { user_address } => {
-            to_binary(&query_all_stakes_for_user(deps.storage, user_address)?)
+        QueryMsg::AllStakes {
+            user_address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_all_stakes(deps.storage, user_address_list, start_after, limit)?),
+        QueryMsg::AllStakesForUser {
+            user_address,
+            start_after,
+            limit,
+        } => {
+            to_binary(&query_all_stakes_for_user(deps.storage, user_address, start_after, limit)?)
+        }
+        QueryMsg::AllStakesInClub {
+            club_name,
+            start_after,
+            limit,
+        } => {
+            to_binary(&query_all_stakes_in_club(deps.storage, club_name, start_after, limit)?)
         }
-        QueryMsg::AllBonds { user_address_list } => to_binary(&query_all_bonds(deps.storage, user_address_list)?),
+        QueryMsg::AllBonds {
+            user_address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_all_bonds(deps.storage, user_address_list, start_after, limit)?),
+        QueryMsg::AllStakersInClub {
+            club_name,
+            start_after,
+            limit,
+        } => to_binary(&query_all_stakers_in_club(deps.storage, club_name, start_after, limit)?),
+        QueryMsg::AllBondersInClub {
+            club_name,
+            start_after,
+            limit,
+        } => to_binary(&query_all_bonders_in_club(deps.storage, club_name, start_after, limit)?),
         QueryMsg::ClubBondingDetailsForUser {
             club_name,
             user_address,
@@ -1946,23 +3459,82 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             user_address,
         )?),
         QueryMsg::RewardAmount {} => to_binary(&query_reward_amount(deps.storage)?),
+        QueryMsg::RewardSplitPolicy {} => to_binary(&query_reward_split_policy(deps.storage)?),
+        QueryMsg::WinningClubStrategy {} => to_binary(&query_winning_club_strategy(deps.storage)?),
+        QueryMsg::WinningClubDetails { reward_period } => {
+            to_binary(&query_winning_club_details(deps.storage, reward_period)?)
+        }
+        QueryMsg::Admin {} => to_binary(&ADMIN.query_admin(deps)?),
+        QueryMsg::PendingAdmin {} => to_binary(&query_pending_admin(deps.storage)?),
+        QueryMsg::RoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_binary(&query_role_members(deps, role, start_after, limit)?),
+        QueryMsg::PauseStatus {} => to_binary(&query_pause_status(deps.storage)?),
+        QueryMsg::ClubListings { start_after, limit } => {
+            to_binary(&query_club_listings(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::RewardPeriodHistory { start_after, limit } => {
+            to_binary(&query_reward_period_history(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::ClubRewardHistory { reward_period, start_after, limit } => {
+            to_binary(&query_club_reward_history(deps.storage, reward_period, start_after, limit)?)
+        }
+        QueryMsg::StakerRewardHistory { staker, club_name } => {
+            to_binary(&query_staker_reward_history(deps.storage, staker, club_name)?)
+        }
         QueryMsg::QueryStakerRewards {
             staker,
             club_name,
         } => to_binary(&query_staker_rewards(deps, staker, club_name)?),
+        QueryMsg::EarlyUnbondPolicy {} => to_binary(&query_early_unbond_policy(deps.storage)?),
+        QueryMsg::MoveStakeCooldown {} => to_binary(&CONFIG.load(deps.storage)?.move_stake_cooldown),
+        QueryMsg::ClubInfo { club_name } => to_binary(&query_club_info(deps, club_name)?),
+        QueryMsg::ClubNftAddress {} => to_binary(&CONFIG.load(deps.storage)?.club_nft_address),
+        QueryMsg::EarlyUnbondQuote {
+            staker,
+            club_name,
+            amount,
+        } => to_binary(&query_early_unbond_quote(deps, env, staker, club_name, amount)?),
     }
 }
 
 pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
+    let quote = query_fee_quote(deps, msg)?;
+    return Ok(quote.platform_fee + quote.transaction_fee + quote.control_fee + quote.swap_fee);
+}
+
+pub fn query_fury_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
+    let required_ust_fees = query_platform_fees(deps, msg)?;
+    if required_ust_fees.is_zero() {
+        return Ok(Uint128::zero());
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let fee_oracle = ProxyFeeOracle::new(deps.querier, config.astro_proxy_address.to_string());
+    let fury_fees = fee_oracle.fury_equivalent_to_ust(required_ust_fees)?;
+    return Ok(fury_fees - fury_fees.multiply_ratio(config.fury_fee_discount, HUNDRED_PERCENT));
+}
+
+pub fn query_fee_quote(deps: Deps, msg: Binary) -> StdResult<FeeQuoteResponse> {
     let config = CONFIG.load(deps.storage)?;
-    let platform_fees_percentage: Uint128;
+    let no_fees = FeeQuoteResponse {
+        platform_fee: Uint128::zero(),
+        transaction_fee: Uint128::zero(),
+        control_fee: Uint128::zero(),
+        swap_fee: Uint128::zero(),
+        denom: config.usdc_ibc_symbol.clone(),
+        fury_reference_amount: Uint128::zero(),
+        price_used: Decimal::zero(),
+    };
+    let mut control_fees_percentage = Uint128::zero();
     let fury_amount_provided;
     match from_binary(&msg) {
         Ok(ExecuteMsg::IncreaseRewardAmount {
                reward_from: _,
                amount: _,
            }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::BuyAClub {
                buyer: _,
@@ -1970,7 +3542,6 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                club_name: _,
                auto_stake: _,
            }) => {
-            platform_fees_percentage = config.platform_fees + config.transaction_fees;
             fury_amount_provided = config.club_price;
         }
         Ok(ExecuteMsg::AssignAClub {
@@ -1979,7 +3550,7 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                club_name: _,
                auto_stake: _,
            }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::StakeOnAClub {
                staker: _,
@@ -1987,23 +3558,42 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                amount,
                auto_stake: _,
            }) => {
-            platform_fees_percentage = config.platform_fees + config.transaction_fees + config.control_fees;
+            control_fees_percentage = config.control_fees;
             fury_amount_provided = amount;
         }
         Ok(ExecuteMsg::AssignStakesToAClub {
                stake_list: _,
                club_name: _,
            }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::ReleaseClub { owner: _, club_name: _ }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::ListClub {
+               owner: _,
+               club_name: _,
+               asking_price: _,
+               expiry: _,
+           }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::CancelClubListing { owner: _, club_name: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::BuyListedClub {
+               buyer: _,
+               club_name: _,
+               auto_stake: _,
+           }) => {
+            // the platform fee is deducted in Fury from the asking price
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::ClaimOwnerRewards { owner: _, club_name: _ }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::ClaimPreviousOwnerRewards { previous_owner: _ }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::StakeWithdrawFromAClub {
                staker: _,
@@ -2011,34 +3601,105 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                amount,
                immediate_withdrawal: _,
            }) => {
-            platform_fees_percentage = config.platform_fees + config.transaction_fees;
             fury_amount_provided = amount;
         }
-        Ok(ExecuteMsg::CalculateAndDistributeRewards {
-               staker_list: _,
-               club_name: _,
-               is_first_batch: _,
-               is_final_batch: _,
+        Ok(ExecuteMsg::ClaimMatured { .. }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateRewardPolicy { reward_split_policy: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateWinningClubStrategy { winning_club_strategy: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::SetClubRanking { club_ranking: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::ProposeNewAdmin { new_admin: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::AcceptAdmin {}) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::GrantRole { role: _, address: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::RevokeRole { role: _, address: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::SetPaused {
+               buy: _,
+               stake: _,
+               withdraw: _,
+               claim: _,
+               distribute: _,
+           }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::CalculateAndDistributeRewards { .. }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateEarlyUnbondPolicy { early_unbond_policy: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateMoveStakeCooldown { move_stake_cooldown: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::RegisterClub { club_name: _, profile: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateClubProfile { club_name: _, profile: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateClubNftAddress { .. }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::SetAutoStake { club_name: _, auto_stake: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::CompoundRewards { club_name: _ }) => {
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::MoveStake {
+               from_club: _,
+               to_club: _,
+               amount,
            }) => {
-            return Ok(Uint128::zero());
+            fury_amount_provided = amount;
+        }
+        Ok(ExecuteMsg::Receive(_)) => {
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::ClaimStakerRewards { staker, club_name }) => {
             fury_amount_provided = query_staker_rewards(deps, staker, club_name)?;
-            platform_fees_percentage = config.platform_fees + config.transaction_fees;
         }
         Err(err) => {
             return Err(StdError::generic_err(format!("{:?}", err)));
         }
     }
-    let ust_equiv_for_fury: Uint128 = deps
-        .querier
-        .query_wasm_smart(config.astro_proxy_address, &ProxyQueryMsgs::get_ust_equivalent_to_fury {
-            fury_count: fury_amount_provided,
-        })?;
+    let fee_oracle = ProxyFeeOracle::new(deps.querier, config.astro_proxy_address.to_string());
+    let ust_equiv_for_fury = fee_oracle.ust_equivalent_to_fury(fury_amount_provided)?;
+    let mut price_used = Decimal::zero();
+    if !fury_amount_provided.is_zero() {
+        price_used = Decimal::from_ratio(ust_equiv_for_fury, fury_amount_provided);
+    }
 
-    return Ok(ust_equiv_for_fury
-        .checked_mul(platform_fees_percentage)?
-        .checked_div(Uint128::from(HUNDRED_PERCENT))?);
+    let fees = split_fees(
+        ust_equiv_for_fury,
+        &[config.platform_fees, config.transaction_fees, control_fees_percentage],
+    )?;
+    return Ok(FeeQuoteResponse {
+        platform_fee: fees[0],
+        transaction_fee: fees[1],
+        control_fee: fees[2],
+        swap_fee: Uint128::zero(),
+        denom: config.usdc_ibc_symbol,
+        fury_reference_amount: fury_amount_provided,
+        price_used,
+    });
 }
 
 pub fn query_club_staking_details(
@@ -2046,12 +3707,13 @@ pub fn query_club_staking_details(
     club_name: String,
     user_list: Vec<String>,
 ) -> StdResult<Vec<ClubStakingDetails>> {
+    let club_name = normalize_club_name(&club_name)?;
     let mut all_stakes = Vec::new();
     for user in user_list {
-        let csd = CLUB_STAKING_DETAILS.may_load(storage, (&club_name.clone(), &user.clone()))?;
+        let csd = may_load_club_stakes(storage, club_name.clone(), user.clone())?;
         match csd {
             Some(staking_details) => {
-                for stake in staking_details {
+                for stake in stakes_with_pending_rewards(storage, club_name.clone(), staking_details)? {
                     all_stakes.push(stake);
                 }
             }
@@ -2061,18 +3723,48 @@ pub fn query_club_staking_details(
     return Ok(all_stakes);
 }
 
-fn query_all_stakes(storage: &dyn Storage, user_address_list: Vec<String>) -> StdResult<Vec<ClubStakingDetails>> {
-    let mut all_stakes = Vec::new();
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(storage, None, None, Order::Ascending)
+/// Returns a page of club names, in ascending order
+fn club_names_page(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Vec<String> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::inclusive);
+    CLUB_OWNERSHIP_DETAILS
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
         .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for club_name in all_clubs {
-        for user_address in user_address_list.clone() {
-            let csd = CLUB_STAKING_DETAILS.may_load(storage, (&club_name.clone(), &user_address.clone()))?;
+        .collect()
+}
+
+/// The users whose stakes or bonds are looked up in a page of clubs. The users
+/// of a club are listed a page at a time with AllStakersInClub and AllBondersInClub
+fn check_user_address_list(user_address_list: &[String]) -> StdResult<()> {
+    if user_address_list.is_empty() || user_address_list.len() > MAX_LIMIT as usize {
+        return Err(StdError::generic_err(format!(
+            "Between 1 and {} user addresses must be given, the users of a club are listed by AllStakersInClub and AllBondersInClub",
+            MAX_LIMIT
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the stakes of the users in a page of clubs
+fn query_all_stakes(
+    storage: &dyn Storage,
+    user_address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ClubStakingDetails>> {
+    check_user_address_list(&user_address_list)?;
+    let mut all_stakes = Vec::new();
+    for club_name in club_names_page(storage, start_after, limit) {
+        for user_address in user_address_list.iter() {
+            let csd = may_load_club_stakes(storage, club_name.clone(), user_address.clone())?;
             match csd {
                 Some(staking_details) => {
-                    for stake in staking_details {
+                    for stake in stakes_with_pending_rewards(storage, club_name.clone(), staking_details)? {
                         all_stakes.push(stake);
                     }
                 }
@@ -2083,15 +3775,18 @@ fn query_all_stakes(storage: &dyn Storage, user_address_list: Vec<String>) -> St
     return Ok(all_stakes);
 }
 
-fn query_all_bonds(storage: &dyn Storage, user_address_list: Vec<String>) -> StdResult<Vec<ClubBondingDetails>> {
+/// Returns the bonds of the users in a page of clubs
+fn query_all_bonds(
+    storage: &dyn Storage,
+    user_address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ClubBondingDetails>> {
+    check_user_address_list(&user_address_list)?;
     let mut all_bonds = Vec::new();
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for club_name in all_clubs {
-        for user_address in user_address_list.clone() {
-            let cbd = CLUB_BONDING_DETAILS.may_load(storage, (&club_name.clone(), &user_address.clone()))?;
+    for club_name in club_names_page(storage, start_after, limit) {
+        for user_address in user_address_list.iter() {
+            let cbd = CLUB_BONDING_DETAILS.may_load_claims(storage, &club_name, &Addr::unchecked(user_address))?;
             match cbd {
                 Some(bonding_details) => {
                     for bond in bonding_details {
@@ -2105,6 +3800,203 @@ fn query_all_bonds(storage: &dyn Storage, user_address_list: Vec<String>) -> Std
     return Ok(all_bonds);
 }
 
+fn query_all_stakers_in_club(
+    storage: &dyn Storage,
+    club_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let club_name = normalize_club_name(&club_name)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::inclusive);
+    Ok(CLUB_STAKERS
+        .prefix(&club_name)
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|k| String::from_utf8(k).unwrap())
+        .collect())
+}
+
+fn query_all_bonders_in_club(
+    storage: &dyn Storage,
+    club_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let club_name = normalize_club_name(&club_name)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    Ok(CLUB_BONDING_DETAILS.claimants(storage, &club_name, start_after, limit))
+}
+
+fn query_pending_admin(storage: &dyn Storage) -> StdResult<Option<String>> {
+    return Ok(PENDING_ADMIN.may_load(storage)?.map(String::from));
+}
+
+fn query_role_members(
+    deps: Deps,
+    role: Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = maybe_addr(deps.api, start_after)?;
+    let start = calc_range_start(start_after).map(Bound::inclusive);
+    Ok(ROLES
+        .prefix(role.as_str())
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|k| String::from_utf8(k).unwrap())
+        .collect())
+}
+
+fn query_pause_status(storage: &dyn Storage) -> StdResult<PauseStatus> {
+    return Ok(PAUSE_STATUS.may_load(storage)?.unwrap_or_default());
+}
+
+fn query_reward_split_policy(storage: &dyn Storage) -> StdResult<RewardSplitPolicy> {
+    let config = CONFIG.load(storage)?;
+    return Ok(config.reward_split_policy);
+}
+
+fn query_winning_club_strategy(storage: &dyn Storage) -> StdResult<WinningClubStrategy> {
+    let config = CONFIG.load(storage)?;
+    return Ok(config.winning_club_strategy);
+}
+
+fn query_early_unbond_policy(storage: &dyn Storage) -> StdResult<EarlyUnbondPolicy> {
+    let config = CONFIG.load(storage)?;
+    return Ok(config.early_unbond_policy);
+}
+
+fn query_early_unbond_quote(
+    deps: Deps,
+    env: Env,
+    staker: String,
+    club_name: String,
+    amount: Uint128,
+) -> StdResult<EarlyUnbondQuoteResponse> {
+    let club_name = normalize_club_name(&club_name)?;
+    let config = CONFIG.load(deps.storage)?;
+    let staker_addr = deps.api.addr_validate(&staker)?;
+    let bonds = CLUB_BONDING_DETAILS.load_claims(deps.storage, &club_name, &staker_addr)?;
+    let (quote, _) = plan_immediate_unbonding(bonds, &staker_addr, amount, env.block.time, &config.early_unbond_policy);
+    return Ok(quote);
+}
+
+fn query_winning_club_details(
+    storage: &dyn Storage,
+    reward_period: Option<u64>,
+) -> StdResult<WinningClubDetails> {
+    let wcd = match reward_period {
+        Some(reward_period) => WINNING_CLUB_DETAILS_HISTORY.may_load(storage, U64Key::new(reward_period))?,
+        None => WINNING_CLUB_DETAILS_SNAPSHOT.may_load(storage)?,
+    };
+    match wcd {
+        Some(wcd) => return Ok(wcd),
+        None => return Err(StdError::generic_err("No winning club details found")),
+    };
+}
+
+fn query_club_listings(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ClubListing>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::inclusive);
+    CLUB_LISTINGS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, listing)| listing))
+        .collect()
+}
+
+fn query_reward_period_history(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<RewardPeriodRecord>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    REWARD_PERIOD_HISTORY
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, record)| record))
+        .collect()
+}
+
+fn query_club_reward_history(
+    storage: &dyn Storage,
+    reward_period: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ClubRewardRecord>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::inclusive);
+    CLUB_REWARD_HISTORY
+        .prefix(U64Key::new(reward_period))
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, club_reward)| club_reward))
+        .collect()
+}
+
+/// Rebuilds the reward of the staker in this club for each reward period,
+/// from the stake held during the period and the club reward index increment.
+/// Staking rewards are settled across periods at once, so the credited amount
+/// can differ from the sum of these by rounding.
+fn query_staker_reward_history(
+    storage: &dyn Storage,
+    staker: String,
+    club_name: String,
+) -> StdResult<Vec<StakerRewardRecord>> {
+    let club_name = normalize_club_name(&club_name)?;
+    let stake_changes: Vec<(u64, Uint128)> = CLUB_STAKE_HISTORY
+        .prefix((&club_name, &staker))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(k, staked_amount)| {
+                let mut period_bytes = [0u8; 8];
+                period_bytes.copy_from_slice(&k);
+                (u64::from_be_bytes(period_bytes), staked_amount)
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    let mut history = Vec::new();
+    let records = REWARD_PERIOD_HISTORY.range(storage, None, None, Order::Ascending);
+    for item in records {
+        let (_, record) = item?;
+        // stake held during the period is the last one saved before its distribution
+        let staked_amount = stake_changes
+            .iter()
+            .filter(|(period, _)| *period < record.reward_period)
+            .last()
+            .map(|(_, staked_amount)| *staked_amount)
+            .unwrap_or_default();
+        let club_reward = CLUB_REWARD_HISTORY.may_load(storage, (U64Key::new(record.reward_period), &club_name))?;
+        if let Some(club_reward) = club_reward {
+            let staking_reward = staked_amount * club_reward.reward_index_increment;
+            let mut owner_reward = Uint128::zero();
+            if club_reward.owner_address == staker {
+                owner_reward = club_reward.owner_reward;
+            }
+            if staking_reward.is_zero() && owner_reward.is_zero() {
+                continue;
+            }
+            history.push(StakerRewardRecord {
+                reward_period: record.reward_period,
+                timestamp: record.timestamp,
+                staked_amount: staked_amount,
+                reward_index_increment: club_reward.reward_index_increment,
+                staking_reward: staking_reward,
+                owner_reward: owner_reward,
+            });
+        }
+    }
+    Ok(history)
+}
+
 fn query_reward_amount(storage: &dyn Storage) -> StdResult<Uint128> {
     let reward: Uint128 = REWARD.may_load(storage)?.unwrap_or_default();
     return Ok(reward);
@@ -2115,12 +4007,13 @@ fn query_staker_rewards(
     staker: String,
     club_name: String,
 ) -> StdResult<Uint128> {
+    let club_name = normalize_club_name(&club_name)?;
     // Get the exising stakes for this club
     let mut stakes = Vec::new();
-    let all_stakes = CLUB_STAKING_DETAILS.may_load(deps.storage, (&club_name.clone(), &staker.clone()))?;
+    let all_stakes = may_load_club_stakes(deps.storage, club_name.clone(), staker.clone())?;
     match all_stakes {
         Some(some_stakes) => {
-            stakes = some_stakes;
+            stakes = stakes_with_pending_rewards(deps.storage, club_name.clone(), some_stakes)?;
         }
         None => {}
     }
@@ -2133,6 +4026,23 @@ fn query_staker_rewards(
     return Ok(amount);
 }
 
+fn query_club_info(deps: Deps, club_name: String) -> StdResult<ClubInfoResponse> {
+    let club_name = normalize_club_name(&club_name)?;
+    let profile = CLUB_REGISTRY.load(deps.storage, club_name.clone())?;
+    let ownership_details = CLUB_OWNERSHIP_DETAILS.may_load(deps.storage, club_name.clone())?;
+    let owner_address = match &ownership_details {
+        Some(od) => Some(current_club_owner(deps, od)?),
+        None => None,
+    };
+    Ok(ClubInfoResponse {
+        club_name,
+        profile,
+        owner_address,
+        owner_released: ownership_details.as_ref().map_or(false, |od| od.owner_released),
+        total_staked_amount: ownership_details.map_or(Uint128::zero(), |od| od.total_staked_amount),
+    })
+}
+
 fn query_club_ownership_details(
     storage: &dyn Storage,
     club_name: String,
@@ -2158,30 +4068,55 @@ pub fn query_club_previous_owner_details(
 pub fn query_all_stakes_for_user(
     storage: &dyn Storage,
     user_address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
 ) -> StdResult<Vec<ClubStakingDetails>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let stakes: Vec<ClubStakingDetails> = club_staking_details()
+        .idx
+        .staker
+        .prefix(user_address.as_bytes().to_vec())
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, stake)| stake))
+        .collect::<StdResult<_>>()?;
     let mut all_stakes = Vec::new();
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for club_name in all_clubs {
-        let staking_details = CLUB_STAKING_DETAILS.load(storage, (&club_name.clone(), &user_address.clone()))?;
-        for stake in staking_details {
-            if stake.staker_address == user_address {
-                all_stakes.push(stake);
-            }
-        }
+    for stake in stakes {
+        let club_name = stake.club_name.clone();
+        all_stakes.append(&mut stakes_with_pending_rewards(storage, club_name, vec![stake])?);
     }
     return Ok(all_stakes);
 }
 
+pub fn query_all_stakes_in_club(
+    storage: &dyn Storage,
+    club_name: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ClubStakingDetails>> {
+    let club_name = normalize_club_name(&club_name)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let stakes: Vec<ClubStakingDetails> = club_staking_details()
+        .idx
+        .club
+        .prefix(club_name.as_bytes().to_vec())
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, stake)| stake))
+        .collect::<StdResult<_>>()?;
+    stakes_with_pending_rewards(storage, club_name, stakes)
+}
+
 pub fn query_club_bonding_details_for_user(
     storage: &dyn Storage,
     club_name: String,
     user_address: String,
 ) -> StdResult<Vec<ClubBondingDetails>> {
+    let club_name = normalize_club_name(&club_name)?;
     let mut bonds: Vec<ClubBondingDetails> = Vec::new();
-    let cbd = CLUB_BONDING_DETAILS.may_load(storage, (&club_name.clone(), &user_address.clone()))?;
+    let cbd = CLUB_BONDING_DETAILS.may_load_claims(storage, &club_name, &Addr::unchecked(user_address.clone()))?;
     match cbd {
         Some(cbd) => {
             bonds = cbd;
@@ -2200,13 +4135,11 @@ pub fn query_club_bonding_details_for_user(
 
 pub fn query_all_club_ownership_details(
     storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
 ) -> StdResult<Vec<ClubOwnershipDetails>> {
     let mut all_owners = Vec::new();
-    let all_clubs: Vec<String> = CLUB_OWNERSHIP_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for club_name in all_clubs {
+    for club_name in club_names_page(storage, start_after, limit) {
         let owner_details = CLUB_OWNERSHIP_DETAILS.load(storage, club_name)?;
         all_owners.push(owner_details);
     }
@@ -2215,10 +4148,15 @@ pub fn query_all_club_ownership_details(
 
 pub fn query_all_previous_club_ownership_details(
     storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
 ) -> StdResult<Vec<ClubPreviousOwnerDetails>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::inclusive);
     let mut pcod = Vec::new();
     let all_previous: Vec<String> = CLUB_PREVIOUS_OWNER_DETAILS
-        .keys(storage, None, None, Order::Ascending)
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
         .map(|k| String::from_utf8(k).unwrap())
         .collect();
     for previous in all_previous {
@@ -2248,15 +4186,62 @@ pub fn query_club_ownership_details_for_owner(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{Addr, coins, CosmosMsg, from_binary, StdError, SubMsg, WasmMsg};
+    use cosmwasm_std::{
+        Addr, attr, coins, ContractResult, CosmosMsg, Event, from_binary, QuerierResult, StdError, SubMsg, SystemError, SystemResult,
+        WasmMsg, WasmQuery,
+    };
     use cosmwasm_std::coin;
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    use crate::fee_oracle::mock::{mock_dependencies_with_fee_oracle, MockFeeOracle};
+    use crate::msg::PayFeesInFuryCommand;
+    use cw_controllers::ClaimError;
+
+    use crate::state::{LegacyConfig, PenaltyStep};
 
     use super::*;
 
+    /// Answers OwnerOf queries of the club NFT contract, tokens not listed are not minted
+    fn mock_club_nft_holders(holders: &'static [(&'static str, &'static str)]) -> impl Fn(&WasmQuery) -> QuerierResult {
+        move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "club_nft1111" => {
+                let token_id = match from_binary(msg).unwrap() {
+                    Cw721QueryMsg::OwnerOf { token_id, .. } => token_id,
+                    _ => panic!("unexpected club NFT query"),
+                };
+                match holders.iter().find(|(club_name, _)| *club_name == token_id) {
+                    Some((_, owner)) => {
+                        let owner = OwnerOfResponse {
+                            owner: owner.to_string(),
+                            approvals: vec![],
+                        };
+                        SystemResult::Ok(ContractResult::Ok(to_binary(&owner).unwrap()))
+                    }
+                    None => SystemResult::Ok(ContractResult::Err(String::from("cw721_base::state::TokenInfo not found"))),
+                }
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: String::from("wasm query"),
+            }),
+        }
+    }
+
+        fn register_test_clubs(mut deps: DepsMut, adminInfo: MessageInfo) {
+        for club_name in ["CLUB001", "CLUB002", "CLUB003", "CLUB004", "CLUB005"] {
+            let profile = ClubProfile {
+                display_name: club_name.to_string(),
+                logo_url: None,
+                sport: "cricket".to_string(),
+                league: "IPL".to_string(),
+                active: true,
+            };
+            register_club(deps.branch(), adminInfo.clone(), club_name.to_string(), profile).unwrap();
+        }
+    }
+
     #[test]
     fn test_buying_of_club() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2275,6 +4260,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -2285,6 +4272,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
         buy_a_club(
@@ -2313,9 +4301,254 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_buying_of_club_charges_fees_from_oracle() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::new(Decimal::percent(50)));
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        // 1000000 fury at 0.5 uusd each, 1.3% platform and transaction fees
+        let required = query_platform_fees(
+            deps.as_ref(),
+            to_binary(&ExecuteMsg::BuyAClub {
+                buyer: "owner001".to_string(),
+                seller: None,
+                club_name: "CLUB001".to_string(),
+                auto_stake: SET_AUTO_STAKE,
+            })
+                .unwrap(),
+        )
+            .unwrap();
+        assert_eq!(required, Uint128::from(6500u128));
+
+        // staking is also charged the control fee
+        let quote = query_fee_quote(
+            deps.as_ref(),
+            to_binary(&ExecuteMsg::StakeOnAClub {
+                staker: "staker001".to_string(),
+                club_name: "CLUB001".to_string(),
+                amount: Uint128::from(200000u128),
+                auto_stake: SET_AUTO_STAKE,
+            })
+                .unwrap(),
+        )
+            .unwrap();
+        assert_eq!(quote, FeeQuoteResponse {
+            platform_fee: Uint128::from(1000u128),
+            transaction_fee: Uint128::from(300u128),
+            control_fee: Uint128::from(500u128),
+            swap_fee: Uint128::zero(),
+            denom: "uusd".to_string(),
+            fury_reference_amount: Uint128::from(200000u128),
+            price_used: Decimal::percent(50),
+        });
+
+        // the buckets add up to the 1.8% of 499 uusd rounded down once,
+        // the rounding remainder is charged as control fee
+        let quote = query_fee_quote(
+            deps.as_ref(),
+            to_binary(&ExecuteMsg::StakeOnAClub {
+                staker: "staker001".to_string(),
+                club_name: "CLUB001".to_string(),
+                amount: Uint128::from(999u128),
+                auto_stake: SET_AUTO_STAKE,
+            })
+                .unwrap(),
+        )
+            .unwrap();
+        assert_eq!(quote.platform_fee, Uint128::from(4u128));
+        assert_eq!(quote.transaction_fee, Uint128::from(1u128));
+        assert_eq!(quote.control_fee, Uint128::from(3u128));
+        assert_eq!(
+            quote.platform_fee + quote.transaction_fee + quote.control_fee,
+            Uint128::from(499u128 * 180 / 10000)
+        );
+
+        let buy_msg = ExecuteMsg::BuyAClub {
+            buyer: "owner001".to_string(),
+            seller: None,
+            club_name: "CLUB001".to_string(),
+            auto_stake: SET_AUTO_STAKE,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner001", &[coin(1000, "uusd")]),
+            buy_msg.clone(),
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientFees {
+                required: Uint128::from(6500u128),
+                received: Uint128::from(1000u128),
+            }
+        );
+
+        // fees sent in several coins that overflow when added are rejected
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner001", &[coin(u128::MAX, "uusd"), coin(1, "uusd")]),
+            buy_msg.clone(),
+        )
+            .unwrap_err();
+        assert!(matches!(err, ContractError::Std(StdError::Overflow { .. })));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner001", &[coin(6500, "uusd")]),
+            buy_msg,
+        )
+            .unwrap();
+        assert_eq!(
+            res.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "platform_fee_collector_wallet_1111".to_string(),
+                amount: coins(6500, "uusd"),
+            })
+        );
+        assert!(res.attributes.contains(&attr("fee_asset", "uusd")));
+        assert!(res.attributes.contains(&attr("fee_amount", "6500")));
+        let cod = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(cod.owner_address, "owner001".to_string());
+    }
+
+    #[test]
+    fn test_pay_fees_in_fury() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::new(Decimal::percent(50)));
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner001", &[]),
+            ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount: Uint128::from(2000u128) },
+        )
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount: Uint128::from(2000u128) },
+        )
+            .unwrap();
+
+        // 6500uusd of fees is 13000 fury, less the 20% discount
+        let buy_msg = ExecuteMsg::BuyAClub {
+            buyer: "owner001".to_string(),
+            seller: None,
+            club_name: "CLUB001".to_string(),
+            auto_stake: SET_AUTO_STAKE,
+        };
+        let required = query_fury_fees(deps.as_ref(), to_binary(&buy_msg).unwrap()).unwrap();
+        assert_eq!(required, Uint128::from(10400u128));
+
+        let pay_fees = |amount: u128| ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "owner001".to_string(),
+            amount: Uint128::from(amount),
+            msg: to_binary(&ReceivedMsg::PayFeesInFury(PayFeesInFuryCommand {
+                msg: buy_msg.clone(),
+            }))
+                .unwrap(),
+        });
+
+        // only fury is accepted
+        let err = execute(deps.as_mut(), mock_env(), mock_info("other_token", &[]), pay_fees(10400)).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let err = execute(deps.as_mut(), mock_env(), mintingContractInfo.clone(), pay_fees(5000)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientFuryFees {
+                required: Uint128::from(10400u128),
+                received: Uint128::from(5000u128),
+            }
+        );
+
+        let res = execute(deps.as_mut(), mock_env(), mintingContractInfo.clone(), pay_fees(10400)).unwrap();
+        assert_eq!(
+            res.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "minting_admin11111".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "platform_fee_collector_wallet_1111".to_string(),
+                    amount: Uint128::from(10400u128),
+                }).unwrap(),
+                funds: vec![],
+            })
+        );
+        assert!(res.attributes.contains(&attr("fee_asset", "fury")));
+        assert!(res.attributes.contains(&attr("fee_amount", "10400")));
+
+        // the club is bought by the fury sender
+        let cod = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(cod.owner_address, "owner001".to_string());
+    }
+
     #[test]
     fn test_owner_claim_rewards() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2334,6 +4567,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -2344,6 +4579,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
         let result = buy_a_club(
@@ -2396,7 +4632,7 @@ mod tests {
 
     #[test]
     fn test_multiple_buying_of_club() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2415,6 +4651,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -2425,6 +4663,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
         buy_a_club(
@@ -2466,7 +4705,7 @@ mod tests {
 
     #[test]
     fn test_assign_a_club() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2485,6 +4724,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -2495,6 +4736,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(1000, "stake")]);
         let owner2_info = mock_info("owner002", &[coin(1000, "stake")]);
@@ -2595,8 +4837,8 @@ mod tests {
     }
 
     #[test]
-    fn test_assign_stakes_to_a_club() {
-        let mut deps = mock_dependencies();
+    fn test_list_and_buy_listed_club() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2615,9 +4857,10 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
-        let mintingContractInfo = mock_info("minting_admin11111", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
@@ -2625,10 +4868,10 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        let owner1_info = mock_info("owner001", &[coin(1000, "stake")]);
-
-        println!("Now assigning the club to owner001");
+        let owner1_info = mock_info("owner001", &[]);
+        let owner2_info = mock_info("owner002", &[]);
         assign_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2637,73 +4880,261 @@ mod tests {
             Some(String::default()),
             "CLUB001".to_string(),
             SET_AUTO_STAKE,
-        );
+        )
+            .unwrap();
 
-        let queryRes0 = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
-        match queryRes0 {
-            Ok(mut cod) => {
-                assert_eq!(cod.owner_address, "owner001".to_string());
-                assert_eq!(cod.price_paid, Uint128::from(0u128));
-                assert_eq!(cod.owner_released, false);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+        // only the owner can list the club
+        let err = list_club(
+            deps.as_mut(),
+            mock_env(),
+            owner2_info.clone(),
+            "owner002".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(5000000u128),
+            now.plus_seconds(60 * 60),
+        )
+            .unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Lister is not the owner for the club")})));
 
-        let mut stake_list: Vec<ClubStakingDetails> = Vec::new();
-        let mut user_address_list = Vec::new();
-        user_address_list.push("owner001".to_string());
-        for i in 1..7 {
-            let mut staker = String::default();
-            match i {
-                1 => { staker = "staker001".to_string(); }
-                2 => { staker = "staker002".to_string(); }
-                3 => { staker = "staker003".to_string(); }
-                4 => { staker = "staker004".to_string(); }
-                5 => { staker = "staker005".to_string(); }
-                6 => { staker = "staker006".to_string(); }
-                _ => {}
-            }
-            user_address_list.push(staker.clone());
-            println!("staker is {}", staker);
-            stake_list.push(ClubStakingDetails {
-                staker_address: staker,
-                staking_start_timestamp: now,
-                staked_amount: Uint128::from(330000u128),
-                staking_duration: CLUB_STAKING_DURATION,
-                club_name: "CLUB001".to_string(),
-                reward_amount: Uint128::from(CLUB_STAKING_REWARD_AMOUNT),
-                auto_stake: SET_AUTO_STAKE,
-            });
-        };
+        let err = list_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(5000000u128),
+            now,
+        )
+            .unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Listing expiry must be in the future")})));
 
-        let staker6Info = mock_info("staker006", &[coin(10, "stake")]);
-        assign_stakes_to_a_club(
+        list_club(
             deps.as_mut(),
             mock_env(),
-            adminInfo.clone(),
-            stake_list,
+            owner1_info.clone(),
+            "owner001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(5000000u128),
+            now.plus_seconds(60 * 60),
+        )
+            .unwrap();
+        let listings = query_club_listings(&deps.storage, None, None).unwrap();
+        assert_eq!(listings.len(), 1);
+        assert_eq!(listings[0].seller, "owner001".to_string());
+        assert_eq!(listings[0].asking_price, Uint128::from(5000000u128));
+
+        cancel_club_listing(deps.as_mut(), owner1_info.clone(), "owner001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(query_club_listings(&deps.storage, None, None).unwrap().len(), 0);
+        let err = buy_listed_club(deps.as_mut(), mock_env(), owner2_info.clone(), "owner002".to_string(), "CLUB001".to_string(), SET_AUTO_STAKE).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Club is not listed for sale")})));
+
+        list_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
             "CLUB001".to_string(),
+            Uint128::from(5000000u128),
+            now.plus_seconds(60 * 60),
+        )
+            .unwrap();
+
+        // the listing can not be bought after its expiry
+        let mut expired_env = mock_env();
+        expired_env.block.time = now.plus_seconds(2 * 60 * 60);
+        let err = buy_listed_club(deps.as_mut(), expired_env, owner2_info.clone(), "owner002".to_string(), "CLUB001".to_string(), SET_AUTO_STAKE).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Listing for the club has expired")})));
+
+        let res = buy_listed_club(deps.as_mut(), mock_env(), owner2_info.clone(), "owner002".to_string(), "CLUB001".to_string(), SET_AUTO_STAKE).unwrap();
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "minting_admin11111".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: "owner002".to_string(),
+                    recipient: "owner001".to_string(),
+                    amount: Uint128::from(4950000u128),
+                }).unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(
+            res.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "minting_admin11111".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: "owner002".to_string(),
+                    recipient: "platform_fee_collector_wallet_1111".to_string(),
+                    amount: Uint128::from(50000u128),
+                }).unwrap(),
+                funds: vec![],
+            })
         );
 
-        let queryRes1 = query_all_stakes(&mut deps.storage, user_address_list);
-        match queryRes1 {
-            Ok(all_stakes) => {
-                println!("all stakes : {:?}", all_stakes);
-                assert_eq!(all_stakes.len(), 7);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
+        let cod = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(cod.owner_address, "owner002".to_string());
+        assert_eq!(cod.price_paid, Uint128::from(5000000u128));
+        assert_eq!(query_club_listings(&deps.storage, None, None).unwrap().len(), 0);
+    }
+
+    /// Writes the state of a contract instantiated with version 0.9.1
+    fn save_state_of_0_9_1(storage: &mut dyn Storage) {
+        set_contract_version(storage, CONTRACT_NAME, "0.9.1").unwrap();
+        LEGACY_CONFIG.save(
+            storage,
+            &LegacyConfig {
+                admin_address: Addr::unchecked("admin11111"),
+                minting_contract_address: Addr::unchecked("minting_admin11111"),
+                astro_proxy_address: Addr::unchecked("astro_proxy_address1111"),
+                club_fee_collector_wallet: Addr::unchecked("club_fee_collector_wallet11111"),
+                club_reward_next_timestamp: mock_env().block.time,
+                reward_periodicity: 24 * 60 * 60u64,
+                club_price: Uint128::from(1000000u128),
+                bonding_duration: 5 * 60u64,
+                owner_release_locking_duration: 24 * 60 * 60u64,
+                platform_fees_collector_wallet: Addr::unchecked("platform_fee_collector_wallet_1111"),
+                platform_fees: Uint128::from(100u128),
+                transaction_fees: Uint128::from(30u128),
+                control_fees: Uint128::from(50u128),
+                max_bonding_limit_per_user: 10u64,
+                usdc_ibc_symbol: "uusd".to_string(),
+            },
+        )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_migrate_config_from_0_9_1() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        save_state_of_0_9_1(&mut deps.storage);
+        // Config written by 0.9.1 can not be read as the current Config
+        assert!(CONFIG.load(&deps.storage).is_err());
+
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(res.attributes[1].value, "0.9.1".to_string());
+        assert_eq!(res.attributes[2].key, "migration_step".to_string());
+        assert_eq!(res.attributes[2].value, "0.10.0".to_string());
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(config.admin_address, Addr::unchecked("admin11111"));
+        assert_eq!(config.club_price, Uint128::from(1000000u128));
+        assert_eq!(config.reward_split_policy, RewardSplitPolicy::default());
+        assert_eq!(config.winning_club_strategy, WinningClubStrategy::default());
+        assert_eq!(config.move_stake_cooldown, DEFAULT_MOVE_STAKE_COOLDOWN);
+        assert_eq!(ADMIN.get(deps.as_ref()).unwrap(), Some(Addr::unchecked("admin11111")));
+        assert!(has_role(&deps.storage, Role::Distributor, &Addr::unchecked("admin11111")));
+        assert!(has_role(&deps.storage, Role::Assigner, &Addr::unchecked("admin11111")));
+        assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION.to_string());
+
+        // migrating again to the same version runs no step
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert!(res.attributes.iter().all(|attribute| attribute.key != "migration_step"));
+    }
+
+    #[test]
+    fn test_migrate_rejects_other_contract_and_downgrade() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        set_contract_version(&mut deps.storage, "crates.io:gaming-pool", "0.9.1").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert_eq!(err, ContractError::CannotMigrate { previous_contract: "crates.io:gaming-pool".to_string() });
+
+        set_contract_version(&mut deps.storage, CONTRACT_NAME, "99.0.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::CannotMigrateVersion {
+                previous_version: "99.0.0".to_string(),
+                new_version: CONTRACT_VERSION.to_string(),
             }
-        }
+        );
     }
 
     #[test]
-    fn test_buying_of_club_after_releasing_by_prev_owner() {
-        let mut deps = mock_dependencies();
+    fn test_migrate_club_rewards_out_of_reward_period_history() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        save_state_of_0_9_1(&mut deps.storage);
+        let club_reward = ClubRewardRecord {
+            club_name: "CLUB001".to_string(),
+            owner_address: "owner001".to_string(),
+            total_staked_amount: Uint128::from(100000u128),
+            reward_index_increment: Decimal::percent(10),
+            stakers_reward: Uint128::from(10000u128),
+            owner_reward: Uint128::from(300u128),
+        };
+        LEGACY_REWARD_PERIOD_HISTORY.save(
+            &mut deps.storage,
+            U64Key::new(1),
+            &LegacyRewardPeriodRecord {
+                reward_period: 1,
+                total_reward: Uint128::from(10300u128),
+                reward_given: Uint128::from(10300u128),
+                winner_list: vec!["CLUB001".to_string()],
+                club_rewards: vec![club_reward.clone()],
+                ..LegacyRewardPeriodRecord::default()
+            },
+        )
+            .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        let history = query_reward_period_history(&deps.storage, None, None).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reward_given, Uint128::from(10300u128));
+        assert_eq!(query_club_reward_history(&deps.storage, 1, None, None).unwrap(), vec![club_reward]);
+    }
+
+    #[test]
+    fn test_migrate_club_staking_details() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+        save_state_of_0_9_1(&mut deps.storage);
+
+        let stake = |club_name: &str, staker: &str, amount: u128| ClubStakingDetails {
+            stake_id: 0,
+            club_name: club_name.to_string(),
+            staker_address: staker.to_string(),
+            staking_start_timestamp: now,
+            staked_amount: Uint128::from(amount),
+            staking_duration: CLUB_STAKING_DURATION,
+            reward_amount: Uint128::zero(),
+            auto_stake: false,
+            reward_index: Decimal::zero(),
+        };
+        LEGACY_CLUB_STAKING_DETAILS.save(&mut deps.storage, ("CLUB001", "staker001"), &vec![stake("CLUB001", "staker001", 100)]).unwrap();
+        LEGACY_CLUB_STAKING_DETAILS.save(&mut deps.storage, ("CLUB001", "staker002"), &vec![stake("CLUB001", "staker002", 200)]).unwrap();
+        LEGACY_CLUB_STAKING_DETAILS.save(&mut deps.storage, ("CLUB002", "staker001"), &vec![stake("CLUB002", "staker001", 300)]).unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(LEGACY_CLUB_STAKING_DETAILS.keys(&deps.storage, None, None, Order::Ascending).count(), 0);
+
+        let stakes = query_all_stakes_for_user(&deps.storage, "staker001".to_string(), None, None).unwrap();
+        assert_eq!(stakes.len(), 2);
+        assert_eq!(stakes[0].stake_id, 1);
+        assert_eq!(stakes[0].club_name, "CLUB001".to_string());
+        assert_eq!(stakes[0].staked_amount, Uint128::from(100u128));
+        assert_eq!(stakes[1].stake_id, 3);
+        assert_eq!(stakes[1].club_name, "CLUB002".to_string());
+
+        let stakes = query_all_stakes_in_club(&deps.storage, "CLUB001".to_string(), None, None).unwrap();
+        assert_eq!(stakes.len(), 2);
+        assert_eq!(stakes[1].staker_address, "staker002".to_string());
+
+        // pages continue after the last stake id returned
+        let stakes = query_all_stakes_in_club(&deps.storage, "CLUB001".to_string(), None, Some(1)).unwrap();
+        assert_eq!(stakes.len(), 1);
+        let stakes = query_all_stakes_in_club(&deps.storage, "CLUB001".to_string(), Some(stakes[0].stake_id), Some(1)).unwrap();
+        assert_eq!(stakes.len(), 1);
+        assert_eq!(stakes[0].staker_address, "staker002".to_string());
+
+        // new stakes get the next stake id
+        save_club_stakes(&mut deps.storage, "CLUB002".to_string(), "staker002".to_string(), &vec![stake("CLUB002", "staker002", 400)]).unwrap();
+        let stakes = query_club_staking_details(&deps.storage, "CLUB002".to_string(), vec!["staker002".to_string()]).unwrap();
+        assert_eq!(stakes[0].stake_id, 4);
+    }
+
+    #[test]
+    fn test_paginated_enumeration_queries() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2722,9 +5153,10 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
-        let mintingContractInfo = mock_info("minting_admin11111", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
@@ -2732,96 +5164,189 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
-
-        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
-        let mut resp = buy_a_club(
-            deps.as_mut(),
-            mock_env(),
-            owner1_info.clone(),
-            "owner001".to_string(),
-            Some(String::default()),
-            "CLUB001".to_string(),
-            Uint128::from(1000000u128),
-            SET_AUTO_STAKE,
-        );
-        println!("{:?}", resp);
-        resp = release_club(
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        for (owner, club_name) in [("owner001", "CLUB001"), ("owner002", "CLUB002"), ("owner003", "CLUB003")] {
+            assign_a_club(
+                deps.as_mut(),
+                mock_env(),
+                adminInfo.clone(),
+                owner.to_string(),
+                Some(String::default()),
+                club_name.to_string(),
+                SET_AUTO_STAKE,
+            )
+                .unwrap();
+        }
+        for staker in ["staker001", "staker002", "staker003"] {
+            stake_on_a_club(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(staker, &[coin(10, "stake")]),
+                staker.to_string(),
+                "CLUB001".to_string(),
+                Uint128::from(100000u128),
+                SET_AUTO_STAKE,
+            )
+                .unwrap();
+        }
+        withdraw_stake_from_a_club(
             deps.as_mut(),
             mock_env(),
-            owner1_info.clone(),
-            "owner001".to_string(),
+            mock_info("staker002", &[]),
+            "staker002".to_string(),
             "CLUB001".to_string(),
-        );
-        println!("{:?}", resp);
+            Uint128::from(1000u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
 
-        let now = mock_env().block.time; // today
+        let clubs = query_all_club_ownership_details(&deps.storage, None, Some(2)).unwrap();
+        assert_eq!(clubs.len(), 2);
+        assert_eq!(clubs[1].club_name, "CLUB002".to_string());
+        let clubs = query_all_club_ownership_details(&deps.storage, Some("CLUB002".to_string()), Some(2)).unwrap();
+        assert_eq!(clubs.len(), 1);
+        assert_eq!(clubs[0].club_name, "CLUB003".to_string());
+
+        // the club owner holds a zero stake in the club
+        let stakers = query_all_stakers_in_club(&deps.storage, "CLUB001".to_string(), None, Some(2)).unwrap();
+        assert_eq!(stakers, vec!["owner001".to_string(), "staker001".to_string()]);
+        let stakers = query_all_stakers_in_club(&deps.storage, "CLUB001".to_string(), Some("staker001".to_string()), None).unwrap();
+        assert_eq!(stakers, vec!["staker002".to_string(), "staker003".to_string()]);
+
+        let bonders = query_all_bonders_in_club(&deps.storage, "CLUB001".to_string(), None, None).unwrap();
+        assert_eq!(bonders, vec!["staker002".to_string()]);
+
+        // the stakers of a club are listed a page at a time, not by an empty user list
+        let err = query_all_stakes(&deps.storage, vec![], None, None).unwrap_err();
+        assert!(err.to_string().contains("AllStakersInClub"));
+        let err = query_all_bonds(&deps.storage, vec![], None, None).unwrap_err();
+        assert!(err.to_string().contains("AllBondersInClub"));
+        let stakers = query_all_stakers_in_club(&deps.storage, "CLUB001".to_string(), None, None).unwrap();
+        let stakes = query_all_stakes(&deps.storage, stakers, None, Some(1)).unwrap();
+        assert_eq!(stakes.len(), 4);
+        let stakers = query_all_stakers_in_club(&deps.storage, "CLUB002".to_string(), None, None).unwrap();
+        let stakes = query_all_stakes(&deps.storage, stakers, Some("CLUB001".to_string()), Some(1)).unwrap();
+        assert_eq!(stakes.len(), 1);
+        assert_eq!(stakes[0].club_name, "CLUB002".to_string());
+        let bonds = query_all_bonds(&deps.storage, vec!["staker002".to_string()], None, None).unwrap();
+        assert_eq!(bonds.len(), 1);
+        assert_eq!(bonds[0].bonded_amount, Uint128::from(1000u128));
+    }
 
-        let query_res = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
-        match query_res {
-            Ok(mut cod) => {
-                assert_eq!(cod.owner_address, "owner001".to_string());
-                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
-                cod.start_timestamp = now.minus_seconds(22 * 24 * 60 * 60);
-                CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "CLUB001".to_string(), &cod);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+    #[test]
+    fn test_set_paused() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
 
-        release_club(
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
-            owner1_info.clone(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+        assign_a_club(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
             "owner001".to_string(),
+            Some(String::default()),
             "CLUB001".to_string(),
+            SET_AUTO_STAKE,
+        )
+            .unwrap();
+        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
+        let stake_msg = ExecuteMsg::StakeOnAClub {
+            staker: "staker001".to_string(),
+            club_name: "CLUB001".to_string(),
+            amount: Uint128::from(100000u128),
+            auto_stake: SET_AUTO_STAKE,
+        };
+        execute(deps.as_mut(), mock_env(), staker1Info.clone(), stake_msg.clone()).unwrap();
+
+        let set_paused_msg = ExecuteMsg::SetPaused {
+            buy: Some(true),
+            stake: Some(true),
+            withdraw: None,
+            claim: None,
+            distribute: None,
+        };
+        let err = execute(deps.as_mut(), mock_env(), staker1Info.clone(), set_paused_msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let res = execute(deps.as_mut(), mock_env(), adminInfo.clone(), set_paused_msg).unwrap();
+        assert_eq!(res.attributes[0].value, "set_paused".to_string());
+        assert_eq!(res.events, vec![Event::new("paused").add_attribute("operations", "buy,stake")]);
+        assert_eq!(
+            query_pause_status(&deps.storage).unwrap(),
+            PauseStatus {
+                buy: true,
+                stake: true,
+                withdraw: false,
+                claim: false,
+                distribute: false,
+            }
         );
 
-        let queryResAfterReleasing =
-            query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
-        match queryResAfterReleasing {
-            Ok(cod) => {
-                assert_eq!(cod.owner_address, "owner001".to_string());
-                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
-                assert_eq!(cod.owner_released, true);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+        // deposits are stopped while users can still exit
+        let err = execute(deps.as_mut(), mock_env(), staker1Info.clone(), stake_msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Paused { operation: "stake".to_string() });
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            ExecuteMsg::StakeWithdrawFromAClub {
+                staker: "staker001".to_string(),
+                club_name: "CLUB001".to_string(),
+                amount: Uint128::from(1000u128),
+                immediate_withdrawal: NO_IMMEDIATE_WITHDRAWAL,
+            },
+        )
+            .unwrap();
 
-        let owner2_info = mock_info("owner002", &[coin(0, "uusd")]);
-        let resp = buy_a_club(
+        // switches not given are left unchanged
+        let res = execute(
             deps.as_mut(),
             mock_env(),
-            owner2_info.clone(),
-            "owner002".to_string(),
-            Some("owner001".to_string()),
-            "CLUB001".to_string(),
-            Uint128::from(1000000u128),
-            SET_AUTO_STAKE,
-        );
-        println!("{:?}", resp);
-        let queryResAfterSellingByPrevOwner =
-            query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
-        match queryResAfterSellingByPrevOwner {
-            Ok(cod) => {
-                assert_eq!(cod.owner_address, "owner002".to_string());
-                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
-                assert_eq!(cod.owner_released, false);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+            adminInfo.clone(),
+            ExecuteMsg::SetPaused {
+                buy: None,
+                stake: Some(false),
+                withdraw: None,
+                claim: None,
+                distribute: None,
+            },
+        )
+            .unwrap();
+        assert_eq!(res.events, vec![Event::new("unpaused").add_attribute("operations", "stake")]);
+        assert_eq!(query_pause_status(&deps.storage).unwrap().buy, true);
+        execute(deps.as_mut(), mock_env(), staker1Info.clone(), stake_msg).unwrap();
     }
 
     #[test]
-    fn test_claim_previous_owner_rewards() {
-        let mut deps = mock_dependencies();
+    fn test_two_step_admin_and_roles() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2840,9 +5365,12 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
-        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        let newAdminInfo = mock_info("admin22222", &[]);
+        let botInfo = mock_info("distributor_bot", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
@@ -2850,36 +5378,107 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+        assert_eq!(
+            query_role_members(deps.as_ref(), Role::Distributor, None, None).unwrap(),
+            vec!["admin11111".to_string()]
+        );
 
-        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
-        buy_a_club(
+        // hand the distribution over to a bot, which can not assign clubs
+        execute(deps.as_mut(), mock_env(), adminInfo.clone(), ExecuteMsg::GrantRole { role: Role::Distributor, address: "distributor_bot".to_string() }).unwrap();
+        execute(deps.as_mut(), mock_env(), adminInfo.clone(), ExecuteMsg::RevokeRole { role: Role::Distributor, address: "admin11111".to_string() }).unwrap();
+        assert_eq!(
+            query_role_members(deps.as_ref(), Role::Distributor, None, None).unwrap(),
+            vec!["distributor_bot".to_string()]
+        );
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), botInfo.clone(), None).unwrap();
+        let err = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("not authorised")})));
+        let err = assign_a_club(
             deps.as_mut(),
             mock_env(),
-            owner1_info.clone(),
+            botInfo.clone(),
             "owner001".to_string(),
             Some(String::default()),
             "CLUB001".to_string(),
-            Uint128::from(1000000u128),
             SET_AUTO_STAKE,
-        );
-
-        release_club(
-            deps.as_mut(),
-            mock_env(),
-            owner1_info.clone(),
-            "owner001".to_string(),
-            "CLUB001".to_string(),
-        );
+        )
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let err = execute(deps.as_mut(), mock_env(), botInfo.clone(), ExecuteMsg::GrantRole { role: Role::Assigner, address: "distributor_bot".to_string() }).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // the new admin takes over only once it accepts
+        execute(deps.as_mut(), mock_env(), adminInfo.clone(), ExecuteMsg::ProposeNewAdmin { new_admin: "admin22222".to_string() }).unwrap();
+        assert_eq!(query_pending_admin(&deps.storage).unwrap(), Some("admin22222".to_string()));
+        let err = execute(deps.as_mut(), mock_env(), botInfo.clone(), ExecuteMsg::AcceptAdmin {}).unwrap_err();
+        assert_eq!(err, ContractError::NotPendingAdmin { address: "distributor_bot".to_string() });
+        assert_eq!(ADMIN.get(deps.as_ref()).unwrap(), Some(Addr::unchecked("admin11111")));
+        execute(deps.as_mut(), mock_env(), newAdminInfo.clone(), ExecuteMsg::AcceptAdmin {}).unwrap();
+        assert_eq!(ADMIN.get(deps.as_ref()).unwrap(), Some(Addr::unchecked("admin22222")));
+        assert_eq!(CONFIG.load(&deps.storage).unwrap().admin_address, Addr::unchecked("admin22222"));
+        assert_eq!(query_pending_admin(&deps.storage).unwrap(), None);
+
+        let err = execute(deps.as_mut(), mock_env(), adminInfo.clone(), ExecuteMsg::GrantRole { role: Role::Assigner, address: "admin11111".to_string() }).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute(deps.as_mut(), mock_env(), newAdminInfo.clone(), ExecuteMsg::RevokeRole { role: Role::Assigner, address: "admin11111".to_string() }).unwrap();
+        assert!(!has_role(&deps.storage, Role::Assigner, &Addr::unchecked("admin11111")));
+    }
 
+    #[test]
+    fn test_assign_stakes_to_a_club() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
-        let query_res = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
-        match query_res {
-            Ok(mut cod) => {
-                assert_eq!(cod.owner_address, "owner001".to_string());
-                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
-                cod.start_timestamp = now.minus_seconds(22 * 24 * 60 * 60);
-                CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "CLUB001".to_string(), &cod);
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(1000, "stake")]);
+
+        println!("Now assigning the club to owner001");
+        assign_a_club(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            SET_AUTO_STAKE,
+        );
+
+        let queryRes0 = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
+        match queryRes0 {
+            Ok(mut cod) => {
+                assert_eq!(cod.owner_address, "owner001".to_string());
+                assert_eq!(cod.price_paid, Uint128::from(0u128));
+                assert_eq!(cod.owner_released, false);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
@@ -2887,32 +5486,129 @@ mod tests {
             }
         }
 
-        let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
-        stake_on_a_club(
+        let mut stake_list: Vec<ClubStakingDetails> = Vec::new();
+        let mut user_address_list = Vec::new();
+        user_address_list.push("owner001".to_string());
+        for i in 1..7 {
+            let mut staker = String::default();
+            match i {
+                1 => { staker = "staker001".to_string(); }
+                2 => { staker = "staker002".to_string(); }
+                3 => { staker = "staker003".to_string(); }
+                4 => { staker = "staker004".to_string(); }
+                5 => { staker = "staker005".to_string(); }
+                6 => { staker = "staker006".to_string(); }
+                _ => {}
+            }
+            user_address_list.push(staker.clone());
+            println!("staker is {}", staker);
+            stake_list.push(ClubStakingDetails {
+                stake_id: 0,
+                staker_address: staker,
+                staking_start_timestamp: now,
+                staked_amount: Uint128::from(330000u128),
+                staking_duration: CLUB_STAKING_DURATION,
+                club_name: "CLUB001".to_string(),
+                reward_amount: Uint128::from(CLUB_STAKING_REWARD_AMOUNT),
+                auto_stake: SET_AUTO_STAKE,
+                reward_index: Decimal::zero(),
+            });
+        };
+
+        let staker6Info = mock_info("staker006", &[coin(10, "stake")]);
+        assign_stakes_to_a_club(
             deps.as_mut(),
             mock_env(),
-            stakerInfo.clone(),
-            "staker001".to_string(),
+            adminInfo.clone(),
+            stake_list,
             "CLUB001".to_string(),
-            Uint128::from(33u128),
-            SET_AUTO_STAKE,
         );
 
-        increase_reward_amount(
+        let queryRes1 = query_all_stakes(&mut deps.storage, user_address_list, None, None);
+        match queryRes1 {
+            Ok(all_stakes) => {
+                println!("all stakes : {:?}", all_stakes);
+                assert_eq!(all_stakes.len(), 7);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_buying_of_club_after_releasing_by_prev_owner() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
-            mintingContractInfo.clone(),
-            "reward_from abc".to_string(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        let mut resp = buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
             Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+        println!("{:?}", resp);
+        resp = release_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            "CLUB001".to_string(),
         );
+        println!("{:?}", resp);
 
-        let mut staker_list1 = Vec::new();
-        staker_list1.push("staker001".to_string());
-        staker_list1.push("owner001".to_string());
-        let club_name1 = "CLUB001".to_string();
-        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), staker_list1.clone(), club_name1, true, true);
+        let now = mock_env().block.time; // today
+
+        let query_res = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
+        match query_res {
+            Ok(mut cod) => {
+                assert_eq!(cod.owner_address, "owner001".to_string());
+                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
+                cod.start_timestamp = now.minus_seconds(22 * 24 * 60 * 60);
+                CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "CLUB001".to_string(), &cod);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
 
-        println!("releasing club");
         release_club(
             deps.as_mut(),
             mock_env(),
@@ -2925,10 +5621,6 @@ mod tests {
             query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
         match queryResAfterReleasing {
             Ok(cod) => {
-                println!(
-                    "before - owner:{:?}, reward {:?}",
-                    cod.owner_address, cod.reward_amount
-                );
                 assert_eq!(cod.owner_address, "owner001".to_string());
                 assert_eq!(cod.price_paid, Uint128::from(1000000u128));
                 assert_eq!(cod.owner_released, true);
@@ -2939,14 +5631,8 @@ mod tests {
             }
         }
 
-        println!(
-            "pod:\n {:?}",
-            query_all_previous_club_ownership_details(&mut deps.storage)
-        );
-
-        println!("buy a club with new owner");
         let owner2_info = mock_info("owner002", &[coin(0, "uusd")]);
-        buy_a_club(
+        let resp = buy_a_club(
             deps.as_mut(),
             mock_env(),
             owner2_info.clone(),
@@ -2956,15 +5642,11 @@ mod tests {
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
         );
-
+        println!("{:?}", resp);
         let queryResAfterSellingByPrevOwner =
             query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
         match queryResAfterSellingByPrevOwner {
             Ok(cod) => {
-                println!(
-                    "after - owner:{:?}, reward {:?}",
-                    cod.owner_address, cod.reward_amount
-                );
                 assert_eq!(cod.owner_address, "owner002".to_string());
                 assert_eq!(cod.price_paid, Uint128::from(1000000u128));
                 assert_eq!(cod.owner_released, false);
@@ -2974,55 +5656,11 @@ mod tests {
                 assert_eq!(1, 2);
             }
         }
-        println!("checking previous owner details now");
-
-        /*
-        27 Feb 2022, Commenting this out - because the reward is now moved to stake
-                     so there will be no previous owner details
-
-        let queryPrevOwnerDetailsBeforeRewardClaim =
-            query_club_previous_owner_details(&mut deps.storage, "owner001".to_string());
-        match queryPrevOwnerDetailsBeforeRewardClaim {
-            Ok(pod) => {
-                println!(
-                    "before - owner:{:?}, reward {:?}",
-                    pod.previous_owner_address, pod.reward_amount
-                );
-                assert_eq!(pod.previous_owner_address, "owner001".to_string());
-                assert_eq!(pod.reward_amount, Uint128::from(10000u128));
-            }
-            Err(e) => {
-                println!("error parsing cpod header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
-        */
-
-        println!(
-            "pod:\n {:?}",
-            query_all_previous_club_ownership_details(&mut deps.storage)
-        );
-
-        claim_previous_owner_rewards(deps.as_mut(), owner1_info.clone(), "owner001".to_string());
-        let queryPrevOwnerDetailsAfterRewardClaim =
-            query_club_previous_owner_details(&mut deps.storage, "owner001".to_string())
-                .unwrap_err();
-        assert_eq!(
-            queryPrevOwnerDetailsAfterRewardClaim,
-            (StdError::GenericErr {
-                msg: String::from("No previous ownership details found")
-            })
-        );
-
-        println!(
-            "pod:\n {:?}",
-            query_all_previous_club_ownership_details(&mut deps.storage)
-        );
     }
 
     #[test]
-    fn test_claim_rewards_with_no_auto_stake() {
-        let mut deps = mock_dependencies();
+    fn test_claim_previous_owner_rewards() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3041,6 +5679,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -3051,6 +5691,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
         buy_a_club(
@@ -3061,9 +5702,32 @@ mod tests {
             Some(String::default()),
             "CLUB001".to_string(),
             Uint128::from(1000000u128),
-            false, // NO AUTO STAKE
+            SET_AUTO_STAKE,
+        );
+
+        release_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            "CLUB001".to_string(),
         );
 
+        let now = mock_env().block.time; // today
+
+        let query_res = query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
+        match query_res {
+            Ok(mut cod) => {
+                assert_eq!(cod.owner_address, "owner001".to_string());
+                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
+                cod.start_timestamp = now.minus_seconds(22 * 24 * 60 * 60);
+                CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "CLUB001".to_string(), &cod);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
 
         let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
         stake_on_a_club(
@@ -3072,28 +5736,201 @@ mod tests {
             stakerInfo.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(33000u128),
-            false, // NO AUTO STAKE
+            Uint128::from(33u128),
+            SET_AUTO_STAKE,
         );
 
         increase_reward_amount(
             deps.as_mut(),
             mock_env(),
-            adminInfo.clone(),
+            mintingContractInfo.clone(),
             "reward_from abc".to_string(),
             Uint128::from(1000000u128),
         );
 
-        let mut staker_list1 = Vec::new();
-        staker_list1.push("staker001".to_string());
-        staker_list1.push("owner001".to_string());
-        let club_name1 = "CLUB001".to_string();
-        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), staker_list1.clone(), club_name1, true, true);
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None);
 
-        let mut user_address_list = Vec::new();
-        user_address_list.push("staker001".to_string());
+        println!("releasing club");
+        release_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            "CLUB001".to_string(),
+        );
+
+        let queryResAfterReleasing =
+            query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
+        match queryResAfterReleasing {
+            Ok(cod) => {
+                println!(
+                    "before - owner:{:?}, reward {:?}",
+                    cod.owner_address, cod.reward_amount
+                );
+                assert_eq!(cod.owner_address, "owner001".to_string());
+                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
+                assert_eq!(cod.owner_released, true);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        println!(
+            "pod:\n {:?}",
+            query_all_previous_club_ownership_details(&mut deps.storage, None, None)
+        );
+
+        println!("buy a club with new owner");
+        let owner2_info = mock_info("owner002", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner2_info.clone(),
+            "owner002".to_string(),
+            Some("owner001".to_string()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let queryResAfterSellingByPrevOwner =
+            query_club_ownership_details(&mut deps.storage, "CLUB001".to_string());
+        match queryResAfterSellingByPrevOwner {
+            Ok(cod) => {
+                println!(
+                    "after - owner:{:?}, reward {:?}",
+                    cod.owner_address, cod.reward_amount
+                );
+                assert_eq!(cod.owner_address, "owner002".to_string());
+                assert_eq!(cod.price_paid, Uint128::from(1000000u128));
+                assert_eq!(cod.owner_released, false);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+        println!("checking previous owner details now");
+
+        /*
+        27 Feb 2022, Commenting this out - because the reward is now moved to stake
+                     so there will be no previous owner details
+
+        let queryPrevOwnerDetailsBeforeRewardClaim =
+            query_club_previous_owner_details(&mut deps.storage, "owner001".to_string());
+        match queryPrevOwnerDetailsBeforeRewardClaim {
+            Ok(pod) => {
+                println!(
+                    "before - owner:{:?}, reward {:?}",
+                    pod.previous_owner_address, pod.reward_amount
+                );
+                assert_eq!(pod.previous_owner_address, "owner001".to_string());
+                assert_eq!(pod.reward_amount, Uint128::from(10000u128));
+            }
+            Err(e) => {
+                println!("error parsing cpod header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+        */
+
+        println!(
+            "pod:\n {:?}",
+            query_all_previous_club_ownership_details(&mut deps.storage, None, None)
+        );
+
+        claim_previous_owner_rewards(deps.as_mut(), owner1_info.clone(), "owner001".to_string());
+        let queryPrevOwnerDetailsAfterRewardClaim =
+            query_club_previous_owner_details(&mut deps.storage, "owner001".to_string())
+                .unwrap_err();
+        assert_eq!(
+            queryPrevOwnerDetailsAfterRewardClaim,
+            (StdError::GenericErr {
+                msg: String::from("No previous ownership details found")
+            })
+        );
+
+        println!(
+            "pod:\n {:?}",
+            query_all_previous_club_ownership_details(&mut deps.storage, None, None)
+        );
+    }
+
+    #[test]
+    fn test_claim_rewards_with_no_auto_stake() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            false, // NO AUTO STAKE
+        );
+
+
+        let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(33000u128),
+            false, // NO AUTO STAKE
+        );
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        );
+
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None);
+
+        let mut user_address_list = Vec::new();
+        user_address_list.push("staker001".to_string());
         user_address_list.push("owner001".to_string());
-        let queryRes = query_all_stakes(&mut deps.storage, user_address_list);
+        let queryRes = query_all_stakes(&mut deps.storage, user_address_list, None, None);
         match queryRes {
             Ok(all_stakes) => {
                 assert_eq!(all_stakes.len(), 2);
@@ -3103,7 +5940,7 @@ mod tests {
                     let staked_amount = stake.staked_amount;
                     println!("staker : {:?} reward_amount : {:?} staked_amount : {:?}", staker_address.clone(), reward_amount, staked_amount);
                     if staker_address == "staker001" {
-                        assert_eq!(reward_amount, Uint128::from(970000u128));
+                        assert_eq!(reward_amount, Uint128::from(969999u128));
                         assert_eq!(staked_amount, Uint128::from(33000u128));
                     }
                     if staker_address == "owner001" {
@@ -3121,7 +5958,7 @@ mod tests {
 
     #[test]
     fn test_multiple_staking_on_club_by_same_address() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3140,6 +5977,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -3150,6 +5989,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
         buy_a_club(
@@ -3195,7 +6035,7 @@ mod tests {
         let mut user_address_list = Vec::new();
         user_address_list.push("staker001".to_string());
         user_address_list.push("owner001".to_string());
-        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list);
+        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list, None, None);
         match query_stakes {
             Ok(all_stakes) => {
                 assert_eq!(all_stakes.len(), 2);
@@ -3216,7 +6056,7 @@ mod tests {
 
     #[test]
     fn test_immediate_partial_withdrawals_from_club() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3235,6 +6075,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -3245,6 +6087,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
         buy_a_club(
@@ -3299,7 +6142,7 @@ mod tests {
         let mut user_address_list = Vec::new();
         user_address_list.push("staker001".to_string());
         user_address_list.push("owner001".to_string());
-        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone());
+        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
         match query_stakes {
             Ok(all_stakes) => {
                 assert_eq!(all_stakes.len(), 2);
@@ -3317,7 +6160,7 @@ mod tests {
             }
         }
 
-        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone());
+        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone(), None, None);
         match queryBonds {
             Ok(all_bonds) => {
                 assert_eq!(all_bonds.len(), 0);
@@ -3331,7 +6174,7 @@ mod tests {
 
     #[test]
     fn test_immediate_complete_withdrawals_from_club() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3350,6 +6193,8 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
         let mintingContractInfo = mock_info("minting_admin11111", &[]);
@@ -3360,6 +6205,7 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
         let owner1Info = mock_info("owner001", &[coin(1000, "stake")]);
         buy_a_club(
@@ -3423,7 +6269,7 @@ mod tests {
         let mut user_address_list = Vec::new();
         user_address_list.push("staker001".to_string());
         user_address_list.push("owner001".to_string());
-        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone());
+        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
         match query_stakes {
             Ok(all_stakes) => {
                 assert_eq!(all_stakes.len(), 2);
@@ -3434,7 +6280,7 @@ mod tests {
             }
         }
 
-        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone());
+        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone(), None, None);
         match queryBonds {
             Ok(all_bonds) => {
                 assert_eq!(all_bonds.len(), 0);
@@ -3447,8 +6293,8 @@ mod tests {
     }
 
     #[test]
-    fn test_non_immediate_complete_withdrawals_from_club() {
-        let mut deps = mock_dependencies();
+    fn test_early_unbond_penalty() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3467,162 +6313,320 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
-        let admin_info = mock_info("admin11111", &[]);
-        let minting_contract_info = mock_info("minting_admin11111", &[]);
+        let adminInfo = mock_info("admin11111", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
-            admin_info.clone(),
+            adminInfo.clone(),
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        let owner1Info = mock_info("owner001", &[]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
-            owner1_info.clone(),
+            owner1Info.clone(),
             "owner001".to_string(),
             Some(String::default()),
             "CLUB001".to_string(),
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
-        );
+        )
+            .unwrap();
 
-        let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("staker001", &[]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
             stakerInfo.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(99u128),
+            Uint128::from(100u128),
             SET_AUTO_STAKE,
-        );
+        )
+            .unwrap();
         withdraw_stake_from_a_club(
             deps.as_mut(),
             mock_env(),
             stakerInfo.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(11u128),
+            Uint128::from(40u128),
             NO_IMMEDIATE_WITHDRAWAL,
-        );
-        withdraw_stake_from_a_club(
+        )
+            .unwrap();
+
+        // half way through bonding
+        let mut halfway_env = mock_env();
+        halfway_env.block.time = now.plus_seconds(150);
+
+        // disabled by default, bonds are only withdrawn after maturity
+        let quote = query_early_unbond_quote(
+            deps.as_ref(),
+            halfway_env.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(30u128),
+        )
+            .unwrap();
+        assert_eq!(quote.early_amount, Uint128::zero());
+        assert_eq!(quote.shortfall, Uint128::from(30u128));
+
+        let linear_policy = EarlyUnbondPolicy {
+            curve: PenaltyCurve::Linear { max_penalty: Uint128::from(2000u128) },
+            destination: PenaltyDestination::RewardPool,
+        };
+        let err = update_early_unbond_policy(deps.as_mut(), stakerInfo.clone(), linear_policy.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let err = update_early_unbond_policy(
             deps.as_mut(),
-            mock_env(),
-            stakerInfo.clone(),
+            adminInfo.clone(),
+            EarlyUnbondPolicy {
+                curve: PenaltyCurve::Linear { max_penalty: Uint128::from(10001u128) },
+                destination: PenaltyDestination::Burn,
+            },
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Early unbond penalty can not be more than 100%"),
+            })
+        );
+        update_early_unbond_policy(deps.as_mut(), adminInfo.clone(), linear_policy.clone()).unwrap();
+        assert_eq!(query_early_unbond_policy(&deps.storage).unwrap(), linear_policy);
+
+        // 10% penalty half way through bonding
+        let quote = query_early_unbond_quote(
+            deps.as_ref(),
+            halfway_env.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(12u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            Uint128::from(30u128),
+        )
+            .unwrap();
+        assert_eq!(
+            quote,
+            EarlyUnbondQuoteResponse {
+                matured_amount: Uint128::zero(),
+                early_amount: Uint128::from(30u128),
+                penalty: Uint128::from(3u128),
+                shortfall: Uint128::zero(),
+                amount_received: Uint128::from(27u128),
+            }
         );
-        withdraw_stake_from_a_club(
+
+        let rsp = withdraw_stake_from_a_club(
             deps.as_mut(),
-            mock_env(),
+            halfway_env.clone(),
             stakerInfo.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(13u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            Uint128::from(30u128),
+            IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
+        assert_eq!(
+            rsp.messages,
+            vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "minting_admin11111".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "staker001".to_string(),
+                    amount: Uint128::from(27u128),
+                })
+                    .unwrap(),
+                funds: vec![],
+            }))]
         );
-        withdraw_stake_from_a_club(
+        assert_eq!(query_reward_amount(&deps.storage).unwrap(), Uint128::from(3u128));
+        let bonds = query_club_bonding_details_for_user(&deps.storage, "CLUB001".to_string(), "staker001".to_string()).unwrap();
+        assert_eq!(bonds.len(), 1);
+        assert_eq!(bonds[0].bonded_amount, Uint128::from(10u128));
+
+        // stepped penalty, burnt
+        update_early_unbond_policy(
             deps.as_mut(),
-            mock_env(),
+            adminInfo.clone(),
+            EarlyUnbondPolicy {
+                curve: PenaltyCurve::Stepped {
+                    steps: vec![
+                        PenaltyStep { min_remaining_seconds: 200, penalty: Uint128::from(5000u128) },
+                        PenaltyStep { min_remaining_seconds: 100, penalty: Uint128::from(1000u128) },
+                    ],
+                },
+                destination: PenaltyDestination::Burn,
+            },
+        )
+            .unwrap();
+        let rsp = withdraw_stake_from_a_club(
+            deps.as_mut(),
+            halfway_env.clone(),
             stakerInfo.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(63u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            Uint128::from(10u128),
+            IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
+        assert_eq!(rsp.messages.len(), 2);
+        assert_eq!(
+            rsp.messages[0],
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "minting_admin11111".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Burn { amount: Uint128::from(1u128) }).unwrap(),
+                funds: vec![],
+            }))
         );
+        let bonds = query_club_bonding_details_for_user(&deps.storage, "CLUB001".to_string(), "staker001".to_string()).unwrap();
+        assert_eq!(bonds.len(), 0);
+    }
 
-        let mut user_address_list = Vec::new();
-        user_address_list.push("staker001".to_string());
-        user_address_list.push("owner001".to_string());
-        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match query_stakes {
-            Ok(all_stakes) => {
-                assert_eq!(all_stakes.len(), 2);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+    #[test]
+    fn test_move_stake_between_clubs() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
 
-        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone());
-        match queryBonds {
-            Ok(all_bonds) => {
-                assert_eq!(all_bonds.len(), 4);
-                for bond in all_bonds {
-                    if bond.bonded_amount != Uint128::from(11u128)
-                        && bond.bonded_amount != Uint128::from(12u128)
-                        && bond.bonded_amount != Uint128::from(13u128)
-                        && bond.bonded_amount != Uint128::from(63u128)
-                    {
-                        println!("bond is {:?} ", bond);
-                        assert_eq!(1, 2);
-                    }
-                }
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.plus_seconds(24 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        for (owner, club_name) in [("owner001", "CLUB001"), ("owner002", "CLUB002")] {
+            buy_a_club(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(owner, &[]),
+                owner.to_string(),
+                Some(String::default()),
+                club_name.to_string(),
+                Uint128::from(1000000u128),
+                SET_AUTO_STAKE,
+            )
+                .unwrap();
         }
-        let stakerInfo = mock_info("staker002", &[coin(10, "stake")]);
+
+        let stakerInfo = mock_info("staker001", &[]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
             stakerInfo.clone(),
-            "staker002".to_string(),
+            "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(99u128),
+            Uint128::from(100u128),
             SET_AUTO_STAKE,
+        )
+            .unwrap();
+
+        // moves are rate limited from the start
+        assert_eq!(CONFIG.load(&deps.storage).unwrap().move_stake_cooldown, DEFAULT_MOVE_STAKE_COOLDOWN);
+        let err = update_move_stake_cooldown(deps.as_mut(), stakerInfo.clone(), 600).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        update_move_stake_cooldown(deps.as_mut(), adminInfo.clone(), 600).unwrap();
+
+        let err = move_stake(deps.as_mut(), mock_env(), stakerInfo.clone(), "CLUB001".to_string(), "CLUB002".to_string(), Uint128::from(101u128)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Excess amount demanded for moving"),
+            })
         );
-        withdraw_stake_from_a_club(
-            deps.as_mut(),
-            mock_env(),
-            stakerInfo.clone(),
-            "staker002".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(11u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+        let err = move_stake(deps.as_mut(), mock_env(), stakerInfo.clone(), "CLUB001".to_string(), "CLUB001".to_string(), Uint128::from(40u128)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Stake can only be moved to another club"),
+            })
         );
 
-        let queryBonds = query_club_bonding_details_for_user(
-            &mut deps.storage,
-            "CLUB001".to_string(),
-            "staker002".to_string(),
+        let rsp = move_stake(deps.as_mut(), mock_env(), stakerInfo.clone(), "CLUB001".to_string(), "CLUB002".to_string(), Uint128::from(40u128)).unwrap();
+        assert_eq!(rsp.messages.len(), 0);
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("stake_moved")
+                .add_attribute("staker", "staker001")
+                .add_attribute("from_club", "CLUB001")
+                .add_attribute("to_club", "CLUB002")
+                .add_attribute("amount", "40")]
         );
-        match queryBonds {
-            Ok(all_bonds) => {
-                assert_eq!(all_bonds.len(), 1);
-                for bond in all_bonds {
-                    if bond.bonded_amount != Uint128::from(11u128) {
-                        println!("bond is {:?} ", bond);
-                        assert_eq!(1, 2);
-                    }
-                }
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+        let club1 = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(club1.total_staked_amount, Uint128::from(60u128));
+        let club2 = query_club_ownership_details(&deps.storage, "CLUB002".to_string()).unwrap();
+        assert_eq!(club2.total_staked_amount, Uint128::from(40u128));
+        let stakes = query_all_stakes_for_user(&deps.storage, "staker001".to_string(), None, None).unwrap();
+        assert_eq!(stakes.len(), 2);
+
+        // at most one move per cooldown
+        let err = move_stake(deps.as_mut(), mock_env(), stakerInfo.clone(), "CLUB002".to_string(), "CLUB001".to_string(), Uint128::from(10u128)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Stake was moved too recently"),
+            })
+        );
+        let mut later_env = mock_env();
+        later_env.block.time = now.plus_seconds(700);
+        move_stake(deps.as_mut(), later_env, stakerInfo.clone(), "CLUB002".to_string(), "CLUB001".to_string(), Uint128::from(10u128)).unwrap();
+        let club2 = query_club_ownership_details(&deps.storage, "CLUB002".to_string()).unwrap();
+        assert_eq!(club2.total_staked_amount, Uint128::from(30u128));
+
+        // no moves just before the reward distribution
+        let mut before_reward_env = mock_env();
+        before_reward_env.block.time = now.plus_seconds(24 * 60 * 60 - 300);
+        let err = move_stake(deps.as_mut(), before_reward_env, stakerInfo.clone(), "CLUB002".to_string(), "CLUB001".to_string(), Uint128::from(10u128)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Stake can not be moved this close to the reward distribution"),
+            })
+        );
+
+        // moves are allowed again once the distribution time has passed
+        let mut overdue_reward_env = mock_env();
+        overdue_reward_env.block.time = now.plus_seconds(24 * 60 * 60 + 300);
+        move_stake(deps.as_mut(), overdue_reward_env, stakerInfo.clone(), "CLUB002".to_string(), "CLUB001".to_string(), Uint128::from(10u128)).unwrap();
+        let club2 = query_club_ownership_details(&deps.storage, "CLUB002".to_string()).unwrap();
+        assert_eq!(club2.total_staked_amount, Uint128::from(20u128));
     }
 
     #[test]
-    fn test_non_immediate_complete_withdrawals_from_club_with_scheduled_refunds() {
-        let mut deps = mock_dependencies();
+    fn test_set_auto_stake_and_compound_rewards() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
             admin_address: "admin11111".to_string(),
             minting_contract_address: "minting_admin11111".to_string(),
             astro_proxy_address: "astro_proxy_address1111".to_string(),
-            club_fee_collector_wallet: "feecollector11111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
             club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
             reward_periodicity: 24 * 60 * 60u64,
             club_price: Uint128::from(1000000u128),
@@ -3634,153 +6638,106 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
-        let mintingContractInfo = mock_info("minting_admin11111", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
             instantiate_msg,
-        );
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        let owner1Info = mock_info("owner001", &[]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
-            owner1_info.clone(),
+            owner1Info.clone(),
             "owner001".to_string(),
             Some(String::default()),
             "CLUB001".to_string(),
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
+        )
+            .unwrap();
+
+        let stakerInfo = mock_info("staker001", &[]);
+        let err = set_auto_stake(deps.as_mut(), stakerInfo.clone(), "CLUB001".to_string(), true).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("User has not staked in this club"),
+            })
         );
 
-        let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
             stakerInfo.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(99u128),
-            SET_AUTO_STAKE,
-        );
-        withdraw_stake_from_a_club(
-            deps.as_mut(),
-            mock_env(),
-            stakerInfo.clone(),
-            "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(11u128),
-            NO_IMMEDIATE_WITHDRAWAL,
-        );
-        withdraw_stake_from_a_club(
-            deps.as_mut(),
-            mock_env(),
-            stakerInfo.clone(),
-            "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(12u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            Uint128::from(100u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        let err = compound_rewards(deps.as_mut(), stakerInfo.clone(), "CLUB001".to_string()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("No rewards for this user"),
+            })
         );
-        withdraw_stake_from_a_club(
+
+        increase_reward_amount(
             deps.as_mut(),
             mock_env(),
-            stakerInfo.clone(),
-            "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(13u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+        let reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert!(!reward.is_zero());
+        let club_before = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+
+        // rewards are moved into the stake without any transfer
+        let rsp = compound_rewards(deps.as_mut(), stakerInfo.clone(), "CLUB001".to_string()).unwrap();
+        assert_eq!(rsp.messages.len(), 0);
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("rewards_compounded")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("staker", "staker001")
+                .add_attribute("amount", reward)]
         );
-        withdraw_stake_from_a_club(
-            deps.as_mut(),
-            mock_env(),
-            stakerInfo.clone(),
-            "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(63u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+        let stakes = query_club_staking_details(&deps.storage, "CLUB001".to_string(), vec!["staker001".to_string()]).unwrap();
+        assert_eq!(stakes[0].staked_amount, Uint128::from(100u128) + reward);
+        assert_eq!(stakes[0].reward_amount, Uint128::zero());
+        let club = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(club.total_staked_amount, club_before.total_staked_amount + reward);
+        let reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(reward, Uint128::zero());
+
+        let rsp = set_auto_stake(deps.as_mut(), stakerInfo.clone(), "CLUB001".to_string(), true).unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("auto_stake_set")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("staker", "staker001")
+                .add_attribute("auto_stake", "true")]
         );
-
-        let mut user_address_list = Vec::new();
-        user_address_list.push("staker001".to_string());
-        user_address_list.push("owner001".to_string());
-        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match query_stakes {
-            Ok(all_stakes) => {
-                assert_eq!(all_stakes.len(), 2);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
-
-        let now = mock_env().block.time; // today
-
-        let query_bonds = query_all_bonds(&mut deps.storage, user_address_list.clone());
-        let club_name = "CLUB001".to_string();
-        match query_bonds {
-            Ok(all_bonds) => {
-                let existing_bonds = all_bonds.clone();
-                let mut updated_bonds = Vec::new();
-                assert_eq!(existing_bonds.len(), 4);
-                for user_addr in user_address_list.clone() {
-                    for bond in existing_bonds.clone() {
-                        let mut updated_bond = bond.clone();
-                        if updated_bond.bonded_amount != Uint128::from(11u128)
-                            && updated_bond.bonded_amount != Uint128::from(12u128)
-                            && updated_bond.bonded_amount != Uint128::from(13u128)
-                            && updated_bond.bonded_amount != Uint128::from(63u128)
-                        {
-                            println!("updated_bond is {:?} ", updated_bond);
-                            assert_eq!(1, 2);
-                        }
-                        if updated_bond.bonded_amount == Uint128::from(63u128) {
-                            updated_bond.bonding_start_timestamp = now.minus_seconds(8 * 24 * 60 * 60);
-                        }
-                        updated_bonds.push(updated_bond);
-                    }
-                    CLUB_BONDING_DETAILS.save(&mut deps.storage, (&club_name.clone(), &user_addr.clone()), &updated_bonds);
-                }
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
-
-        /*
-                Commenting out as this is no longer used, 6 Apr 2022
-
-                periodically_refund_stakeouts(deps.as_mut(), mock_env(), adminInfo);
-
-                let queryBondsAfterPeriodicRefund = query_all_bonds(&mut deps.storage, user_address_list.clone());
-                match queryBondsAfterPeriodicRefund {
-                    Ok(all_bonds) => {
-                        assert_eq!(all_bonds.len(), 3);
-                        for bond in all_bonds {
-                            if bond.bonded_amount != Uint128::from(11u128)
-                                && bond.bonded_amount != Uint128::from(12u128)
-                                && bond.bonded_amount != Uint128::from(13u128)
-                            {
-                                println!("bond is {:?} ", bond);
-                                assert_eq!(1, 2);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        println!("error parsing header: {:?}", e);
-                        assert_eq!(1, 2);
-                    }
-                }
-        */
+        let stakes = query_club_staking_details(&deps.storage, "CLUB001".to_string(), vec!["staker001".to_string()]).unwrap();
+        assert!(stakes.iter().all(|stake| stake.auto_stake));
     }
 
     #[test]
-    fn test_non_immediate_partial_withdrawals_from_club() {
-        let mut deps = mock_dependencies();
+    fn test_auto_stake_compounds_rewards_lazily() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3799,9 +6756,10 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
-        let mintingContractInfo = mock_info("minting_admin11111", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
@@ -3809,102 +6767,260 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
-        let result = buy_a_club(
+        let owner1Info = mock_info("owner001", &[]);
+        buy_a_club(
             deps.as_mut(),
             mock_env(),
-            owner1_info.clone(),
+            owner1Info.clone(),
             "owner001".to_string(),
             Some(String::default()),
             "CLUB001".to_string(),
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
-        );
-        println!("buy_a_club result = {:?}", result);
-        let stakerInfo = mock_info("staker001", &[coin(10, "uusd")]);
-        stake_on_a_club(
+        )
+            .unwrap();
+
+        // both stake the same amount without auto stake, then staker001 opts in
+        for staker in ["staker001", "staker002"] {
+            stake_on_a_club(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(staker, &[]),
+                staker.to_string(),
+                "CLUB001".to_string(),
+                Uint128::from(100u128),
+                false, // NO AUTO STAKE
+            )
+                .unwrap();
+        }
+        let staker1Info = mock_info("staker001", &[]);
+        set_auto_stake(deps.as_mut(), staker1Info.clone(), "CLUB001".to_string(), SET_AUTO_STAKE).unwrap();
+
+        increase_reward_amount(
             deps.as_mut(),
             mock_env(),
-            stakerInfo.clone(),
-            "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(99u128),
-            SET_AUTO_STAKE,
-        );
-        withdraw_stake_from_a_club(
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+        let club_before = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+
+        // the rewards of staker002 are left unclaimed, staker001 has them compounded
+        let unclaimed = query_staker_rewards(deps.as_ref(), "staker002".to_string(), "CLUB001".to_string()).unwrap();
+        assert!(!unclaimed.is_zero());
+        let stakes = query_club_staking_details(&deps.storage, "CLUB001".to_string(), vec!["staker002".to_string()]).unwrap();
+        assert_eq!(stakes[0].staked_amount, Uint128::from(100u128));
+        assert_eq!(stakes[0].reward_amount, unclaimed);
+
+        let reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(reward, Uint128::zero());
+        let stakes = query_club_staking_details(&deps.storage, "CLUB001".to_string(), vec!["staker001".to_string()]).unwrap();
+        assert_eq!(stakes[0].staked_amount, Uint128::from(100u128) + unclaimed);
+        assert_eq!(stakes[0].reward_amount, Uint128::zero());
+
+        // the compounded rewards count towards the club once the stake is touched again
+        stake_on_a_club(
             deps.as_mut(),
             mock_env(),
-            stakerInfo.clone(),
+            staker1Info.clone(),
             "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(11u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            Uint128::from(100u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap();
+        let club = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(club.total_staked_amount, club_before.total_staked_amount + unclaimed + Uint128::from(100u128));
+        let stakes = query_club_staking_details(&deps.storage, "CLUB001".to_string(), vec!["staker001".to_string()]).unwrap();
+        let staked: Uint128 = stakes.iter().map(|stake| stake.staked_amount).sum();
+        assert_eq!(staked, Uint128::from(200u128) + unclaimed);
+    }
+
+    #[test]
+    fn test_club_registry() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.plus_seconds(24 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+
+        let profile = ClubProfile {
+            display_name: "Mumbai Indians".to_string(),
+            logo_url: Some("https://example.com/mi.png".to_string()),
+            sport: "cricket".to_string(),
+            league: "IPL".to_string(),
+            active: true,
+        };
+        let owner1Info = mock_info("owner001", &[]);
+        let err = register_club(deps.as_mut(), owner1Info.clone(), "MI".to_string(), profile.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        for invalid_name in ["MI", "MUMBAI INDIANS", "MUMBAI-INDIANS", "MUMBAI_INDIANS_CRICKET_CLUB_OF_INDIA"] {
+            let err = register_club(deps.as_mut(), adminInfo.clone(), invalid_name.to_string(), profile.clone()).unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Club name must have 3 to 32 letters, digits or underscores"),
+                })
+            );
+        }
+
+        // names are registered in upper case
+        register_club(deps.as_mut(), adminInfo.clone(), " mumbai_indians ".to_string(), profile.clone()).unwrap();
+        let err = register_club(deps.as_mut(), adminInfo.clone(), "MUMBAI_INDIANS".to_string(), profile.clone()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Club is already registered"),
+            })
         );
-        withdraw_stake_from_a_club(
+
+        let err = buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1Info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CHENNAI_SUPER_KINGS".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Club is not registered"),
+            })
+        );
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1Info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "MUMBAI_INDIANS".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap();
+
+        // the club is found whatever the case of the name
+        let stakerInfo = mock_info("staker001", &[]);
+        stake_on_a_club(
             deps.as_mut(),
             mock_env(),
             stakerInfo.clone(),
             "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(12u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            "Mumbai_Indians".to_string(),
+            Uint128::from(100u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap();
+
+        let club_info = query_club_info(deps.as_ref(), "MUMBAI_INDIANS".to_string()).unwrap();
+        assert_eq!(
+            club_info,
+            ClubInfoResponse {
+                club_name: "MUMBAI_INDIANS".to_string(),
+                profile: profile.clone(),
+                owner_address: Some("owner001".to_string()),
+                owner_released: false,
+                total_staked_amount: Uint128::from(100u128),
+            }
         );
-        let result = withdraw_stake_from_a_club(
+
+        // inactive clubs can not be staked on
+        let inactive_profile = ClubProfile {
+            active: false,
+            ..profile.clone()
+        };
+        update_club_profile(deps.as_mut(), adminInfo.clone(), "MUMBAI_INDIANS".to_string(), inactive_profile).unwrap();
+        let err = stake_on_a_club(
             deps.as_mut(),
             mock_env(),
             stakerInfo.clone(),
             "staker001".to_string(),
-            "CLUB001".to_string(),
-            Uint128::from(13u128),
-            NO_IMMEDIATE_WITHDRAWAL,
+            "MUMBAI_INDIANS".to_string(),
+            Uint128::from(100u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Club is not active"),
+            })
         );
-        println!("result = {:?}", result);
-        let mut user_address_list = Vec::new();
-        user_address_list.push("staker001".to_string());
-        user_address_list.push("owner001".to_string());
-        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match query_stakes {
-            Ok(all_stakes) => {
-                assert_eq!(all_stakes.len(), 2);
-                for stake in all_stakes {
-                    if stake.staker_address == "staker001".to_string() {
-                        assert_eq!(stake.staked_amount, Uint128::from(63u128));
-                    } else {
-                        assert_eq!(stake.staked_amount, Uint128::from(0u128));
-                    }
-                }
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
 
-        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone());
-        match queryBonds {
-            Ok(all_bonds) => {
-                assert_eq!(all_bonds.len(), 3);
-                for bond in all_bonds {
-                    if bond.bonded_amount != Uint128::from(11u128)
-                        && bond.bonded_amount != Uint128::from(12u128)
-                        && bond.bonded_amount != Uint128::from(13u128)
-                    {
-                        println!("bond is {:?} ", bond);
-                        assert_eq!(1, 2);
-                    }
-                }
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+        // clubs bought before the registry existed are registered on migration
+        CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "CLUB001".to_string(), &ClubOwnershipDetails {
+            club_name: "CLUB001".to_string(),
+            start_timestamp: now,
+            locking_period: 0,
+            owner_address: "owner002".to_string(),
+            price_paid: Uint128::from(1000000u128),
+            reward_amount: Uint128::zero(),
+            owner_released: false,
+            total_staked_amount: Uint128::zero(),
+        }).unwrap();
+        migrate_to_0_12_0(deps.as_mut()).unwrap();
+        let club_info = query_club_info(deps.as_ref(), "CLUB001".to_string()).unwrap();
+        assert_eq!(club_info.profile.display_name, "CLUB001".to_string());
+        assert!(club_info.profile.active);
+        let club_info = query_club_info(deps.as_ref(), "MUMBAI_INDIANS".to_string()).unwrap();
+        assert!(!club_info.profile.active);
+
+        // a club stored under a name that does not normalize to itself could no longer be found
+        CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "club002".to_string(), &ClubOwnershipDetails {
+            club_name: "club002".to_string(),
+            start_timestamp: now,
+            locking_period: 0,
+            owner_address: "owner003".to_string(),
+            price_paid: Uint128::from(1000000u128),
+            reward_amount: Uint128::zero(),
+            owner_released: false,
+            total_staked_amount: Uint128::zero(),
+        }).unwrap();
+        let err = migrate_to_0_12_0(deps.as_mut()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Club club002 is not stored under its normalized name CLUB002"),
+            })
+        );
     }
 
     #[test]
-    fn test_distribute_rewards() {
-        let mut deps = mock_dependencies();
+    fn test_club_name_case_round_trip() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3912,9 +7028,8 @@ mod tests {
             minting_contract_address: "minting_admin11111".to_string(),
             astro_proxy_address: "astro_proxy_address1111".to_string(),
             club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
-            //club_reward_next_timestamp: now.minus_seconds(8 * 60 * 60),
-            club_reward_next_timestamp: now.minus_seconds(1),
-            reward_periodicity: 5 * 60 * 60u64,
+            club_reward_next_timestamp: now.plus_seconds(24 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
             club_price: Uint128::from(1000000u128),
             bonding_duration: 5 * 60u64,
             owner_release_locking_duration: 24 * 60 * 60u64,
@@ -3924,10 +7039,10 @@ mod tests {
             control_fees: Uint128::from(50u128),
             max_bonding_limit_per_user: 10u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
         let adminInfo = mock_info("admin11111", &[]);
-        let mintingContractInfo = mock_info("minting_admin11111", &[]);
-
         instantiate(
             deps.as_mut(),
             mock_env(),
@@ -3935,20 +7050,145 @@ mod tests {
             instantiate_msg,
         )
             .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        let owner1Info = mock_info("owner001", &[coin(1000, "stake")]);
+        // the club is bought, staked on and managed by its lower case name, stored as CLUB001
+        let owner1Info = mock_info("owner001", &[]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
             owner1Info.clone(),
             "owner001".to_string(),
             Some(String::default()),
-            "CLUB001".to_string(),
+            "club001".to_string(),
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
+        )
+            .unwrap();
+        let stakerInfo = mock_info("staker001", &[]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "club001".to_string(),
+            Uint128::from(100u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap();
+        let stakes = query_club_staking_details(&deps.storage, "club001".to_string(), vec!["staker001".to_string()]).unwrap();
+        assert_eq!(stakes[0].club_name, "CLUB001".to_string());
+        assert_eq!(query_club_info(deps.as_ref(), "club001".to_string()).unwrap().club_name, "CLUB001".to_string());
+        assert_eq!(query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap().owner_address, "owner001".to_string());
+        assert_eq!(query_staker_rewards(deps.as_ref(), "staker001".to_string(), "club001".to_string()).unwrap(), Uint128::zero());
+
+        set_auto_stake(deps.as_mut(), stakerInfo.clone(), "club001".to_string(), false).unwrap();
+        let err = claim_staker_rewards(deps.as_mut(), stakerInfo.clone(), "staker001".to_string(), "club001".to_string()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("No rewards for this user"),
+            })
         );
-        let owner2Info = mock_info("owner002", &[coin(1000, "stake")]);
-        buy_a_club(
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "club001".to_string(),
+            Uint128::from(40u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
+        let bonds = query_club_bonding_details_for_user(&deps.storage, "club001".to_string(), "staker001".to_string()).unwrap();
+        assert_eq!(bonds.len(), 1);
+        let mut matured_env = mock_env();
+        matured_env.block.time = now.plus_seconds(10 * 60);
+        let rsp = claim_matured(deps.as_mut(), matured_env, stakerInfo.clone(), Some("club001".to_string()), None, None).unwrap();
+        assert_eq!(rsp.messages.len(), 1);
+
+        set_club_ranking(deps.as_mut(), adminInfo.clone(), vec!["club001".to_string()]).unwrap();
+        assert_eq!(CLUB_RANKING.load(&deps.storage).unwrap(), vec!["CLUB001".to_string()]);
+
+        let expiry = now.plus_seconds(60 * 60);
+        list_club(deps.as_mut(), mock_env(), owner1Info.clone(), "owner001".to_string(), "club001".to_string(), Uint128::from(2000000u128), expiry).unwrap();
+        cancel_club_listing(deps.as_mut(), owner1Info.clone(), "owner001".to_string(), "club001".to_string()).unwrap();
+        release_club(deps.as_mut(), mock_env(), owner1Info.clone(), "owner001".to_string(), "club001".to_string()).unwrap();
+        assert!(query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap().owner_released);
+    }
+
+    #[test]
+    fn test_club_ownership_nft() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.plus_seconds(24 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        // a club bought before NFTs are enabled
+        let owner1Info = mock_info("owner001", &[]);
+        let rsp = buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1Info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap();
+        assert_eq!(rsp.messages.len(), 1);
+
+        let err = update_club_nft_address(deps.as_mut(), owner1Info.clone(), Some("club_nft1111".to_string()), None, None).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let rsp = update_club_nft_address(deps.as_mut(), adminInfo.clone(), Some("club_nft1111".to_string()), None, None).unwrap();
+        let mint_msg = |club_name: &str, owner: &str| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "club_nft1111".to_string(),
+                msg: to_binary(&Cw721ExecuteMsg::Mint(MintMsg::<Extension> {
+                    token_id: club_name.to_string(),
+                    owner: owner.to_string(),
+                    name: club_name.to_string(),
+                    description: Some("cricket - IPL".to_string()),
+                    image: None,
+                    extension: None,
+                }))
+                    .unwrap(),
+                funds: vec![],
+            })
+        };
+        assert_eq!(rsp.messages, vec![mint_msg("CLUB001", "owner001")]);
+
+        // new clubs are minted to the buyer
+        let owner2Info = mock_info("owner002", &[]);
+        let rsp = buy_a_club(
             deps.as_mut(),
             mock_env(),
             owner2Info.clone(),
@@ -3957,8 +7197,83 @@ mod tests {
             "CLUB002".to_string(),
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
+        )
+            .unwrap();
+        assert_eq!(rsp.messages.len(), 2);
+        assert_eq!(rsp.messages[1], mint_msg("CLUB002", "owner002"));
+
+        // the NFT of CLUB001 was traded to owner003, who now gets the owner rewards
+        deps.querier.update_wasm(mock_club_nft_holders(&[("CLUB001", "owner003"), ("CLUB002", "owner002")]));
+        let mut club = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        club.reward_amount = Uint128::from(500u128);
+        CLUB_OWNERSHIP_DETAILS.save(&mut deps.storage, "CLUB001".to_string(), &club).unwrap();
+
+        // clubs already holding an NFT are not minted again
+        let rsp = update_club_nft_address(deps.as_mut(), adminInfo.clone(), Some("club_nft1111".to_string()), None, None).unwrap();
+        assert!(rsp.messages.is_empty());
+
+        // owner rewards go to the NFT holder, not to the stake of the recorded owner
+        let club_info = query_club_info(deps.as_ref(), "CLUB001".to_string()).unwrap();
+        assert_eq!(club_info.owner_address, Some("owner003".to_string()));
+        credit_owner_reward(&mut deps.storage, "CLUB001".to_string(), club_info.owner_address.unwrap(), Uint128::from(100u128)).unwrap();
+        let owner1_stakes = may_load_club_stakes(&deps.storage, "CLUB001".to_string(), "owner001".to_string()).unwrap().unwrap();
+        assert_eq!(owner1_stakes[0].staked_amount, Uint128::zero());
+        let club = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(club.reward_amount, Uint128::from(600u128));
+
+        // only the NFT holder can release or list the club
+        let err = release_club(deps.as_mut(), mock_env(), owner1Info.clone(), "owner001".to_string(), "CLUB001".to_string()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Releaser is not the owner for the club"),
+            })
         );
-        let owner3Info = mock_info("owner003", &[coin(1000, "stake")]);
+        let expiry = now.plus_seconds(60 * 60);
+        let err = list_club(deps.as_mut(), mock_env(), owner1Info.clone(), "owner001".to_string(), "CLUB001".to_string(), Uint128::from(2000000u128), expiry).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Lister is not the owner for the club"),
+            })
+        );
+        list_club(deps.as_mut(), mock_env(), mock_info("owner003", &[]), "owner003".to_string(), "CLUB001".to_string(), Uint128::from(2000000u128), expiry).unwrap();
+
+        let err = claim_owner_rewards(deps.as_mut(), mock_env(), owner1Info.clone(), "owner001".to_string(), "CLUB001".to_string()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Not a valid owner for the club"),
+            })
+        );
+        let owner3Info = mock_info("owner003", &[]);
+        let rsp = claim_owner_rewards(deps.as_mut(), mock_env(), owner3Info.clone(), "owner003".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(rsp.messages.len(), 1);
+        let club = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(club.owner_address, "owner003".to_string());
+        assert_eq!(club.reward_amount, Uint128::zero());
+
+        // the NFT of CLUB001 is traded again, to owner005
+        deps.querier.update_wasm(mock_club_nft_holders(&[("CLUB001", "owner005"), ("CLUB002", "owner002")]));
+        let owner5Info = mock_info("owner005", &[]);
+        let err = buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner5Info.clone(),
+            "owner005".to_string(),
+            Some(String::default()),
+            "CLUB003".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("buyer already owns this club"),
+            })
+        );
+        // owner003 sold its NFT and can buy another club, minted to it and owned by record until the mint lands
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3968,234 +7283,203 @@ mod tests {
             "CLUB003".to_string(),
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
-        );
+        )
+            .unwrap();
+        let club_info = query_club_info(deps.as_ref(), "CLUB003".to_string()).unwrap();
+        assert_eq!(club_info.owner_address, Some("owner003".to_string()));
 
-        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
-        stake_on_a_club(
+        // the NFT holder sells the club, not the recorded owner
+        release_club(deps.as_mut(), mock_env(), owner5Info.clone(), "owner005".to_string(), "CLUB001".to_string()).unwrap();
+        let owner4Info = mock_info("owner004", &[]);
+        let err = buy_a_club(
             deps.as_mut(),
             mock_env(),
-            staker1Info.clone(),
-            "staker001".to_string(),
+            owner4Info.clone(),
+            "owner004".to_string(),
+            Some("owner003".to_string()),
             "CLUB001".to_string(),
-            Uint128::from(330000u128),
+            Uint128::from(1000000u128),
             SET_AUTO_STAKE,
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("Seller is not the owner for the club"),
+            })
         );
-
-        let staker2Info = mock_info("staker002", &[coin(10, "stake")]);
-        stake_on_a_club(
+        buy_a_club(
             deps.as_mut(),
             mock_env(),
-            staker2Info.clone(),
-            "staker002".to_string(),
+            owner4Info.clone(),
+            "owner004".to_string(),
+            Some("owner005".to_string()),
             "CLUB001".to_string(),
-            Uint128::from(110000u128),
+            Uint128::from(1000000u128),
             SET_AUTO_STAKE,
-        );
+        )
+            .unwrap();
+        let club = query_club_ownership_details(&deps.storage, "CLUB001".to_string()).unwrap();
+        assert_eq!(club.owner_address, "owner004".to_string());
+    }
 
-        let staker3Info = mock_info("staker003", &[coin(10, "stake")]);
-        stake_on_a_club(
-            deps.as_mut(),
-            mock_env(),
-            staker3Info.clone(),
-            "staker003".to_string(),
-            "CLUB002".to_string(),
-            Uint128::from(420000u128),
-            SET_AUTO_STAKE,
-        );
+    #[test]
+    fn test_events_for_club_staking() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
 
-        let staker4Info = mock_info("staker004", &[coin(10, "stake")]);
-        stake_on_a_club(
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
-            staker4Info.clone(),
-            "staker004".to_string(),
-            "CLUB002".to_string(),
-            Uint128::from(100000u128),
-            SET_AUTO_STAKE,
-        );
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        let staker5Info = mock_info("staker005", &[coin(10, "stake")]);
-        stake_on_a_club(
+        let owner1Info = mock_info("owner001", &[]);
+        let rsp = buy_a_club(
             deps.as_mut(),
             mock_env(),
-            staker5Info.clone(),
-            "staker005".to_string(),
-            "CLUB003".to_string(),
-            Uint128::from(820000u128),
+            owner1Info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
             SET_AUTO_STAKE,
+        )
+            .unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("club_bought")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("buyer", "owner001")
+                .add_attribute("price", "1000000")]
         );
 
-        let staker6Info = mock_info("staker006", &[coin(10, "stake")]);
-        stake_on_a_club(
+        let stakerInfo = mock_info("staker001", &[]);
+        let rsp = stake_on_a_club(
             deps.as_mut(),
             mock_env(),
-            staker6Info.clone(),
-            "staker006".to_string(),
-            "CLUB003".to_string(),
-            Uint128::from(50000u128),
-            SET_AUTO_STAKE,
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("stake_added")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("staker", "staker001")
+                .add_attribute("amount", "100")]
         );
 
-        let mut user_address_list = Vec::new();
-        user_address_list.push("staker001".to_string());
-        user_address_list.push("staker002".to_string());
-        user_address_list.push("staker003".to_string());
-        user_address_list.push("staker004".to_string());
-        user_address_list.push("staker005".to_string());
-        user_address_list.push("staker006".to_string());
-        user_address_list.push("owner001".to_string());
-        user_address_list.push("owner002".to_string());
-        user_address_list.push("owner003".to_string());
-        let queryRes0 = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match queryRes0 {
-            Ok(all_stakes) => {
-                assert_eq!(all_stakes.len(), 9);
-                println!("all stakes : {:?}", all_stakes);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+        let rsp = withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(40u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("bond_created")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("bonder", "staker001")
+                .add_attribute("amount", "40")
+                .add_attribute("matures_at", now.plus_seconds(5 * 60).seconds().to_string())]
+        );
 
-        increase_reward_amount(
+        let rsp = increase_reward_amount(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
             "reward_from abc".to_string(),
             Uint128::from(1000000u128),
+        )
+            .unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("reward_added")
+                .add_attribute("reward_from", "reward_from abc")
+                .add_attribute("amount", "1000000")]
         );
-        println!("stakes before distribution");
-        let queryRes00 = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match queryRes00 {
-            Ok(all_stakes) => {
-                println!("all stakes : {:?}", all_stakes);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
-
-        let mut queryReward = query_reward_amount(&mut deps.storage);
-        println!("reward amount before distribution: {:?}", queryReward);
-        let club_name1 = "CLUB001".to_string();
-        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), user_address_list.clone(), club_name1, true, false);
-        println!("stakes after first distribution");
-        let queryRes01 = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match queryRes01 {
-            Ok(all_stakes) => {
-                println!("all stakes : {:?}", all_stakes);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
-
-        queryReward = query_reward_amount(&mut deps.storage);
-        println!("reward amount after first distribution: {:?}", queryReward);
-        let club_name2 = "CLUB002".to_string();
-        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), user_address_list.clone(), club_name2, false, false);
-        println!("stakes after second distribution");
-        let queryRes01 = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match queryRes01 {
-            Ok(all_stakes) => {
-                println!("all stakes : {:?}", all_stakes);
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
-
-        queryReward = query_reward_amount(&mut deps.storage);
-        println!("reward amount after second distribution: {:?}", queryReward);
-        let club_name3 = "CLUB003".to_string();
-        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), user_address_list.clone(), club_name3, false, true);
 
-        queryReward = query_reward_amount(&mut deps.storage);
-        println!("reward amount after third distribution: {:?}", queryReward);
-        println!("stakes after third distribution");
-        let queryRes = query_all_stakes(&mut deps.storage, user_address_list.clone());
-        match queryRes {
-            Ok(all_stakes) => {
-                assert_eq!(all_stakes.len(), 9);
-                println!("all stakes : {:?}", all_stakes);
-                for stake in all_stakes {
-                    let staker_address = stake.staker_address;
-                    let staked_amount = stake.staked_amount;
-                    println!("staker : {:?} staked_amount : {:?}", staker_address.clone(), staked_amount);
-                    if staker_address == "staker001" {
-                        assert_eq!(staked_amount, Uint128::from(470655u128));
-                    }
-                    if staker_address == "staker002" {
-                        assert_eq!(staked_amount, Uint128::from(156885u128));
-                    }
-                    if staker_address == "staker003" {
-                        assert_eq!(staked_amount, Uint128::from(599016u128));
-                    }
-                    if staker_address == "staker004" {
-                        assert_eq!(staked_amount, Uint128::from(142622u128));
-                    }
-                    if staker_address == "staker005" {
-                        assert_eq!(staked_amount, Uint128::from(1348588u128));
-                    }
-                    if staker_address == "staker006" {
-                        assert_eq!(staked_amount, Uint128::from(82230u128));
-                    }
-                    if staker_address == "owner001" {
-                        assert_eq!(staked_amount, Uint128::from(10000u128));
-                    }
-                    if staker_address == "owner002" {
-                        assert_eq!(staked_amount, Uint128::from(10000u128));
-                    }
-                    if staker_address == "owner003" {
-                        assert_eq!(staked_amount, Uint128::from(10000u128));
-                    }
-                }
-            }
-            Err(e) => {
-                println!("error parsing header: {:?}", e);
-                assert_eq!(1, 2);
-            }
-        }
+        let rsp = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("reward_distributed")
+                .add_attribute("reward_period", "1")
+                .add_attribute("total_reward", "1000000")
+                .add_attribute("reward_given", "999999")
+                .add_attribute("next_timestamp", now.plus_seconds(23 * 60 * 60).seconds().to_string())]
+        );
 
-        // test another attempt to calculate and distribute at the same time
+        let rsp = claim_staker_rewards(deps.as_mut(), stakerInfo.clone(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(rsp.events.len(), 1);
+        assert_eq!(rsp.events[0].ty, "reward_claimed");
+        assert_eq!(rsp.events[0].attributes[0], attr("claimant_type", "staker"));
+        assert_eq!(rsp.events[0].attributes[1], attr("claimant", "staker001"));
+        assert_eq!(rsp.events[0].attributes[3], attr("club_name", "CLUB001"));
 
-        increase_reward_amount(
-            deps.as_mut(),
-            mock_env(),
-            mintingContractInfo.clone(),
-            "reward_from abc".to_string(),
-            Uint128::from(1000000u128),
+        let mut matured_env = mock_env();
+        matured_env.block.time = now.plus_seconds(10 * 60);
+        let rsp = claim_matured(deps.as_mut(), matured_env, stakerInfo.clone(), None, None, None).unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("bond_released")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("bonder", "staker001")
+                .add_attribute("amount", "40")
+                .add_attribute("penalty", "0")]
         );
 
-        /*
-                let club_name2 = "CLUB001".to_string();
-                let err = execute(
-                    deps.as_mut(),
-                    mock_env(),
-                    adminInfo.clone(),
-                    ExecuteMsg::CalculateAndDistributeRewards {},
-                )
-                .unwrap_err();
+        let rsp = release_club(deps.as_mut(), mock_env(), owner1Info.clone(), "owner001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(
+            rsp.events,
+            vec![Event::new("club_released")
+                .add_attribute("club_name", "CLUB001")
+                .add_attribute("owner", "owner001")]
+        );
+    }
 
-                assert_eq!(
-                    err,
-                    (ContractError::Std(StdError::GenericErr {
-                        msg: String::from("Time for Reward not yet arrived")
-                    }))
-                );
+    #[test]
+    fn test_claim_matured_bonds() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
 
-        // test by preponing club_reward_next_timestamp
         let instantiate_msg = InstantiateMsg {
             admin_address: "admin11111".to_string(),
             minting_contract_address: "minting_admin11111".to_string(),
             astro_proxy_address: "astro_proxy_address1111".to_string(),
             club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
             club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
-            reward_periodicity: 5 * 60 * 60u64,
+            reward_periodicity: 24 * 60 * 60u64,
             club_price: Uint128::from(1000000u128),
             bonding_duration: 5 * 60u64,
             owner_release_locking_duration: 24 * 60 * 60u64,
@@ -4203,71 +7487,1615 @@ mod tests {
             platform_fees: Uint128::from(100u128),
             transaction_fees: Uint128::from(30u128),
             control_fees: Uint128::from(50u128),
-            max_bonding_limit_per_user: 10u64,
+            max_bonding_limit_per_user: 2u64,
             usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
         };
+        let adminInfo = mock_info("admin11111", &[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
             instantiate_msg,
         )
-        .unwrap();
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
 
-        stake_on_a_club(
+        let owner1Info = mock_info("owner001", &[]);
+        buy_a_club(
             deps.as_mut(),
             mock_env(),
-            staker4Info.clone(),
-            "staker004".to_string(),
-            "CLUB002".to_string(),
-            Uint128::from(100000u128),
+            owner1Info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
             SET_AUTO_STAKE,
-        );
+        )
+            .unwrap();
+
+        let stakerInfo = mock_info("staker001", &[]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
-            staker4Info.clone(),
-            "staker004".to_string(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
             "CLUB001".to_string(),
-            Uint128::from(500000u128),
+            Uint128::from(100u128),
             SET_AUTO_STAKE,
-        );
-        stake_on_a_club(
+        )
+            .unwrap();
+        withdraw_stake_from_a_club(
             deps.as_mut(),
             mock_env(),
-            staker4Info.clone(),
-            "staker004".to_string(),
-            "CLUB003".to_string(),
-            Uint128::from(126718u128),
-            SET_AUTO_STAKE,
-        );
-        */
-
-        increase_reward_amount(
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(10u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
+        let mut later_env = mock_env();
+        later_env.block.time = now.plus_seconds(200);
+        withdraw_stake_from_a_club(
             deps.as_mut(),
-            mock_env(),
-            mintingContractInfo.clone(),
-            "reward_from def".to_string(),
+            later_env.clone(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(20u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap();
+
+        // no more bonds than max_bonding_limit_per_user
+        let err = withdraw_stake_from_a_club(
+            deps.as_mut(),
+            later_env.clone(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(30u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        )
+            .unwrap_err();
+        assert_eq!(err, ContractError::Claim(ClaimError::TooManyClaims { max: 2 }));
+
+        // only the first bond has matured
+        let mut matured_env = mock_env();
+        matured_env.block.time = now.plus_seconds(350);
+        let rsp = claim_matured(deps.as_mut(), matured_env.clone(), stakerInfo.clone(), None, None, None).unwrap();
+        assert_eq!(
+            rsp.messages,
+            vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "minting_admin11111".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "staker001".to_string(),
+                    amount: Uint128::from(10u128),
+                })
+                    .unwrap(),
+                funds: vec![],
+            }))]
+        );
+        let bonds = query_club_bonding_details_for_user(&deps.storage, "CLUB001".to_string(), "staker001".to_string()).unwrap();
+        assert_eq!(bonds.len(), 1);
+        assert_eq!(bonds[0].bonded_amount, Uint128::from(20u128));
+
+        let err = claim_matured(deps.as_mut(), matured_env.clone(), stakerInfo.clone(), Some("CLUB001".to_string()), None, None).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("No matured bonds to claim"),
+            })
+        );
+
+        let mut all_matured_env = mock_env();
+        all_matured_env.block.time = now.plus_seconds(600);
+        // the page of clubs after CLUB001 has no bonds of the staker
+        let err = claim_matured(
+            deps.as_mut(),
+            all_matured_env.clone(),
+            stakerInfo.clone(),
+            None,
+            Some("CLUB001".to_string()),
+            Some(3),
+        )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::GenericErr {
+                msg: String::from("No matured bonds to claim"),
+            })
+        );
+        let rsp = claim_matured(deps.as_mut(), all_matured_env, stakerInfo.clone(), Some("CLUB001".to_string()), None, None).unwrap();
+        assert_eq!(rsp.attributes, vec![attr("action", "claim_matured"), attr("claimed", "20")]);
+        let bonds = query_club_bonding_details_for_user(&deps.storage, "CLUB001".to_string(), "staker001".to_string()).unwrap();
+        assert_eq!(bonds.len(), 0);
+    }
+
+    #[test]
+    fn test_non_immediate_complete_withdrawals_from_club() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let admin_info = mock_info("admin11111", &[]);
+        let minting_contract_info = mock_info("minting_admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            admin_info.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), admin_info.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
             Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
         );
 
-        let queryReward = query_reward_amount(&mut deps.storage);
-        println!("reward amount is {:?}", queryReward);
-        let club_name1 = "CLUB001".to_string();
-        let res1 = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), user_address_list.clone(), 
-            club_name1, true, false).unwrap_err();
-        assert_eq!(res1, (ContractError::Std(StdError::GenericErr {msg: String::from("Time for Reward not yet arrived")})));
-        println!("");
-        println!("");
-        let club_name2 = "CLUB002".to_string();
-        let res2 = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), user_address_list.clone(), 
-            club_name2, false, false).unwrap_err();
-        assert_eq!(res2, (ContractError::Std(StdError::GenericErr {msg: String::from("Time for Reward not yet arrived")})));
-        println!("");
-        println!("");
-        let club_name3 = "CLUB003".to_string();
-        let res3 = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), user_address_list.clone(), 
-            club_name3, false, true).unwrap_err();
-        assert_eq!(res3, (ContractError::Std(StdError::GenericErr {msg: String::from("Time for Reward not yet arrived")})));
+        let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(99u128),
+            SET_AUTO_STAKE,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(11u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(12u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(13u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(63u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+
+        let mut user_address_list = Vec::new();
+        user_address_list.push("staker001".to_string());
+        user_address_list.push("owner001".to_string());
+        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
+        match query_stakes {
+            Ok(all_stakes) => {
+                assert_eq!(all_stakes.len(), 2);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone(), None, None);
+        match queryBonds {
+            Ok(all_bonds) => {
+                assert_eq!(all_bonds.len(), 4);
+                for bond in all_bonds {
+                    if bond.bonded_amount != Uint128::from(11u128)
+                        && bond.bonded_amount != Uint128::from(12u128)
+                        && bond.bonded_amount != Uint128::from(13u128)
+                        && bond.bonded_amount != Uint128::from(63u128)
+                    {
+                        println!("bond is {:?} ", bond);
+                        assert_eq!(1, 2);
+                    }
+                }
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+        let stakerInfo = mock_info("staker002", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker002".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(99u128),
+            SET_AUTO_STAKE,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker002".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(11u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+
+        let queryBonds = query_club_bonding_details_for_user(
+            &mut deps.storage,
+            "CLUB001".to_string(),
+            "staker002".to_string(),
+        );
+        match queryBonds {
+            Ok(all_bonds) => {
+                assert_eq!(all_bonds.len(), 1);
+                for bond in all_bonds {
+                    if bond.bonded_amount != Uint128::from(11u128) {
+                        println!("bond is {:?} ", bond);
+                        assert_eq!(1, 2);
+                    }
+                }
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_immediate_complete_withdrawals_from_club_with_scheduled_refunds() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "feecollector11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let stakerInfo = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(99u128),
+            SET_AUTO_STAKE,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(11u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(12u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(13u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(63u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+
+        let mut user_address_list = Vec::new();
+        user_address_list.push("staker001".to_string());
+        user_address_list.push("owner001".to_string());
+        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
+        match query_stakes {
+            Ok(all_stakes) => {
+                assert_eq!(all_stakes.len(), 2);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        let now = mock_env().block.time; // today
+
+        let query_bonds = query_all_bonds(&mut deps.storage, user_address_list.clone(), None, None);
+        let club_name = "CLUB001".to_string();
+        match query_bonds {
+            Ok(all_bonds) => {
+                let existing_bonds = all_bonds.clone();
+                let mut updated_bonds = Vec::new();
+                assert_eq!(existing_bonds.len(), 4);
+                for user_addr in user_address_list.clone() {
+                    for bond in existing_bonds.clone() {
+                        let mut updated_bond = bond.clone();
+                        if updated_bond.bonded_amount != Uint128::from(11u128)
+                            && updated_bond.bonded_amount != Uint128::from(12u128)
+                            && updated_bond.bonded_amount != Uint128::from(13u128)
+                            && updated_bond.bonded_amount != Uint128::from(63u128)
+                        {
+                            println!("updated_bond is {:?} ", updated_bond);
+                            assert_eq!(1, 2);
+                        }
+                        if updated_bond.bonded_amount == Uint128::from(63u128) {
+                            updated_bond.bonding_start_timestamp = now.minus_seconds(8 * 24 * 60 * 60);
+                        }
+                        updated_bonds.push(updated_bond);
+                    }
+                    CLUB_BONDING_DETAILS.save_claims(&mut deps.storage, &club_name, &Addr::unchecked(user_addr.clone()), &updated_bonds);
+                }
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        /*
+                Commenting out as this is no longer used, 6 Apr 2022
+
+                periodically_refund_stakeouts(deps.as_mut(), mock_env(), adminInfo);
+
+                let queryBondsAfterPeriodicRefund = query_all_bonds(&mut deps.storage, user_address_list.clone(), None, None);
+                match queryBondsAfterPeriodicRefund {
+                    Ok(all_bonds) => {
+                        assert_eq!(all_bonds.len(), 3);
+                        for bond in all_bonds {
+                            if bond.bonded_amount != Uint128::from(11u128)
+                                && bond.bonded_amount != Uint128::from(12u128)
+                                && bond.bonded_amount != Uint128::from(13u128)
+                            {
+                                println!("bond is {:?} ", bond);
+                                assert_eq!(1, 2);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        println!("error parsing header: {:?}", e);
+                        assert_eq!(1, 2);
+                    }
+                }
+        */
+    }
+
+    #[test]
+    fn test_non_immediate_partial_withdrawals_from_club() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        let result = buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+        println!("buy_a_club result = {:?}", result);
+        let stakerInfo = mock_info("staker001", &[coin(10, "uusd")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(99u128),
+            SET_AUTO_STAKE,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(11u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(12u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        let result = withdraw_stake_from_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(13u128),
+            NO_IMMEDIATE_WITHDRAWAL,
+        );
+        println!("result = {:?}", result);
+        let mut user_address_list = Vec::new();
+        user_address_list.push("staker001".to_string());
+        user_address_list.push("owner001".to_string());
+        let query_stakes = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
+        match query_stakes {
+            Ok(all_stakes) => {
+                assert_eq!(all_stakes.len(), 2);
+                for stake in all_stakes {
+                    if stake.staker_address == "staker001".to_string() {
+                        assert_eq!(stake.staked_amount, Uint128::from(63u128));
+                    } else {
+                        assert_eq!(stake.staked_amount, Uint128::from(0u128));
+                    }
+                }
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        let queryBonds = query_all_bonds(&mut deps.storage, user_address_list.clone(), None, None);
+        match queryBonds {
+            Ok(all_bonds) => {
+                assert_eq!(all_bonds.len(), 3);
+                for bond in all_bonds {
+                    if bond.bonded_amount != Uint128::from(11u128)
+                        && bond.bonded_amount != Uint128::from(12u128)
+                        && bond.bonded_amount != Uint128::from(13u128)
+                    {
+                        println!("bond is {:?} ", bond);
+                        assert_eq!(1, 2);
+                    }
+                }
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distribute_rewards() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            //club_reward_next_timestamp: now.minus_seconds(8 * 60 * 60),
+            club_reward_next_timestamp: now.minus_seconds(1),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let mintingContractInfo = mock_info("minting_admin11111", &[]);
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1Info = mock_info("owner001", &[coin(1000, "stake")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1Info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+        let owner2Info = mock_info("owner002", &[coin(1000, "stake")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner2Info.clone(),
+            "owner002".to_string(),
+            Some(String::default()),
+            "CLUB002".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+        let owner3Info = mock_info("owner003", &[coin(1000, "stake")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner3Info.clone(),
+            "owner003".to_string(),
+            Some(String::default()),
+            "CLUB003".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(330000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let staker2Info = mock_info("staker002", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker2Info.clone(),
+            "staker002".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(110000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let staker3Info = mock_info("staker003", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker3Info.clone(),
+            "staker003".to_string(),
+            "CLUB002".to_string(),
+            Uint128::from(420000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let staker4Info = mock_info("staker004", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker4Info.clone(),
+            "staker004".to_string(),
+            "CLUB002".to_string(),
+            Uint128::from(100000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let staker5Info = mock_info("staker005", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker5Info.clone(),
+            "staker005".to_string(),
+            "CLUB003".to_string(),
+            Uint128::from(820000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let staker6Info = mock_info("staker006", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker6Info.clone(),
+            "staker006".to_string(),
+            "CLUB003".to_string(),
+            Uint128::from(50000u128),
+            SET_AUTO_STAKE,
+        );
+
+        let mut user_address_list = Vec::new();
+        user_address_list.push("staker001".to_string());
+        user_address_list.push("staker002".to_string());
+        user_address_list.push("staker003".to_string());
+        user_address_list.push("staker004".to_string());
+        user_address_list.push("staker005".to_string());
+        user_address_list.push("staker006".to_string());
+        user_address_list.push("owner001".to_string());
+        user_address_list.push("owner002".to_string());
+        user_address_list.push("owner003".to_string());
+        let queryRes0 = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
+        match queryRes0 {
+            Ok(all_stakes) => {
+                assert_eq!(all_stakes.len(), 9);
+                println!("all stakes : {:?}", all_stakes);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        );
+        println!("stakes before distribution");
+        let queryRes00 = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
+        match queryRes00 {
+            Ok(all_stakes) => {
+                println!("all stakes : {:?}", all_stakes);
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        let mut queryReward = query_reward_amount(&mut deps.storage);
+        println!("reward amount before distribution: {:?}", queryReward);
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None);
+
+        queryReward = query_reward_amount(&mut deps.storage);
+        println!("reward amount after third distribution: {:?}", queryReward);
+        println!("stakes after third distribution");
+        let queryRes = query_all_stakes(&mut deps.storage, user_address_list.clone(), None, None);
+        match queryRes {
+            Ok(all_stakes) => {
+                assert_eq!(all_stakes.len(), 9);
+                println!("all stakes : {:?}", all_stakes);
+                for stake in all_stakes {
+                    let staker_address = stake.staker_address;
+                    let staked_amount = stake.staked_amount;
+                    println!("staker : {:?} staked_amount : {:?}", staker_address.clone(), staked_amount);
+                    if staker_address == "staker001" {
+                        assert_eq!(staked_amount, Uint128::from(470655u128));
+                    }
+                    if staker_address == "staker002" {
+                        assert_eq!(staked_amount, Uint128::from(156885u128));
+                    }
+                    if staker_address == "staker003" {
+                        assert_eq!(staked_amount, Uint128::from(599016u128));
+                    }
+                    if staker_address == "staker004" {
+                        assert_eq!(staked_amount, Uint128::from(142622u128));
+                    }
+                    if staker_address == "staker005" {
+                        assert_eq!(staked_amount, Uint128::from(1348588u128));
+                    }
+                    if staker_address == "staker006" {
+                        assert_eq!(staked_amount, Uint128::from(82231u128));
+                    }
+                    if staker_address == "owner001" {
+                        assert_eq!(staked_amount, Uint128::from(10000u128));
+                    }
+                    if staker_address == "owner002" {
+                        assert_eq!(staked_amount, Uint128::from(10000u128));
+                    }
+                    if staker_address == "owner003" {
+                        assert_eq!(staked_amount, Uint128::from(10000u128));
+                    }
+                }
+            }
+            Err(e) => {
+                println!("error parsing header: {:?}", e);
+                assert_eq!(1, 2);
+            }
+        }
+
+        // test another attempt to calculate and distribute at the same time
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            mintingContractInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        );
+
+        /*
+                let club_name2 = "CLUB001".to_string();
+                let err = execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    adminInfo.clone(),
+                    ExecuteMsg::CalculateAndDistributeRewards { limit: None },
+                )
+                .unwrap_err();
+
+                assert_eq!(
+                    err,
+                    (ContractError::Std(StdError::GenericErr {
+                        msg: String::from("Time for Reward not yet arrived")
+                    }))
+                );
+
+        // test by preponing club_reward_next_timestamp
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+        .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker4Info.clone(),
+            "staker004".to_string(),
+            "CLUB002".to_string(),
+            Uint128::from(100000u128),
+            SET_AUTO_STAKE,
+        );
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker4Info.clone(),
+            "staker004".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(500000u128),
+            SET_AUTO_STAKE,
+        );
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker4Info.clone(),
+            "staker004".to_string(),
+            "CLUB003".to_string(),
+            Uint128::from(126718u128),
+            SET_AUTO_STAKE,
+        );
+        */
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            mintingContractInfo.clone(),
+            "reward_from def".to_string(),
+            Uint128::from(1000000u128),
+        );
+
+        let queryReward = query_reward_amount(&mut deps.storage);
+        println!("reward amount is {:?}", queryReward);
+        let res = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap_err();
+        assert_eq!(res, (ContractError::Std(StdError::GenericErr {msg: String::from("Time for Reward not yet arrived")})));
+    }
+
+    #[test]
+    fn test_rewards_accrue_lazily_to_stakers() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+
+        // only the admin can distribute
+        let err = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), staker1Info.clone(), None).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("not authorised")})));
+
+        // a single call distributes across all clubs without passing any staker
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+        assert_eq!(query_reward_amount(&deps.storage).unwrap(), Uint128::zero());
+
+        // staking after the distribution does not earn the rewards distributed before it
+        let staker2Info = mock_info("staker002", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker2Info.clone(),
+            "staker002".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        let staker1_reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker1_reward, Uint128::from(970000u128));
+        let staker2_reward = query_staker_rewards(deps.as_ref(), "staker002".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker2_reward, Uint128::zero());
+        let owner_reward = query_staker_rewards(deps.as_ref(), "owner001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(owner_reward, Uint128::from(30000u128));
+
+        // rewards already earned are kept when the stake changes
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(50000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+        let stakes = query_club_staking_details(&deps.storage, "CLUB001".to_string(), vec!["staker001".to_string()]).unwrap();
+        assert_eq!(stakes.len(), 1);
+        assert_eq!(stakes[0].staked_amount, Uint128::from(150000u128));
+        assert_eq!(stakes[0].reward_amount, Uint128::from(970000u128));
+    }
+
+    #[test]
+    fn test_reward_history() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+
+        let staker2Info = mock_info("staker002", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker2Info.clone(),
+            "staker002".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+        let mut next_period_env = mock_env();
+        next_period_env.block.time = now.plus_seconds(5 * 60 * 60u64);
+        calculate_and_distribute_rewards(deps.as_mut(), next_period_env.clone(), adminInfo.clone(), None).unwrap();
+
+        let history = query_reward_period_history(&deps.storage, None, None).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].reward_period, 1);
+        assert_eq!(history[0].timestamp, now);
+        assert_eq!(history[0].total_reward, Uint128::from(1000000u128));
+        assert_eq!(history[0].winner_list, vec!["CLUB001".to_string()]);
+        let club_rewards = query_club_reward_history(&deps.storage, 1, None, None).unwrap();
+        assert_eq!(club_rewards.len(), 1);
+        assert_eq!(club_rewards[0].total_staked_amount, Uint128::from(100000u128));
+        assert_eq!(club_rewards[0].stakers_reward, Uint128::from(970000u128));
+        assert_eq!(club_rewards[0].owner_reward, Uint128::from(30000u128));
+        assert_eq!(history[1].reward_period, 2);
+        assert_eq!(history[1].timestamp, next_period_env.block.time);
+        let club_rewards = query_club_reward_history(&deps.storage, 2, None, None).unwrap();
+        assert_eq!(club_rewards[0].total_staked_amount, Uint128::from(200000u128));
+
+        let history = query_reward_period_history(&deps.storage, Some(1), Some(10)).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reward_period, 2);
+
+        let staker1_history = query_staker_reward_history(&deps.storage, "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker1_history.len(), 2);
+        assert_eq!(staker1_history[0].staked_amount, Uint128::from(100000u128));
+        assert_eq!(staker1_history[0].staking_reward, Uint128::from(970000u128));
+        assert_eq!(staker1_history[1].staked_amount, Uint128::from(100000u128));
+        assert_eq!(staker1_history[1].staking_reward, Uint128::from(485000u128));
+
+        // staker002 joined after the first distribution
+        let staker2_history = query_staker_reward_history(&deps.storage, "staker002".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker2_history.len(), 1);
+        assert_eq!(staker2_history[0].reward_period, 2);
+        assert_eq!(staker2_history[0].staking_reward, Uint128::from(485000u128));
+
+        let owner_history = query_staker_reward_history(&deps.storage, "owner001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(owner_history.len(), 2);
+        assert_eq!(owner_history[0].staking_reward, Uint128::zero());
+        assert_eq!(owner_history[0].owner_reward, Uint128::from(30000u128));
+    }
+
+    #[test]
+    fn test_update_reward_policy() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: None,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+        assert_eq!(query_reward_split_policy(&deps.storage).unwrap(), RewardSplitPolicy::default());
+
+        let new_policy = RewardSplitPolicy {
+            all_stakers_share: Uint128::from(9000u128),
+            winning_club_stakers_share: Uint128::zero(),
+            winning_club_owners_share: Uint128::from(1000u128),
+            non_winning_club_owners_share: Uint128::zero(),
+        };
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        let err = update_reward_policy(deps.as_mut(), owner1_info.clone(), new_policy.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let mut invalid_policy = new_policy.clone();
+        invalid_policy.all_stakers_share = Uint128::from(8000u128);
+        let err = update_reward_policy(deps.as_mut(), adminInfo.clone(), invalid_policy).unwrap_err();
+        assert_eq!(err, ContractError::InvalidRewardSplitPolicy { total: Uint128::from(9000u128) });
+
+        update_reward_policy(deps.as_mut(), adminInfo.clone(), new_policy.clone()).unwrap();
+        assert_eq!(query_reward_split_policy(&deps.storage).unwrap(), new_policy);
+
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+
+        let staker1_reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker1_reward, Uint128::from(900000u128));
+        let owner_reward = query_staker_rewards(deps.as_ref(), "owner001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(owner_reward, Uint128::from(100000u128));
+    }
+
+    fn club_standing(club_name: &str, total_stake: u128, incremental_stake: i128) -> ClubStakeStanding {
+        ClubStakeStanding {
+            club_name: club_name.to_string(),
+            total_stake: Uint128::from(total_stake),
+            previous_stake: Uint128::from((total_stake as i128 - incremental_stake) as u128),
+        }
+    }
+
+    #[test]
+    fn test_leaders_pick_the_same_winners_as_all_standings() {
+        let standings = vec![
+            club_standing("CLUB001", 900, 300),
+            club_standing("CLUB002", 500, 100),
+            club_standing("CLUB003", 900, 300),
+            club_standing("CLUB004", 1000, -50),
+            club_standing("CLUB005", 1000, 0),
+            club_standing("CLUB006", 700, 300),
+        ];
+        let ranking = vec!["CLUB009".to_string(), "CLUB004".to_string(), "CLUB002".to_string()];
+        let strategies = vec![
+            WinningClubStrategy::LargestIncrementalStake,
+            WinningClubStrategy::LargestTotalStake,
+            WinningClubStrategy::TopClubsByIncrementalStake { count: 2 },
+            WinningClubStrategy::TopClubsByIncrementalStake { count: 4 },
+            WinningClubStrategy::AdminRanking { count: 2 },
+        ];
+        for strategy in strategies.iter() {
+            let mut leaders = Vec::new();
+            for standing in standings.iter() {
+                add_to_leaders(strategy, &mut leaders, standing.clone(), &ranking);
+            }
+            assert_eq!(
+                select_winning_clubs(strategy, &leaders, &ranking).unwrap(),
+                select_winning_clubs(strategy, &standings, &ranking).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_largest_incremental_stake_strategy() {
+        let standings = vec![
+            club_standing("CLUB001", 500, 100),
+            club_standing("CLUB002", 900, 300),
+            club_standing("CLUB003", 700, 300),
+            club_standing("CLUB004", 1000, -50),
+        ];
+        let winners = select_winning_clubs(&WinningClubStrategy::LargestIncrementalStake, &standings, &[]).unwrap();
+        assert_eq!(winners, vec!["CLUB002".to_string()]);
+
+        // same increment and same total stake - all are winners
+        let standings = vec![
+            club_standing("CLUB001", 900, 300),
+            club_standing("CLUB002", 900, 300),
+            club_standing("CLUB003", 100, 100),
+        ];
+        let winners = select_winning_clubs(&WinningClubStrategy::LargestIncrementalStake, &standings, &[]).unwrap();
+        assert_eq!(winners, vec!["CLUB001".to_string(), "CLUB002".to_string()]);
+
+        // every club lost stake - the one that lost the least wins
+        let standings = vec![
+            club_standing("CLUB001", 900, -300),
+            club_standing("CLUB002", 100, -10),
+        ];
+        let winners = select_winning_clubs(&WinningClubStrategy::LargestIncrementalStake, &standings, &[]).unwrap();
+        assert_eq!(winners, vec!["CLUB002".to_string()]);
+
+        let winners = select_winning_clubs(&WinningClubStrategy::LargestIncrementalStake, &[], &[]).unwrap();
+        assert_eq!(winners.len(), 0);
+    }
+
+    #[test]
+    fn test_largest_total_stake_strategy() {
+        let standings = vec![
+            club_standing("CLUB001", 500, 100),
+            club_standing("CLUB002", 900, 300),
+            club_standing("CLUB003", 1000, -50),
+            club_standing("CLUB004", 1000, 0),
+        ];
+        let winners = select_winning_clubs(&WinningClubStrategy::LargestTotalStake, &standings, &[]).unwrap();
+        assert_eq!(winners, vec!["CLUB003".to_string(), "CLUB004".to_string()]);
+    }
+
+    #[test]
+    fn test_top_clubs_by_incremental_stake_strategy() {
+        let standings = vec![
+            club_standing("CLUB001", 500, 100),
+            club_standing("CLUB002", 900, 300),
+            club_standing("CLUB003", 700, 300),
+            club_standing("CLUB004", 1000, -50),
+            club_standing("CLUB005", 200, 200),
+        ];
+        let strategy = WinningClubStrategy::TopClubsByIncrementalStake { count: 3 };
+        let winners = select_winning_clubs(&strategy, &standings, &[]).unwrap();
+        assert_eq!(winners, vec!["CLUB002".to_string(), "CLUB003".to_string(), "CLUB005".to_string()]);
+
+        // fewer clubs than the count
+        let strategy = WinningClubStrategy::TopClubsByIncrementalStake { count: 10 };
+        let winners = select_winning_clubs(&strategy, &standings, &[]).unwrap();
+        assert_eq!(winners.len(), 5);
+        assert_eq!(winners[4], "CLUB004".to_string());
+
+        let err = validate_winning_club_strategy(&WinningClubStrategy::TopClubsByIncrementalStake { count: 0 }).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Number of winning clubs must be more than 0")})));
+    }
+
+    #[test]
+    fn test_admin_ranking_strategy() {
+        let standings = vec![
+            club_standing("CLUB001", 500, 100),
+            club_standing("CLUB002", 900, 300),
+            club_standing("CLUB003", 700, 300),
+        ];
+        let strategy = WinningClubStrategy::AdminRanking { count: 2 };
+        let err = select_winning_clubs(&strategy, &standings, &[]).unwrap_err();
+        assert_eq!(err, StdError::generic_err("Club ranking not submitted for this reward period"));
+
+        let club_ranking = vec!["CLUB003".to_string(), "CLUB099".to_string(), "CLUB001".to_string(), "CLUB002".to_string()];
+        let winners = select_winning_clubs(&strategy, &standings, &club_ranking).unwrap();
+        assert_eq!(winners, vec!["CLUB003".to_string(), "CLUB001".to_string()]);
+    }
+
+    #[test]
+    fn test_distribute_rewards_with_admin_ranking() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: Some(WinningClubStrategy::AdminRanking { count: 1 }),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        let owner1_info = mock_info("owner001", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+        let owner2_info = mock_info("owner002", &[coin(0, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner2_info.clone(),
+            "owner002".to_string(),
+            Some(String::default()),
+            "CLUB002".to_string(),
+            Uint128::from(1000000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        // CLUB001 has the larger stake, but CLUB002 won the match
+        let staker1Info = mock_info("staker001", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker1Info.clone(),
+            "staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(300000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+        let staker2Info = mock_info("staker002", &[coin(10, "stake")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            staker2Info.clone(),
+            "staker002".to_string(),
+            "CLUB002".to_string(),
+            Uint128::from(100000u128),
+            false, // NO AUTO STAKE
+        )
+            .unwrap();
+
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+
+        // no ranking submitted yet
+        let err = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Club ranking not submitted for this reward period")})));
+
+        let err = set_club_ranking(deps.as_mut(), adminInfo.clone(), vec!["CLUB002".to_string(), "CLUB003".to_string()]).unwrap_err();
+        assert_eq!(err, (ContractError::Std(StdError::GenericErr {msg: String::from("Unknown club CLUB003 in ranking")})));
+        let err = set_club_ranking(deps.as_mut(), staker1Info.clone(), vec!["CLUB002".to_string()]).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        set_club_ranking(deps.as_mut(), adminInfo.clone(), vec!["CLUB002".to_string(), "CLUB001".to_string()]).unwrap();
+
+        calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), None).unwrap();
+
+        let wcd = query_winning_club_details(&deps.storage, Some(1u64)).unwrap();
+        assert_eq!(wcd.winner_list, vec!["CLUB002".to_string()]);
+        assert_eq!(wcd.total_number_of_clubs, 2u64);
+        assert_eq!(wcd.total_stake_across_all_clubs, Uint128::from(400000u128));
+        assert_eq!(wcd.total_stake_in_winning_club, Uint128::from(100000u128));
+        assert_eq!(query_winning_club_details(&deps.storage, None).unwrap(), wcd);
+        assert!(query_winning_club_details(&deps.storage, Some(2u64)).is_err());
+
+        // 78% shared by stake, 19% to the stakers of the winning club
+        let staker1_reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker1_reward, Uint128::from(585000u128));
+        let staker2_reward = query_staker_rewards(deps.as_ref(), "staker002".to_string(), "CLUB002".to_string()).unwrap();
+        assert_eq!(staker2_reward, Uint128::from(385000u128));
+
+        // the ranking is used up by the distribution
+        assert_eq!(CLUB_RANKING.may_load(&deps.storage).unwrap(), None);
+    }
+
+    #[test]
+    fn test_distribute_rewards_a_page_of_clubs_at_a_time() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1),
+            reward_periodicity: 5 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+            usdc_ibc_symbol: "uusd".to_string(),
+            reward_split_policy: None,
+            winning_club_strategy: Some(WinningClubStrategy::LargestTotalStake),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        register_test_clubs(deps.as_mut(), adminInfo.clone());
+
+        for (owner, club_name, staker, amount) in [
+            ("owner001", "CLUB001", "staker001", 300000u128),
+            ("owner002", "CLUB002", "staker002", 100000u128),
+        ] {
+            buy_a_club(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(owner, &[coin(0, "uusd")]),
+                owner.to_string(),
+                Some(String::default()),
+                club_name.to_string(),
+                Uint128::from(1000000u128),
+                false, // NO AUTO STAKE
+            )
+                .unwrap();
+            stake_on_a_club(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(staker, &[coin(10, "stake")]),
+                staker.to_string(),
+                club_name.to_string(),
+                Uint128::from(amount),
+                false, // NO AUTO STAKE
+            )
+                .unwrap();
+        }
+        increase_reward_amount(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "reward_from abc".to_string(),
+            Uint128::from(1000000u128),
+        )
+            .unwrap();
+
+        // one club per call, the standings of both clubs are taken first
+        let rsp = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), Some(1)).unwrap();
+        assert_eq!(rsp.attributes[1].value, "reward distribution in progress");
+        assert_eq!(rsp.attributes[2].value, "CLUB001");
+
+        // stakes can not change until the distribution completes
+        let stake_msg = ExecuteMsg::StakeOnAClub {
+            staker: "staker001".to_string(),
+            club_name: "CLUB002".to_string(),
+            amount: Uint128::from(500000u128),
+            auto_stake: false,
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("staker001", &[]), stake_msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::generic_err("Reward distribution in progress")));
+
+        // the standings are complete and the first club is credited
+        let rsp = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), Some(1)).unwrap();
+        assert_eq!(rsp.attributes[2].value, "CLUB001");
+        let wcd = query_winning_club_details(&deps.storage, Some(1u64)).unwrap();
+        assert_eq!(wcd.winner_list, vec!["CLUB001".to_string()]);
+        assert_eq!(wcd.total_number_of_clubs, 2u64);
+        assert_eq!(query_club_reward_history(&deps.storage, 1, None, None).unwrap().len(), 1);
+
+        let rsp = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), Some(1)).unwrap();
+        assert_eq!(rsp.events[0].ty, "reward_distributed");
+        assert_eq!(REWARD_DISTRIBUTION_PROGRESS.may_load(&deps.storage).unwrap(), None);
+        assert_eq!(query_club_reward_history(&deps.storage, 1, None, None).unwrap().len(), 2);
+
+        // 78% shared by stake, 19% to the stakers of the winning club, 3% to the owners
+        let staker1_reward = query_staker_rewards(deps.as_ref(), "staker001".to_string(), "CLUB001".to_string()).unwrap();
+        assert_eq!(staker1_reward, Uint128::from(774999u128));
+        let staker2_reward = query_staker_rewards(deps.as_ref(), "staker002".to_string(), "CLUB002".to_string()).unwrap();
+        assert_eq!(staker2_reward, Uint128::from(195000u128));
+        // the rounding dust stays for the next period
+        assert_eq!(query_reward_amount(&deps.storage).unwrap(), Uint128::from(1u128));
+
+        let err = calculate_and_distribute_rewards(deps.as_mut(), mock_env(), adminInfo.clone(), Some(1)).unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::generic_err("Time for Reward not yet arrived")));
+        execute(deps.as_mut(), mock_env(), mock_info("staker001", &[]), stake_msg).unwrap();
     }
 }
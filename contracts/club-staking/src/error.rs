@@ -1,4 +1,5 @@
 use cosmwasm_std::{StdError, Uint128};
+use cw_controllers::{AdminError, ClaimError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,9 +7,18 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Admin(#[from] AdminError),
+
+    #[error("{0}")]
+    Claim(#[from] ClaimError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("No admin proposed to {address}")]
+    NotPendingAdmin { address: String },
+
     #[error("Cannot set to own account")]
     CannotSetOwnAccount {},
 
@@ -31,6 +41,21 @@ pub enum ContractError {
         backtrace: Backtrace,
     },
 
+    #[error("Cannot migrate from {previous_contract} contract")]
+    CannotMigrate { previous_contract: String },
+
+    #[error("Cannot migrate from version {previous_version} to older version {new_version}")]
+    CannotMigrateVersion {
+        previous_version: String,
+        new_version: String,
+    },
+
+    #[error("{operation} is paused")]
+    Paused { operation: String },
+
+    #[error("Reward split policy shares add up to {total} instead of 10000")]
+    InvalidRewardSplitPolicy { total: Uint128 },
+
     #[error("Fees received = {received}uusd whereas required = {required}uusd")]
     InsufficientFees {
         received: Uint128,
@@ -38,4 +63,10 @@ pub enum ContractError {
         #[cfg(feature = "backtraces")]
         backtrace: Backtrace,
     },
+
+    #[error("Fees received = {received} fury whereas required = {required} fury")]
+    InsufficientFuryFees {
+        received: Uint128,
+        required: Uint128,
+    },
 }
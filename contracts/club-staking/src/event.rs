@@ -0,0 +1,285 @@
+use cosmwasm_std::{Event, Response, Timestamp, Uint128};
+
+/// This defines an event which should be added to `Response`.
+/// The chain emits it with the type prefixed by `wasm-`, e.g. `wasm-club_bought`
+pub trait StakingEvent {
+    /// Append event to response
+    fn add_event(&self, response: &mut Response);
+}
+
+/// Tracks a club bought at the club price or from a listing
+pub struct ClubBoughtEvent<'a> {
+    pub club_name: &'a str,
+    pub buyer: &'a str,
+    /// previous owner, if any
+    pub seller: Option<&'a str>,
+    pub price: Uint128,
+}
+
+impl<'a> StakingEvent for ClubBoughtEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        let mut event = Event::new("club_bought")
+            .add_attribute("club_name", self.club_name)
+            .add_attribute("buyer", self.buyer)
+            .add_attribute("price", self.price);
+        if let Some(seller) = self.seller {
+            event = event.add_attribute("seller", seller);
+        }
+        rsp.events.push(event);
+    }
+}
+
+/// Tracks a club assigned to an owner by an assigner
+pub struct ClubAssignedEvent<'a> {
+    pub club_name: &'a str,
+    pub owner: &'a str,
+}
+
+impl<'a> StakingEvent for ClubAssignedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("club_assigned")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("owner", self.owner),
+        );
+    }
+}
+
+/// Tracks a club released by its owner for buying by others
+pub struct ClubReleasedEvent<'a> {
+    pub club_name: &'a str,
+    pub owner: &'a str,
+}
+
+impl<'a> StakingEvent for ClubReleasedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("club_released")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("owner", self.owner),
+        );
+    }
+}
+
+/// Tracks a club listed for sale by its owner
+pub struct ClubListedEvent<'a> {
+    pub club_name: &'a str,
+    pub seller: &'a str,
+    pub asking_price: Uint128,
+    pub expiry: Timestamp,
+}
+
+impl<'a> StakingEvent for ClubListedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("club_listed")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("seller", self.seller)
+                .add_attribute("asking_price", self.asking_price)
+                .add_attribute("expiry", self.expiry.seconds().to_string()),
+        );
+    }
+}
+
+/// Tracks a club listing cancelled by the seller
+pub struct ClubListingCancelledEvent<'a> {
+    pub club_name: &'a str,
+    pub seller: &'a str,
+}
+
+impl<'a> StakingEvent for ClubListingCancelledEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("club_listing_cancelled")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("seller", self.seller),
+        );
+    }
+}
+
+/// Tracks stake added to a club by the staker or an assigner
+pub struct StakeAddedEvent<'a> {
+    pub club_name: &'a str,
+    pub staker: &'a str,
+    pub amount: Uint128,
+}
+
+impl<'a> StakingEvent for StakeAddedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("stake_added")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("staker", self.staker)
+                .add_attribute("amount", self.amount),
+        );
+    }
+}
+
+/// Tracks stake moved by the staker from one club to another
+pub struct StakeMovedEvent<'a> {
+    pub staker: &'a str,
+    pub from_club: &'a str,
+    pub to_club: &'a str,
+    pub amount: Uint128,
+}
+
+impl<'a> StakingEvent for StakeMovedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("stake_moved")
+                .add_attribute("staker", self.staker)
+                .add_attribute("from_club", self.from_club)
+                .add_attribute("to_club", self.to_club)
+                .add_attribute("amount", self.amount),
+        );
+    }
+}
+
+/// Tracks compounding of future rewards turned on or off by the staker
+pub struct AutoStakeSetEvent<'a> {
+    pub club_name: &'a str,
+    pub staker: &'a str,
+    pub auto_stake: bool,
+}
+
+impl<'a> StakingEvent for AutoStakeSetEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("auto_stake_set")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("staker", self.staker)
+                .add_attribute("auto_stake", self.auto_stake.to_string()),
+        );
+    }
+}
+
+/// Tracks rewards moved into the stake by the staker
+pub struct RewardsCompoundedEvent<'a> {
+    pub club_name: &'a str,
+    pub staker: &'a str,
+    pub amount: Uint128,
+}
+
+impl<'a> StakingEvent for RewardsCompoundedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("rewards_compounded")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("staker", self.staker)
+                .add_attribute("amount", self.amount),
+        );
+    }
+}
+
+/// Tracks stake withdrawn into a bond
+pub struct BondCreatedEvent<'a> {
+    pub club_name: &'a str,
+    pub bonder: &'a str,
+    pub amount: Uint128,
+    pub matures_at: Timestamp,
+}
+
+impl<'a> StakingEvent for BondCreatedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("bond_created")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("bonder", self.bonder)
+                .add_attribute("amount", self.amount)
+                .add_attribute("matures_at", self.matures_at.seconds().to_string()),
+        );
+    }
+}
+
+/// Tracks bonds paid out to the bonder, with the early unbond penalty if any
+pub struct BondReleasedEvent<'a> {
+    pub club_name: &'a str,
+    pub bonder: &'a str,
+    pub amount: Uint128,
+    pub penalty: Uint128,
+}
+
+impl<'a> StakingEvent for BondReleasedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("bond_released")
+                .add_attribute("club_name", self.club_name)
+                .add_attribute("bonder", self.bonder)
+                .add_attribute("amount", self.amount)
+                .add_attribute("penalty", self.penalty),
+        );
+    }
+}
+
+/// Tracks reward added for the next distribution
+pub struct RewardAddedEvent<'a> {
+    pub reward_from: &'a str,
+    pub amount: Uint128,
+}
+
+impl<'a> StakingEvent for RewardAddedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("reward_added")
+                .add_attribute("reward_from", self.reward_from)
+                .add_attribute("amount", self.amount),
+        );
+    }
+}
+
+/// Tracks the reward of a reward period distributed between clubs
+pub struct RewardDistributedEvent {
+    pub reward_period: u64,
+    pub total_reward: Uint128,
+    pub reward_given: Uint128,
+    pub next_timestamp: Timestamp,
+}
+
+impl StakingEvent for RewardDistributedEvent {
+    fn add_event(&self, rsp: &mut Response) {
+        rsp.events.push(
+            Event::new("reward_distributed")
+                .add_attribute("reward_period", self.reward_period.to_string())
+                .add_attribute("total_reward", self.total_reward)
+                .add_attribute("reward_given", self.reward_given)
+                .add_attribute("next_timestamp", self.next_timestamp.seconds().to_string()),
+        );
+    }
+}
+
+/// Tracks rewards claimed by a staker, an owner or a previous owner
+pub struct RewardClaimedEvent<'a> {
+    /// staker, owner or previous_owner
+    pub claimant_type: &'a str,
+    pub claimant: &'a str,
+    /// not given for previous owners, whose rewards are not kept per club
+    pub club_name: Option<&'a str>,
+    pub amount: Uint128,
+}
+
+impl<'a> StakingEvent for RewardClaimedEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        let mut event = Event::new("reward_claimed")
+            .add_attribute("claimant_type", self.claimant_type)
+            .add_attribute("claimant", self.claimant)
+            .add_attribute("amount", self.amount);
+        if let Some(club_name) = self.club_name {
+            event = event.add_attribute("club_name", club_name);
+        }
+        rsp.events.push(event);
+    }
+}
+
+/// Tracks operations paused or unpaused by the admin
+pub struct PauseSetEvent<'a> {
+    /// emitted as `paused` when true, `unpaused` otherwise
+    pub paused: bool,
+    pub operations: &'a [&'a str],
+}
+
+impl<'a> StakingEvent for PauseSetEvent<'a> {
+    fn add_event(&self, rsp: &mut Response) {
+        let ty = if self.paused { "paused" } else { "unpaused" };
+        rsp.events.push(Event::new(ty).add_attribute("operations", self.operations.join(",")));
+    }
+}
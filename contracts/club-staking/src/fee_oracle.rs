@@ -0,0 +1,106 @@
+use cosmwasm_std::{QuerierWrapper, StdResult, Uint128};
+
+use crate::msg::ProxyQueryMsgs;
+
+/// Prices fury in the stable denom so that platform fees can be charged
+pub trait FeeOracle {
+    /// Returns the UST equivalent for some Fury amount
+    fn ust_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128>;
+    /// Returns the Fury equivalent for some UST amount
+    fn fury_equivalent_to_ust(&self, ust_count: Uint128) -> StdResult<Uint128>;
+}
+
+/// Fee oracle backed by the astro proxy contract
+pub struct ProxyFeeOracle<'a> {
+    querier: QuerierWrapper<'a>,
+    proxy_address: String,
+}
+
+impl<'a> ProxyFeeOracle<'a> {
+    pub fn new(querier: QuerierWrapper<'a>, proxy_address: String) -> Self {
+        ProxyFeeOracle {
+            querier,
+            proxy_address,
+        }
+    }
+}
+
+impl<'a> FeeOracle for ProxyFeeOracle<'a> {
+    fn ust_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &ProxyQueryMsgs::get_ust_equivalent_to_fury { fury_count },
+        )
+    }
+
+    fn fury_equivalent_to_ust(&self, ust_count: Uint128) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &ProxyQueryMsgs::get_fury_equivalent_to_ust { ust_count },
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{
+        from_binary, to_binary, ContractResult, Decimal, Fraction, OwnedDeps, StdError, StdResult,
+        SystemError, SystemResult, Uint128, WasmQuery,
+    };
+
+    use crate::msg::ProxyQueryMsgs;
+
+    use super::FeeOracle;
+
+    /// Fee oracle with a fixed fury price, answering proxy queries in unit tests
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct MockFeeOracle {
+        pub ust_per_fury: Decimal,
+    }
+
+    impl MockFeeOracle {
+        pub fn new(ust_per_fury: Decimal) -> Self {
+            MockFeeOracle { ust_per_fury }
+        }
+    }
+
+    impl FeeOracle for MockFeeOracle {
+        fn ust_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128> {
+            Ok(fury_count * self.ust_per_fury)
+        }
+
+        fn fury_equivalent_to_ust(&self, ust_count: Uint128) -> StdResult<Uint128> {
+            if self.ust_per_fury.is_zero() {
+                return Err(StdError::generic_err("Fury has no price"));
+            }
+            Ok(ust_count.multiply_ratio(self.ust_per_fury.denominator(), self.ust_per_fury.numerator()))
+        }
+    }
+
+    /// Mock dependencies whose querier answers proxy price queries from the given oracle
+    pub fn mock_dependencies_with_fee_oracle(
+        oracle: MockFeeOracle,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { msg, .. } => match from_binary(msg) {
+                Ok(ProxyQueryMsgs::get_ust_equivalent_to_fury { fury_count }) => {
+                    let result = oracle.ust_equivalent_to_fury(fury_count).and_then(|ust| to_binary(&ust));
+                    SystemResult::Ok(ContractResult::from(result))
+                }
+                Ok(ProxyQueryMsgs::get_fury_equivalent_to_ust { ust_count }) => {
+                    let result = oracle.fury_equivalent_to_ust(ust_count).and_then(|fury| to_binary(&fury));
+                    SystemResult::Ok(ContractResult::from(result))
+                }
+                _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: String::from("proxy query"),
+                }),
+            },
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: String::from("wasm query"),
+            }),
+        });
+        deps
+    }
+}
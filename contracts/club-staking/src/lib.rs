@@ -1,5 +1,7 @@
 pub mod contract;
 mod error;
+pub mod event;
+mod fee_oracle;
 pub mod msg;
 pub mod state;
 
@@ -1,11 +1,11 @@
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{Binary, Decimal, Uint128};
 use cosmwasm_std::{Coin, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use cw20::Cw20ReceiveMsg;
 
-use crate::state::ClubStakingDetails;
+use crate::state::{ClubProfile, ClubStakingDetails, EarlyUnbondPolicy, RewardSplitPolicy, Role, WinningClubStrategy};
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct InstantiateMsg {
@@ -37,6 +37,10 @@ pub struct InstantiateMsg {
     pub control_fees: Uint128,
     pub max_bonding_limit_per_user: u64,
     pub usdc_ibc_symbol:String,
+    /// Split of the periodic reward between stakers and owners, defaults to 78/19/1/2
+    pub reward_split_policy: Option<RewardSplitPolicy>,
+    /// How the winning clubs are picked for each reward period, defaults to largest incremental stake
+    pub winning_club_strategy: Option<WinningClubStrategy>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -63,6 +67,13 @@ pub enum ExecuteMsg {
         amount: Uint128,
         auto_stake: bool,
     },
+    /// to Move Staked Tokens of the sender from one Club to another without bonding,
+    /// at most once per move stake cooldown
+    MoveStake {
+        from_club: String,
+        to_club: String,
+        amount: Uint128,
+    },
     /// to Stake Tokens on a Club on behalf of a Staker by Administrator
     AssignStakesToAClub {
         stake_list: Vec<ClubStakingDetails>,
@@ -73,6 +84,24 @@ pub enum ExecuteMsg {
         owner: String,
         club_name: String,
     },
+    /// to List a Club for sale at an asking price until the expiry
+    ListClub {
+        owner: String,
+        club_name: String,
+        asking_price: Uint128,
+        expiry: Timestamp,
+    },
+    /// to Cancel the listing of a Club
+    CancelClubListing {
+        owner: String,
+        club_name: String,
+    },
+    /// to Buy a listed Club at its asking price, the proceeds minus platform fees go to the seller
+    BuyListedClub {
+        buyer: String,
+        club_name: String,
+        auto_stake: bool,
+    },
     /// to Claim Rewards accumulated for a Club Owner
     ClaimOwnerRewards {
         owner: String,
@@ -89,23 +118,106 @@ pub enum ExecuteMsg {
         amount: Uint128,
         immediate_withdrawal: bool,
     },
-    /// To Distribute Rewards to Stakers and Owners based on Club Ranking by Administrator in Batches
+    /// to Claim all matured Bonded Stakes of the sender in the club, or in a page of clubs if not given
+    ClaimMatured {
+        club_name: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// To Distribute Rewards to Stakers and Owners based on Club Ranking by Administrator,
+    /// stakers are credited lazily through the club reward index.
+    /// Each call handles at most limit clubs, repeat it until the reward_distributed event.
+    /// Stakes and clubs can not be changed until then
     CalculateAndDistributeRewards {
-        staker_list: Vec<String>,
-        club_name: String,
-        is_first_batch: bool,
-        is_final_batch: bool,
+        limit: Option<u32>,
     },
     /// to Claim Rewards accumulated for a wallet of a Staker
     ClaimStakerRewards {
         staker: String,
         club_name: String,
     },
+    /// to Turn compounding of future Rewards on or off for all stakes of the sender in a Club
+    SetAutoStake {
+        club_name: String,
+        auto_stake: bool,
+    },
+    /// to Move Rewards accumulated for the sender in a Club into the stake
+    CompoundRewards {
+        club_name: String,
+    },
     IncreaseRewardAmount {
         reward_from: String,
         amount: Uint128,
     },
-
+    /// Administrator updates the split of the periodic reward between stakers and owners
+    UpdateRewardPolicy {
+        reward_split_policy: RewardSplitPolicy,
+    },
+    /// Administrator updates how the winning clubs are picked for each reward period
+    UpdateWinningClubStrategy {
+        winning_club_strategy: WinningClubStrategy,
+    },
+    /// Administrator submits the ranking of clubs from the match results, best club first,
+    /// used by the admin ranking strategy in the next reward distribution
+    SetClubRanking {
+        club_ranking: Vec<String>,
+    },
+    /// Administrator proposes a new administrator, who takes over on AcceptAdmin
+    ProposeNewAdmin {
+        new_admin: String,
+    },
+    /// Proposed administrator accepts and becomes the administrator
+    AcceptAdmin {},
+    /// Administrator grants a role to a wallet
+    GrantRole {
+        role: Role,
+        address: String,
+    },
+    /// Administrator revokes a role from a wallet
+    RevokeRole {
+        role: Role,
+        address: String,
+    },
+    /// Administrator pauses or resumes operations, switches not given are left unchanged
+    SetPaused {
+        buy: Option<bool>,
+        stake: Option<bool>,
+        withdraw: Option<bool>,
+        claim: Option<bool>,
+        distribute: Option<bool>,
+    },
+    /// Administrator updates the discount on fees paid in fury
+    UpdateFuryFeeDiscount {
+        fury_fee_discount: Uint128,
+    },
+    /// Administrator updates the penalty for unbonding immediately from bonds that have not matured yet
+    UpdateEarlyUnbondPolicy {
+        early_unbond_policy: EarlyUnbondPolicy,
+    },
+    /// Administrator updates the seconds a staker waits between moves of stake between clubs
+    UpdateMoveStakeCooldown {
+        move_stake_cooldown: u64,
+    },
+    /// Administrator registers a club so that it can be bought and staked on
+    RegisterClub {
+        club_name: String,
+        profile: ClubProfile,
+    },
+    /// Administrator replaces the profile of a registered club, e.g. to deactivate it
+    UpdateClubProfile {
+        club_name: String,
+        profile: ClubProfile,
+    },
+    /// Administrator sets the cw721 contract for club NFTs, this contract must be its minter.
+    /// NFTs are minted for a page of the clubs already owned that have none yet,
+    /// call again with start_after for the next page
+    UpdateClubNftAddress {
+        club_nft_address: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Fury sent to the contract, see ReceivedMsg
+    Receive(Cw20ReceiveMsg),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -127,16 +239,61 @@ pub enum QueryMsg {
     ClubOwnershipDetailsForOwner {
         owner_address: String,
     },
-    AllClubOwnershipDetails {},
-    AllPreviousClubOwnershipDetails {},
+    /// Returns the ownership details of clubs, ordered by club name.
+    /// Return type: Vec<ClubOwnershipDetails>.
+    AllClubOwnershipDetails {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the previous owners, ordered by address.
+    /// Return type: Vec<ClubPreviousOwnerDetails>.
+    AllPreviousClubOwnershipDetails {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the stakes of the users, at most 30 of them, in a page of clubs ordered
+    /// by club name. AllStakersInClub lists the stakers of a club.
+    /// Return type: Vec<ClubStakingDetails>.
     AllStakes {
         user_address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
+    /// Returns the stakes of the user across all clubs, ordered by stake id.
+    /// Return type: Vec<ClubStakingDetails>.
     AllStakesForUser {
         user_address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the stakes in the club, ordered by stake id.
+    /// Return type: Vec<ClubStakingDetails>.
+    AllStakesInClub {
+        club_name: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
     },
+    /// Returns the bonds of the users, at most 30 of them, in a page of clubs ordered
+    /// by club name. AllBondersInClub lists the bonders of a club.
+    /// Return type: Vec<ClubBondingDetails>.
     AllBonds {
         user_address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the addresses staking in the club, ordered by address.
+    /// Return type: Vec<String>.
+    AllStakersInClub {
+        club_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the addresses with bonds in the club, ordered by address.
+    /// Return type: Vec<String>.
+    AllBondersInClub {
+        club_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
     ClubBondingDetailsForUser {
         club_name: String,
@@ -146,10 +303,91 @@ pub enum QueryMsg {
     QueryPlatformFees {
         msg: Binary,
     },
+    /// Returns the itemized fees charged for the given ExecuteMsg.
+    /// Return type: FeeQuoteResponse.
+    FeeQuote {
+        msg: Binary,
+    },
+    /// Returns the fee in fury for the given ExecuteMsg, after the fury fee discount.
+    /// Return type: Uint128.
+    QueryFuryFees {
+        msg: Binary,
+    },
     QueryStakerRewards {
         staker: String,
         club_name: String,
     },
+    /// Returns the split of the periodic reward between stakers and owners.
+    /// Return type: RewardSplitPolicy.
+    RewardSplitPolicy {},
+    /// Returns how the winning clubs are picked for each reward period.
+    /// Return type: WinningClubStrategy.
+    WinningClubStrategy {},
+    /// Returns the winning clubs of the given reward period, or of the latest one if not given.
+    /// Return type: WinningClubDetails.
+    WinningClubDetails {
+        reward_period: Option<u64>,
+    },
+    /// Return type: cw_controllers::AdminResponse.
+    Admin {},
+    /// Returns the administrator proposed by the current one, if any.
+    /// Return type: Option<String>.
+    PendingAdmin {},
+    /// Returns the wallets holding the role, ordered by address.
+    /// Return type: Vec<String>.
+    RoleMembers {
+        role: Role,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the operations currently paused.
+    /// Return type: PauseStatus.
+    PauseStatus {},
+    /// Returns the clubs listed for sale, including expired listings.
+    /// Return type: Vec<ClubListing>.
+    ClubListings {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns how the reward of each period was split between stakers and owners.
+    /// Return type: Vec<RewardPeriodRecord>.
+    RewardPeriodHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the reward given to each club in the reward period, ordered by club name.
+    /// Return type: Vec<ClubRewardRecord>.
+    ClubRewardHistory {
+        reward_period: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the reward earned by a staker in a club for each reward period.
+    /// Return type: Vec<StakerRewardRecord>.
+    StakerRewardHistory {
+        staker: String,
+        club_name: String,
+    },
+    /// Return type: EarlyUnbondPolicy.
+    EarlyUnbondPolicy {},
+    /// Returns the seconds a staker waits between moves of stake between clubs.
+    /// Return type: u64.
+    MoveStakeCooldown {},
+    /// Returns the profile of a registered club with its owner and total stake.
+    /// Return type: ClubInfoResponse.
+    ClubInfo {
+        club_name: String,
+    },
+    /// Return type: Option<Addr>.
+    ClubNftAddress {},
+    /// Returns how an immediate withdrawal of the amount would be covered by the bonds of the staker
+    /// and the penalty charged for bonds that have not matured yet.
+    /// Return type: EarlyUnbondQuoteResponse.
+    EarlyUnbondQuote {
+        staker: String,
+        club_name: String,
+        amount: Uint128,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -157,6 +395,8 @@ pub enum QueryMsg {
 pub enum ReceivedMsg {
     /// Incoming Rewards for meant for distribution to Stakers and Owners
     IncreaseRewardAmount(IncreaseRewardAmountCommand),
+    /// Fees for the given message paid in Fury, the message is executed on behalf of the sender
+    PayFeesInFury(PayFeesInFuryCommand),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -164,9 +404,54 @@ pub struct IncreaseRewardAmountCommand {
     pub reward_from: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayFeesInFuryCommand {
+    pub msg: ExecuteMsg,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct MigrateMsg {}
 
+/// Itemized fees for an ExecuteMsg, all amounts in `denom`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeQuoteResponse {
+    pub platform_fee: Uint128,
+    pub transaction_fee: Uint128,
+    pub control_fee: Uint128,
+    pub swap_fee: Uint128,
+    pub denom: String,
+    /// Fury amount the fees are computed on
+    pub fury_reference_amount: Uint128,
+    /// Price of one fury in `denom` reported by the proxy
+    pub price_used: Decimal,
+}
+
+/// Split of an immediate withdrawal between matured and early unbonded bonds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct EarlyUnbondQuoteResponse {
+    /// Amount taken from matured bonds
+    pub matured_amount: Uint128,
+    /// Amount taken from bonds that have not matured yet
+    pub early_amount: Uint128,
+    /// Penalty on early_amount
+    pub penalty: Uint128,
+    /// Amount not covered by the bonds, the withdrawal fails unless it is zero
+    pub shortfall: Uint128,
+    /// Amount transferred to the staker
+    pub amount_received: Uint128,
+}
+
+/// Profile of a registered club along with its ownership
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClubInfoResponse {
+    pub club_name: String,
+    pub profile: ClubProfile,
+    /// None until the club is bought or assigned
+    pub owner_address: Option<String>,
+    pub owner_released: bool,
+    pub total_staked_amount: Uint128,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub enum ProxyQueryMsgs {
     get_fury_equivalent_to_ust {
@@ -1,11 +1,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Addr, Timestamp, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, BlockInfo, Decimal, Empty, Timestamp, Uint128};
+use cw_controllers::{Admin, Claimable, ScopedClaims};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, U64Key};
+
+/// Seconds between moves of stake between clubs unless the admin configures otherwise
+pub const DEFAULT_MOVE_STAKE_COOLDOWN: u64 = 60 * 60;
+
+fn default_move_stake_cooldown() -> u64 {
+    DEFAULT_MOVE_STAKE_COOLDOWN
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
+    /// current administrator, kept in sync with ADMIN
     pub admin_address: Addr,
     pub minting_contract_address: Addr,
     pub astro_proxy_address: Addr,
@@ -24,10 +33,196 @@ pub struct Config {
     pub control_fees: Uint128,
     pub max_bonding_limit_per_user: u64,
     pub usdc_ibc_symbol:String,
+    /// How each periodic reward is split between stakers and club owners
+    pub reward_split_policy: RewardSplitPolicy,
+    /// How the winning clubs are picked for each reward period
+    pub winning_club_strategy: WinningClubStrategy,
+    ///Discount on fees paid in fury, in percentage multiplied by 100
+    #[serde(default)]
+    pub fury_fee_discount: Uint128,
+    /// Penalty for unbonding immediately from bonds that have not matured yet
+    #[serde(default)]
+    pub early_unbond_policy: EarlyUnbondPolicy,
+    /// Seconds a staker waits between moves of stake between clubs, moves are also not allowed
+    /// this long before the next reward distribution. 0 disables both limits
+    #[serde(default = "default_move_stake_cooldown")]
+    pub move_stake_cooldown: u64,
+    /// cw721 contract holding one NFT per club, the NFT holder is the owner of the club.
+    /// None while club ownership is only recorded here
+    #[serde(default)]
+    pub club_nft_address: Option<Addr>,
+}
+
+/// Config as stored before version 0.10.0, only read by migrate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyConfig {
+    pub admin_address: Addr,
+    pub minting_contract_address: Addr,
+    pub astro_proxy_address: Addr,
+    pub club_fee_collector_wallet: Addr,
+    pub club_reward_next_timestamp: Timestamp,
+    pub reward_periodicity: u64,
+    pub club_price: Uint128,
+    pub bonding_duration: u64,
+    pub owner_release_locking_duration: u64,
+    pub platform_fees_collector_wallet: Addr,
+    pub platform_fees: Uint128,
+    pub transaction_fees: Uint128,
+    pub control_fees: Uint128,
+    pub max_bonding_limit_per_user: u64,
+    pub usdc_ibc_symbol:String,
+}
+
+/// Strategy for picking the winning clubs of a reward period
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WinningClubStrategy {
+    /// Club with the largest increase in stake since the last reward period,
+    /// tie broken on the total stake
+    LargestIncrementalStake,
+    /// Club with the largest total stake
+    LargestTotalStake,
+    /// The given number of clubs with the largest increase in stake
+    TopClubsByIncrementalStake { count: u64 },
+    /// The given number of clubs at the top of the ranking submitted by the administrator
+    AdminRanking { count: u64 },
+}
+
+impl Default for WinningClubStrategy {
+    fn default() -> Self {
+        WinningClubStrategy::LargestIncrementalStake
+    }
+}
+
+/// Split of the periodic reward between stakers and club owners.
+/// All shares are specified in percentage multiplied by 100, i.e. 100% = 10000 and 0.01% = 1
+/// and must add up to 100%
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RewardSplitPolicy {
+    /// Share for all stakers, proportional to their stake across all clubs
+    pub all_stakers_share: Uint128,
+    /// Share for stakers of the winning clubs, split equally between winning clubs
+    pub winning_club_stakers_share: Uint128,
+    /// Share for owners of the winning clubs, split equally between winning clubs
+    pub winning_club_owners_share: Uint128,
+    /// Share for owners of the other clubs, split equally between them.
+    /// Goes to the owners of the winning clubs when all clubs are winners
+    pub non_winning_club_owners_share: Uint128,
+}
+
+impl Default for RewardSplitPolicy {
+    fn default() -> Self {
+        RewardSplitPolicy {
+            all_stakers_share: Uint128::from(7800u128),
+            winning_club_stakers_share: Uint128::from(1900u128),
+            winning_club_owners_share: Uint128::from(100u128),
+            non_winning_club_owners_share: Uint128::from(200u128),
+        }
+    }
+}
+
+/// Penalty charged when a staker withdraws immediately from bonds that have not matured yet
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct EarlyUnbondPolicy {
+    pub curve: PenaltyCurve,
+    pub destination: PenaltyDestination,
+}
+
+/// Penalty as a function of the time remaining until the bond matures.
+/// Penalties are specified in percentage multiplied by 100, i.e. 100% = 10000 and 0.01% = 1
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PenaltyCurve {
+    /// Bonds can only be withdrawn immediately after maturity
+    Disabled,
+    /// Penalty decreases linearly from max_penalty at the start of bonding to 0 at maturity
+    Linear { max_penalty: Uint128 },
+    /// Penalty of the step with the highest min_remaining_seconds not above the remaining time,
+    /// no penalty below all steps
+    Stepped { steps: Vec<PenaltyStep> },
 }
 
+impl Default for PenaltyCurve {
+    fn default() -> Self {
+        PenaltyCurve::Disabled
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PenaltyStep {
+    pub min_remaining_seconds: u64,
+    pub penalty: Uint128,
+}
+
+/// Where the early unbond penalty goes
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PenaltyDestination {
+    /// Added to the reward for the next distribution
+    RewardPool,
+    /// Burnt from the fury supply
+    Burn,
+}
+
+impl Default for PenaltyDestination {
+    fn default() -> Self {
+        PenaltyDestination::Burn
+    }
+}
+
+/// Administrator of the contract
+pub const ADMIN: Admin = Admin::new("admin");
+
+/// Administrator proposed by the current one, until it accepts
+pub const PENDING_ADMIN: Item<Addr> = Item::new("pending_admin");
+
+/// Roles the administrator grants to other wallets
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// adds and distributes rewards, and submits the club ranking
+    Distributor,
+    /// assigns clubs and stakes on behalf of users
+    Assigner,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Distributor => "distributor",
+            Role::Assigner => "assigner",
+        }
+    }
+}
+
+/// Wallets holding each role. the key is the role and the wallet address
+pub const ROLES: Map<(&str, &Addr), Empty> = Map::new("roles");
+
+/// Operations halted by the administrator, e.g. during an incident
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PauseStatus {
+    /// buying, assigning and listing clubs
+    pub buy: bool,
+    /// staking on clubs
+    pub stake: bool,
+    /// withdrawing stakes
+    pub withdraw: bool,
+    /// claiming staker and owner rewards
+    pub claim: bool,
+    /// adding and distributing rewards
+    pub distribute: bool,
+}
+
+/// Nothing is paused when not present
+pub const PAUSE_STATUS: Item<PauseStatus> = Item::new("pause_status");
+
 pub const CONFIG_KEY: &str = "config";
 pub const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new(CONFIG_KEY);
 
 /// This is used for saving various vesting details
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
@@ -57,6 +252,41 @@ pub struct ClubOwnershipDetails {
     pub total_staked_amount: Uint128,
 }
 
+/// A club put up for sale by its owner at an asking price
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct ClubListing {
+    pub club_name: String,
+
+    /// owner of the club who receives the proceeds
+    pub seller: String,
+
+    /// price in Fury the buyer pays, the platform fee is deducted from it
+    pub asking_price: Uint128,
+
+    /// The system timestamp after which the club can no longer be bought at this listing
+    pub expiry: Timestamp,
+}
+
+/// Map of club names and their listing for sale
+pub const CLUB_LISTINGS: Map<String, ClubListing> = Map::new("club_listings");
+
+/// Profile of a club registered by the admin, only registered clubs can be bought or staked on
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct ClubProfile {
+    pub display_name: String,
+    pub logo_url: Option<String>,
+    pub sport: String,
+    pub league: String,
+
+    /// inactive clubs can no longer be bought or staked on
+    pub active: bool,
+}
+
+/// Map of normalized club names and their profile
+pub const CLUB_REGISTRY: Map<String, ClubProfile> = Map::new("club_registry");
+
 /// Used to shift previous owner from ClubOwnerShipDetails to a new state variable -
 /// used by previous owner using new verb PreviousOwnerRewardOut()
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
@@ -73,6 +303,10 @@ pub struct ClubPreviousOwnerDetails {
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct ClubStakingDetails {
+    /// id of the stake, assigned when the stake is first saved
+    #[serde(default)]
+    pub stake_id: u64,
+
     pub club_name: String,
 
     pub staker_address: String,
@@ -91,6 +325,10 @@ pub struct ClubStakingDetails {
 
     /// whether rewards are auto-staked or do they need to be claimed
     pub auto_stake: bool,
+
+    /// club reward index at which the rewards of this stake were last settled
+    #[serde(default)]
+    pub reward_index: Decimal,
 }
 
 /// This is used for saving various bonding details for an unstaked club
@@ -111,8 +349,18 @@ pub struct ClubBondingDetails {
     pub bonding_duration: u64,
 }
 
+impl Claimable for ClubBondingDetails {
+    fn amount(&self) -> Uint128 {
+        self.bonded_amount
+    }
 
-/// This is used for saving various bonding details for an unstaked club
+    fn is_mature(&self, block: &BlockInfo) -> bool {
+        self.bonding_start_timestamp < block.time.minus_seconds(self.bonding_duration)
+    }
+}
+
+
+/// This is used for saving the winning clubs of a reward period
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct WinningClubDetails {
@@ -127,15 +375,61 @@ pub struct WinningClubDetails {
 pub const CLUB_OWNERSHIP_DETAILS: Map<String, ClubOwnershipDetails> =
     Map::new("club_ownership_details");
 
-/// Map of clubs and its stakers. the key is club name and staker address and the
-/// ClubStakingDetails will contain information about the stakers and amount staked
-pub const CLUB_STAKING_DETAILS: Map<(&str, &str), Vec<ClubStakingDetails>> =
+/// Map of clubs and its stakers as stored before stakes were keyed by stake id.
+/// Only read by migrate to move the stakes to club_staking_details()
+pub const LEGACY_CLUB_STAKING_DETAILS: Map<(&str, &str), Vec<ClubStakingDetails>> =
     Map::new("club_staking_details");
 
-/// Map of clubs and its bonders. the key is club name and (un)staker address and the
-/// ClubBondingDetails will contain information about the bonders and amount bonded
-pub const CLUB_BONDING_DETAILS: Map<(&str, &str), Vec<ClubBondingDetails>> =
-    Map::new("club_bonding_details");
+/// Set of stakers of each club. the key is club name and staker address
+pub const CLUB_STAKERS: Map<(&str, &str), Empty> = Map::new("club_stakers");
+
+/// Last stake id assigned
+pub const STAKE_ID: Item<u64> = Item::new("stake_id");
+
+pub struct ClubStakingIndexes<'a> {
+    /// index on club name, the last element is the stake id
+    pub club: MultiIndex<'a, (Vec<u8>, Vec<u8>), ClubStakingDetails>,
+    /// index on staker address, the last element is the stake id
+    pub staker: MultiIndex<'a, (Vec<u8>, Vec<u8>), ClubStakingDetails>,
+    /// index on club name and staker address, the last element is the stake id
+    pub club_staker: MultiIndex<'a, (Vec<u8>, Vec<u8>, Vec<u8>), ClubStakingDetails>,
+}
+
+impl<'a> IndexList<ClubStakingDetails> for ClubStakingIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<ClubStakingDetails>> + '_> {
+        let v: Vec<&dyn Index<ClubStakingDetails>> = vec![&self.club, &self.staker, &self.club_staker];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Map of stakes. the key is the stake id and the
+/// ClubStakingDetails will contain information about the staker and amount staked
+pub fn club_staking_details<'a>() -> IndexedMap<'a, U64Key, ClubStakingDetails, ClubStakingIndexes<'a>> {
+    let indexes = ClubStakingIndexes {
+        club: MultiIndex::new(
+            |d: &ClubStakingDetails, pk: Vec<u8>| (d.club_name.as_bytes().to_vec(), pk),
+            "club_stakes",
+            "club_stakes__club",
+        ),
+        staker: MultiIndex::new(
+            |d: &ClubStakingDetails, pk: Vec<u8>| (d.staker_address.as_bytes().to_vec(), pk),
+            "club_stakes",
+            "club_stakes__staker",
+        ),
+        club_staker: MultiIndex::new(
+            |d: &ClubStakingDetails, pk: Vec<u8>| {
+                (d.club_name.as_bytes().to_vec(), d.staker_address.as_bytes().to_vec(), pk)
+            },
+            "club_stakes",
+            "club_stakes__club_staker",
+        ),
+    };
+    IndexedMap::new("club_stakes", indexes)
+}
+
+/// Bonds of each club and its bonders, scoped per club. the key is club name and (un)staker
+/// address and the ClubBondingDetails will contain information about the bonders and amount bonded
+pub const CLUB_BONDING_DETAILS: ScopedClaims<ClubBondingDetails> = ScopedClaims::new("club_bonding_details");
 
 /// Map of previous owners and their reward points. the key is owner address and the
 /// ClubPreviousOwnerDetails will contain information about the
@@ -143,13 +437,177 @@ pub const CLUB_BONDING_DETAILS: Map<(&str, &str), Vec<ClubBondingDetails>> =
 pub const CLUB_PREVIOUS_OWNER_DETAILS: Map<String, ClubPreviousOwnerDetails> =
     Map::new("club_previous_owner_details");
 
+/// Map of clubs and their global reward per staked token. the key is club name and the
+/// value only ever increases, each stake keeps its own checkpoint in reward_index
+pub const CLUB_REWARD_INDEX: Map<String, Decimal> = Map::new("club_reward_index");
+
 pub const REWARD: Item<Uint128> = Item::new("staking_reward");
 pub const REWARD_GIVEN_IN_CURRENT_TIMESTAMP: Item<Uint128> = Item::new("reward_given_in_current_timestamp");
 pub const CLUB_REWARD_NEXT_TIMESTAMP: Item<Timestamp> = Item::new("club_reward_next_timestamp");
 
+/// Map of stakers and the time they last moved stake between clubs. the key is staker address
+pub const LAST_STAKE_MOVE: Map<&str, Timestamp> = Map::new("last_stake_move");
+
 /// Snapshot of ranking by stakes
 pub const CLUB_STAKING_SNAPSHOT: Map<String, Uint128> =
     Map::new("club_staking_snapshot");
 
+/// Stake figures of a club in the current reward period, used to pick the winning clubs
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct ClubStakeStanding {
+    pub club_name: String,
+    pub total_stake: Uint128,
+    /// total stake of the club at the previous reward distribution
+    pub previous_stake: Uint128,
+}
+
+impl ClubStakeStanding {
+    /// Increase in stake since the previous reward distribution, negative on a decrease
+    pub fn incremental_stake(&self) -> i128 {
+        self.total_stake.u128() as i128 - self.previous_stake.u128() as i128
+    }
+}
+
+/// Progress of a reward distribution spread over several calls. The standings
+/// of all clubs are taken first, then the rewards are credited club by club.
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct RewardDistributionProgress {
+    pub total_reward: Uint128,
+
+    /// next reward timestamp, saved once the distribution completes
+    pub next_timestamp: Timestamp,
+
+    /// whether the standings of all clubs are taken and the winners known
+    pub standings_taken: bool,
+
+    /// last club handled by the previous call
+    pub last_club: Option<String>,
+
+    pub total_number_of_clubs: u64,
+
+    pub total_stake_across_all_clubs: Uint128,
+
+    /// clubs in the running for winner so far as per the winning club strategy
+    pub leaders: Vec<ClubStakeStanding>,
+
+    /// reward given to the clubs credited so far
+    pub reward_given: Uint128,
+}
+
+/// Present while a reward distribution is in progress
+pub const REWARD_DISTRIBUTION_PROGRESS: Item<RewardDistributionProgress> =
+    Item::new("reward_distribution_progress");
+
 /// Snapshot of winning club details
 pub const WINNING_CLUB_DETAILS_SNAPSHOT: Item<WinningClubDetails> = Item::new("winning_club_details_snapshot");
+
+/// Number of the latest reward period for which rewards were distributed
+pub const REWARD_PERIOD: Item<u64> = Item::new("reward_period");
+
+/// Map of reward periods and their winning clubs. the key is the reward period
+pub const WINNING_CLUB_DETAILS_HISTORY: Map<U64Key, WinningClubDetails> =
+    Map::new("winning_club_details_history");
+
+/// Reward given to the stakers and the owner of a club in a reward period
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct ClubRewardRecord {
+    pub club_name: String,
+
+    /// owner of the club at the time of distribution
+    pub owner_address: String,
+
+    /// total amount staked in the club at the time of distribution
+    pub total_staked_amount: Uint128,
+
+    /// increase of the club reward index, i.e. reward per staked token
+    pub reward_index_increment: Decimal,
+
+    /// reward for all stakers of the club together
+    pub stakers_reward: Uint128,
+
+    /// reward for the club owner
+    pub owner_reward: Uint128,
+}
+
+/// This is used for saving how the reward of a period was distributed
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct RewardPeriodRecord {
+    pub reward_period: u64,
+
+    /// The system timestamp of the distribution
+    pub timestamp: Timestamp,
+
+    /// reward available for distribution in this period
+    pub total_reward: Uint128,
+
+    /// reward given to stakers and owners
+    pub reward_given: Uint128,
+
+    /// dust and unallocated reward carried over to the next period
+    pub undistributed_reward: Uint128,
+
+    pub winner_list: Vec<String>,
+
+    pub total_stake_across_all_clubs: Uint128,
+}
+
+/// RewardPeriodRecord as stored when it held the reward of every club.
+/// Only read by migrate to move the club rewards to CLUB_REWARD_HISTORY
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct LegacyRewardPeriodRecord {
+    pub reward_period: u64,
+    pub timestamp: Timestamp,
+    pub total_reward: Uint128,
+    pub reward_given: Uint128,
+    pub undistributed_reward: Uint128,
+    pub winner_list: Vec<String>,
+    pub total_stake_across_all_clubs: Uint128,
+    pub club_rewards: Vec<ClubRewardRecord>,
+}
+
+/// Reward of a staker in a club for one reward period
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct StakerRewardRecord {
+    pub reward_period: u64,
+
+    /// The system timestamp of the distribution
+    pub timestamp: Timestamp,
+
+    /// amount staked by the staker during the period
+    pub staked_amount: Uint128,
+
+    /// increase of the club reward index in the period
+    pub reward_index_increment: Decimal,
+
+    /// staked_amount multiplied by reward_index_increment
+    pub staking_reward: Uint128,
+
+    /// reward as owner of the club
+    pub owner_reward: Uint128,
+}
+
+/// Map of reward periods and how their reward was distributed. the key is the reward period
+pub const REWARD_PERIOD_HISTORY: Map<U64Key, RewardPeriodRecord> =
+    Map::new("reward_period_history");
+pub const LEGACY_REWARD_PERIOD_HISTORY: Map<U64Key, LegacyRewardPeriodRecord> =
+    Map::new("reward_period_history");
+
+/// Map of reward periods and clubs to the reward given to the club.
+/// the key is the reward period and club name
+pub const CLUB_REWARD_HISTORY: Map<(U64Key, &str), ClubRewardRecord> =
+    Map::new("club_reward_history");
+
+/// Map of club, staker and reward period to the amount staked at the end of the period.
+/// Only periods in which the stake changed are present.
+pub const CLUB_STAKE_HISTORY: Map<(&str, &str, U64Key), Uint128> =
+    Map::new("club_stake_history");
+
+/// Ranking of clubs from match results submitted by the administrator,
+/// used for the next reward distribution with the admin ranking strategy
+pub const CLUB_RANKING: Item<Vec<String>> = Item::new("club_ranking");
@@ -1,24 +1,16 @@
-use std::convert::TryFrom;
-use std::ops::{Div, Mul};
-use std::str::FromStr;
-
-use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, to_binary, Uint128};
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, to_binary, Uint128};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::OverflowOperation::Add;
-use schemars::_serde_json::ser::State;
 
-use cw20::Cw20QueryMsg;
 use cw2::set_contract_version;
 
 use crate::error::ContractError;
-use crate::execute::{cancel_game, claim_refund, claim_reward, create_pool, execute_sweep,
+use crate::execute::{cancel_game, claim_refund, claim_reward, create_game, create_pool, execute_sweep,
                      game_pool_bid_submit, game_pool_reward_distribute, lock_game,
-                     save_team_details, set_platform_fee_wallets,
-                     set_pool_type_params, swap};
-use crate::msg::{BalanceResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::query::{get_team_count_for_user_in_pool_type, query_all_pool_type_details, query_all_pools_in_game, query_all_teams, query_game_details, query_game_result, query_pool_collection, query_pool_details, query_pool_team_details, query_pool_type_details, query_refund, query_reward, query_swap_data_for_pool, query_team_details, query_total_fees};
-use crate::state::{Config, CONFIG, GAME_DETAILS, GAME_RESULT_DUMMY, GameDetails, GameResult, SWAP_BALANCE_INFO};
+                     set_platform_fee_wallets, set_pool_type_params, swap};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::query::{get_team_count_for_user_in_pool_type, query_all_pool_type_details, query_all_pools_in_game, query_all_teams, query_game_details, query_game_result, query_games, query_pool_collection, query_pool_details, query_pool_team_details, query_pool_type_details, query_refund, query_reward, query_swap_data_for_pool, query_team_details, query_total_fees};
+use crate::state::{Config, CONFIG, GAME_RESULT_DUMMY, GameDetails, GameResult, games, PoolDetails, pools, SWAP_BALANCE_INFO};
 
 // This is a comment
 // version info for migration info
@@ -45,11 +37,6 @@ pub const CLAIMED_REFUND: bool = true;
 pub const REWARDS_DISTRIBUTED: bool = true;
 pub const REWARDS_NOT_DISTRIBUTED: bool = false;
 
-pub const GAME_POOL_OPEN: u64 = 1u64;
-pub const GAME_POOL_CLOSED: u64 = 2u64;
-pub const GAME_CANCELLED: u64 = 3u64;
-pub const GAME_COMPLETED: u64 = 4u64;
-pub const HUNDRED_PERCENT: u128 = 10000u128;
 pub const NINETY_NINE_NINE_PERCENT: u128 = 9990u128;
 
 pub const DUMMY_TEAM_ID: &str = "DUMMY_TEAM_ID";
@@ -68,10 +55,12 @@ pub fn instantiate(
         platform_fees_collector_wallet: deps
             .api
             .addr_validate(&msg.platform_fees_collector_wallet)?,
+        astro_proxy_address: deps.api.addr_validate(&msg.astro_proxy_address)?,
+        swap_contract_address: deps.api.addr_validate(&msg.swap_contract_address)?,
         platform_fee: msg.platform_fee,
         transaction_fee: msg.transaction_fee,
-        game_id: msg.game_id.clone(),
-        usdc_ibc_symbol: msg.usdc_ibc_symbol,
+        fury_denom: msg.fury_denom,
+        usdc_denom: msg.usdc_denom,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -87,15 +76,6 @@ pub fn instantiate(
             reward_amount: Uint128::from(INITIAL_REWARD_AMOUNT),
         },
     )?;
-
-    GAME_DETAILS.save(
-        deps.storage,
-        msg.game_id.clone(),
-        &GameDetails {
-            game_id: msg.game_id.clone(),
-            game_status: GAME_POOL_OPEN,
-        },
-    )?;
     Ok(Response::default())
 }
 
@@ -106,8 +86,6 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Query Cw20 Check list
-    // check_and_confirm_whitelist_status(&deps, &info, &env)?;
     match msg {
         ExecuteMsg::SetPlatformFeeWallets { wallet_percentages } => {
             set_platform_fee_wallets(deps, info, wallet_percentages)
@@ -119,6 +97,7 @@ pub fn execute(
             max_teams_for_pool,
             max_teams_for_gamer,
             wallet_percentages,
+            payout,
         } => set_pool_type_params(
             deps,
             env,
@@ -129,19 +108,29 @@ pub fn execute(
             max_teams_for_pool,
             max_teams_for_gamer,
             wallet_percentages,
+            payout,
         ),
-        ExecuteMsg::CancelGame {} => cancel_game(deps, env, info),
-        ExecuteMsg::LockGame {} => lock_game(deps, env, info),
-        ExecuteMsg::CreatePool { pool_type } => create_pool(deps, env, info, pool_type),
-        ExecuteMsg::ClaimReward { gamer } => claim_reward(deps, info, gamer, env),
-        ExecuteMsg::ClaimRefund { gamer, max_spread } => claim_refund(deps, info, gamer, env, None, max_spread),
+        ExecuteMsg::CreateGame {
+            game_id,
+            lock_at,
+            start_at,
+            settle_deadline,
+            pool_types,
+        } => create_game(deps, env, info, game_id, lock_at, start_at, settle_deadline, pool_types),
+        ExecuteMsg::CancelGame { game_id } => cancel_game(deps, env, info, game_id),
+        ExecuteMsg::LockGame { game_id } => lock_game(deps, env, info, game_id),
+        ExecuteMsg::CreatePool { game_id, pool_type } => create_pool(deps, env, info, game_id, pool_type),
+        ExecuteMsg::ClaimReward { gamer, game_id } => claim_reward(deps, info, gamer, game_id, env),
+        ExecuteMsg::ClaimRefund { gamer, game_id, max_spread } => {
+            claim_refund(deps, info, gamer, game_id, env, max_spread)
+        }
         ExecuteMsg::GamePoolRewardDistribute {
             pool_id,
-            game_winners,
+            ranking,
             is_final_batch,
-            ust_for_rake,
+            usdc_for_rake,
             game_id,
-        } => game_pool_reward_distribute(deps, env, info, game_id, pool_id, game_winners, is_final_batch, false, ust_for_rake),
+        } => game_pool_reward_distribute(deps, env, info, game_id, pool_id, ranking, is_final_batch, usdc_for_rake),
         ExecuteMsg::GamePoolBidSubmitCommand {
             gamer,
             pool_type,
@@ -150,7 +139,7 @@ pub fn execute(
             amount,
             max_spread
         } => game_pool_bid_submit(
-            deps, env, info, gamer, pool_type, pool_id, team_id, amount, false, max_spread),
+            deps, env, info, gamer, pool_type, pool_id, team_id, amount, max_spread),
         ExecuteMsg::Sweep { funds } => execute_sweep(deps, info, funds),
         ExecuteMsg::Swap {
             amount,
@@ -159,36 +148,31 @@ pub fn execute(
     }
 }
 
-pub fn check_and_confirm_whitelist_status(
-    deps: &DepsMut,
-    info: &MessageInfo,
-    env: &Env,
-) -> Result<Response, ContractError> {
-    let query = cw20_base::msg::QueryMsg::WhitelistRestriction {
-        wallet_address: info.sender.to_string(),
-        contract_address: env.contract.address.to_string(),
-        contract_check_needed: true,
-    };
-    let state = CONFIG.load(deps.storage)?;
-    let response_is_restricted: bool = deps.querier.query_wasm_smart(
-        state.minting_contract_address,
-        &query,
-    )?;
-    if response_is_restricted {
-        return Err(ContractError::UserIsRestricted {})
-    }
-    return Ok(Response::default())
-}
-
 // This is the safe way of contract migration
 // We can add expose specific state properties to
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Games and pools saved before they were indexed are saved again to build
+    // the status index of games and the game index of pools
+    let all_games: Vec<GameDetails> = games()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, game)| game))
+        .collect::<StdResult<_>>()?;
+    for game in all_games {
+        games().save(deps.storage, game.game_id.clone(), &game)?;
+    }
+    let all_pools: Vec<PoolDetails> = pools()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, pool)| pool))
+        .collect::<StdResult<_>>()?;
+    for pool in all_pools {
+        pools().save(deps.storage, pool.pool_id.clone(), &pool)?;
+    }
     Ok(Response::default())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::PoolTeamDetails { pool_id, user } => {
             to_binary(&query_pool_team_details(deps.storage, pool_id, user)?)
@@ -198,19 +182,26 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_binary(&query_pool_type_details(deps.storage, pool_type)?)
         }
         QueryMsg::AllPoolTypeDetails {} => to_binary(&query_all_pool_type_details(deps.storage)?),
-        QueryMsg::AllTeams { users } => to_binary(&query_all_teams(deps.storage, users)?),
-        QueryMsg::QueryReward { gamer } => to_binary(&query_reward(deps.storage, gamer)?),
-        QueryMsg::QueryRefund { gamer } => to_binary(&query_refund(deps.storage, gamer)?),
+        QueryMsg::AllTeams { users, game_id } => to_binary(&query_all_teams(deps.storage, users, game_id)?),
+        QueryMsg::QueryReward { gamer, game_id } => to_binary(&query_reward(deps.storage, gamer, game_id)?),
+        QueryMsg::QueryRefund { gamer, game_id } => {
+            to_binary(&query_refund(deps.storage, gamer, game_id, env.block.time)?)
+        }
         QueryMsg::QueryGameResult {
             gamer,
             pool_id,
             team_id,
         } => to_binary(&query_game_result(deps, gamer, pool_id, team_id)?),
-        QueryMsg::GameDetails {} => to_binary(&query_game_details(deps.storage)?),
+        QueryMsg::GameDetails { game_id } => to_binary(&query_game_details(deps.storage, game_id, env.block.time)?),
+        QueryMsg::Games {
+            status,
+            start_after,
+            limit,
+        } => to_binary(&query_games(deps.storage, status, start_after, limit, env.block.time)?),
         QueryMsg::PoolTeamDetailsWithTeamId { pool_id, team_id, gamer } => {
             to_binary(&query_team_details(deps.storage, pool_id, team_id, gamer)?)
         }
-        QueryMsg::AllPoolsInGame {} => to_binary(&query_all_pools_in_game(deps.storage)?),
+        QueryMsg::AllPoolsInGame { game_id } => to_binary(&query_all_pools_in_game(deps.storage, game_id)?),
         QueryMsg::PoolCollection { pool_id } => {
             to_binary(&query_pool_collection(deps.storage, pool_id)?)
         }
@@ -244,18 +235,19 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let pool_id = msg.id.to_string();
-    let current_fury_balance: BalanceResponse = deps.querier.query_wasm_smart(
-        Addr::unchecked("fury1wkhutw4vef5s7kp9sewvv78xrnsrwczaxj3e0"),  // Replace with the actual bank token address
-        &Cw20QueryMsg::Balance {
-            address: _env.contract.address.clone().to_string()
-        },
-    )?;
+    let current_fury_balance = deps
+        .querier
+        .query_balance(_env.contract.address.clone(), config.fury_denom)?;
     let mut balance_info = SWAP_BALANCE_INFO.load(deps.storage, pool_id.clone())?;
-    balance_info.balance_post_swap = current_fury_balance.balance;
-    let balance_gained = balance_info.balance_post_swap - balance_info.balance_pre_swap;
-    // ((Balance gained * 10_000) / Amount In UST Swapped)
+    balance_info.balance_post_swap = current_fury_balance.amount;
+    let balance_gained = balance_info.balance_post_swap.checked_sub(balance_info.balance_pre_swap).map_err(StdError::overflow)?;
+    // ((Balance gained * 10_000) / Amount In USDC Swapped)
     // (poolcollection * exchange rate)/10_000 at the time of use
-    balance_info.exchange_rate = balance_gained.checked_mul(Uint128::from(10000u128)).unwrap().checked_div(balance_info.ust_amount_swapped).unwrap();
+    balance_info.exchange_rate = balance_gained
+        .checked_mul(Uint128::from(10000u128))
+        .map_err(StdError::overflow)?
+        .checked_div(balance_info.usdc_amount_swapped)
+        .map_err(StdError::divide_by_zero)?;
     SWAP_BALANCE_INFO.save(deps.storage, pool_id.clone(), &balance_info)?;
     return Ok(Response::default()
         .add_attribute("fury_balance_gained", balance_gained.to_string())
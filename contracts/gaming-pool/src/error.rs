@@ -38,8 +38,8 @@ pub enum ContractError {
     #[error("Invalid png header")]
     InvalidPngHeader {},
 
-    #[error("Insufficient fees in UST sent")]
-    InsufficientFeesUst {},
+    #[error("Insufficient fees in USDC sent")]
+    InsufficientFeesUsdc {},
 
     #[error("Number Of Coins Sent Is Invalid")]
     InvalidNumberOfCoinsSent {},
@@ -71,7 +71,4 @@ pub enum ContractError {
     #[error("Swap Info Not Found for Pool ")]
     SwapInfoNotFound {},
 
-    #[error("User Is Restricted..!")]
-    UserIsRestricted {},
-
 }
@@ -1,46 +1,22 @@
-use std::convert::TryFrom;
-use std::ops::{Add, Div, Mul};
-use std::str::FromStr;
+use std::ops::Add;
 
-// Replace terraswap with the appropriate module for native bank token (e.g., fury)
-use fury::asset::{Asset, AssetInfo};
-// Replace terraswap with the appropriate module for native bank token (e.g., fury)
-use fury::pair::ExecuteMsg as FurySwapExecute;
-use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env,
-                   from_binary, MessageInfo, Order, Response, StdError,
-                   StdResult, Storage, SubMsg, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Order, Response,
+                   StdError, StdResult, Storage, SubMsg, Timestamp, to_binary, Uint128, WasmMsg};
+use terraswap::asset::{Asset, AssetInfo};
 
-// Replace cw20 with the appropriate module for native bank token (e.g., fury)
-use cosmos_sdk_std::{
-    Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
-    Storage, Uint128, WasmMsg,
-};
-use crate::ContractError;
-// Replace the following imports based on your Cosmos SDK chain
-use fury_native_bank_token::{BankExecuteMsg as NativeBankExecuteMsg, BankQueryMsg as NativeBankQueryMsg};
-use furyswap::execute_msg::SwapExecuteMsg as FurySwapExecuteMsg;
-
-use crate::contract::{
-    CLAIMED_REFUND, CLAIMED_REWARD, DUMMY_WALLET, GAME_POOL_OPEN, HUNDRED_PERCENT,
-    REWARDS_NOT_DISTRIBUTED, CONTRACT_POOL_COUNT, CONFIG, GAME_DETAILS, POOL_DETAILS, PoolDetails,
-    FeeDetails, PLATFORM_WALLET_PERCENTAGES,
-};
-use fury_native_bank_token::POOL_TEAM_DETAILS;
-
-use crate::contract::{CLAIMED_REFUND, CLAIMED_REWARD, DUMMY_WALLET, GAME_CANCELLED,
-                      GAME_COMPLETED, GAME_POOL_CLOSED, GAME_POOL_OPEN, HUNDRED_PERCENT,
+use crate::contract::{DUMMY_WALLET,
                       INITIAL_REFUND_AMOUNT, INITIAL_REWARD_AMOUNT, INITIAL_TEAM_POINTS,
                       INITIAL_TEAM_RANK, NINETY_NINE_NINE_PERCENT, REWARDS_DISTRIBUTED,
                       REWARDS_NOT_DISTRIBUTED, UNCLAIMED_REFUND, UNCLAIMED_REWARD};
 use crate::ContractError;
-use crate::msg::{BalanceResponse, ProxyQueryMsgs, QueryMsgSimulation, ReceivedMsg};
-use crate::query::{get_team_count_for_user_in_pool_type,
-                   query_pool_details, query_pool_type_details, query_swap_data_for_pool};
+use crate::fee_oracle::{FeeOracle, ProxyFeeOracle};
+use crate::msg::{ProxyExecuteMsg, QueryMsgSimulation};
+use crate::query::{is_pool_refundable, query_all_pools_in_game, query_pool_details};
 use crate::state::{CONFIG, CONTRACT_POOL_COUNT, CURRENT_REWARD_FOR_POOL, FeeDetails,
-                   GAME_DETAILS, GameDetails, GameResult, PLATFORM_WALLET_PERCENTAGES,
-                   POOL_DETAILS, POOL_TEAM_DETAILS, POOL_TYPE_DETAILS, PoolDetails, PoolTeamDetails,
-                   PoolTypeDetails, SWAP_BALANCE_INFO, SwapBalanceDetails,
-                   WalletPercentage, WalletTransferDetails};
+                   GameDetails, GameStatus, games, HUNDRED_PERCENT, PAID_RANKS_FOR_POOL, PayoutStructure,
+                   PLATFORM_WALLET_PERCENTAGES, POOL_TEAM_DETAILS, POOL_TYPE_DETAILS, pools,
+                   PoolDetails, PoolTeamDetails, PoolTypeDetails, SWAP_BALANCE_INFO, SwapBalanceDetails,
+                   TeamRanking, WalletPercentage, WalletTransferDetails};
 
 pub fn set_platform_fee_wallets(
     deps: DepsMut,
@@ -78,6 +54,7 @@ pub fn set_pool_type_params(
     max_teams_for_pool: u32,
     max_teams_for_gamer: u32,
     wallet_percentages: Vec<WalletPercentage>,
+    payout: PayoutStructure,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if info.sender != config.admin_address {
@@ -94,6 +71,7 @@ pub fn set_pool_type_params(
         }
         None => {}
     };
+    payout.validate(max_teams_for_pool)?;
 
     let mut rake_list: Vec<WalletPercentage> = Vec::new();
     for wp in wallet_percentages {
@@ -109,22 +87,85 @@ pub fn set_pool_type_params(
             max_teams_for_pool: max_teams_for_pool,
             max_teams_for_gamer: max_teams_for_gamer,
             rake_list: rake_list,
+            payout: payout,
         },
     )?;
     return Ok(Response::default());
 }
 
-pub fn cancel_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn create_game(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    lock_at: Timestamp,
+    start_at: Timestamp,
+    settle_deadline: Timestamp,
+    pool_types: Vec<String>,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if info.sender != config.admin_address {
         return Err(ContractError::Unauthorized {
             invoker: info.sender.to_string(),
         });
     }
-    let game_id = config.game_id;
+    if game_id.trim().is_empty() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game id can not be empty"),
+        }));
+    }
+    if games().may_load(deps.storage, game_id.clone())?.is_some() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game already exists"),
+        }));
+    }
+    if pool_types.is_empty() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game must offer at least one pool type"),
+        }));
+    }
+    if lock_at <= env.block.time {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Lock time must be in the future"),
+        }));
+    }
+    if start_at < lock_at || settle_deadline <= start_at {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game schedule must satisfy lock_at <= start_at < settle_deadline"),
+        }));
+    }
 
-    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
-    let game;
+    games().save(
+        deps.storage,
+        game_id.clone(),
+        &GameDetails {
+            game_id: game_id.clone(),
+            game_status: GameStatus::PoolOpen,
+            lock_at,
+            start_at,
+            settle_deadline,
+            pool_types,
+        },
+    )?;
+    return Ok(Response::new()
+        .add_attribute("game_id", game_id)
+        .add_attribute("game_status", "GAME_POOL_OPEN".to_string()));
+}
+
+pub fn cancel_game(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    game_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin_address {
+        return Err(ContractError::Unauthorized {
+            invoker: info.sender.to_string(),
+        });
+    }
+    let gd = games().may_load(deps.storage, game_id.clone())?;
+    let mut game;
     match gd {
         Some(gd) => {
             game = gd;
@@ -135,44 +176,26 @@ pub fn cancel_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Respon
             }));
         }
     }
-    if game.game_status == GAME_COMPLETED {
+    if game.game_status == GameStatus::Completed {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Cant cancel game as it is already over"),
         }));
     }
-    if game.game_status == GAME_CANCELLED {
+    if game.game_status == GameStatus::Cancelled {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Cant cancel game as it is already cancelled"),
         }));
     }
 
-    GAME_DETAILS.save(
-        deps.storage,
-        game_id.clone(),
-        &GameDetails {
-            game_id: game_id.clone(),
-            game_status: GAME_CANCELLED,
-        },
-    )?;
+    game.game_status = GameStatus::Cancelled;
+    games().save(deps.storage, game_id.clone(), &game)?;
 
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        let mut pool;
-        let pd = POOL_DETAILS.may_load(deps.storage, pool_id.clone())?;
-        match pd {
-            Some(pd) => {
-                pool = pd;
-            }
-            None => {
-                return Err(ContractError::Std(StdError::GenericErr {
-                    msg: String::from("No pool details found for pool"),
-                }));
-            }
-        };
+    // Get the pools of this game
+    let all_pools = query_all_pools_in_game(deps.storage, game_id.clone())?;
+    for mut pool in all_pools {
+        if pool.pool_reward_status {
+            continue;
+        }
         let pool_type;
         let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool.pool_type.clone())?;
         match ptd {
@@ -186,24 +209,27 @@ pub fn cancel_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Respon
             }
         };
         pool.pool_refund_status = true; // We skip the iteration and update the status
-        POOL_DETAILS.save(deps.storage, pool_id.clone(), &pool)?;
+        pools().save(deps.storage, pool.pool_id.clone(), &pool)?;
     }
     return Ok(Response::new()
         .add_attribute("game_id", game_id.clone())
         .add_attribute("game_status", "GAME_CANCELLED".to_string()));
 }
 
-pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn lock_game(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    game_id: String,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if info.sender != config.admin_address {
         return Err(ContractError::Unauthorized {
             invoker: info.sender.to_string(),
         });
     }
-    let game_id = config.game_id;
-
-    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
-    let game;
+    let gd = games().may_load(deps.storage, game_id.clone())?;
+    let mut game;
     match gd {
         Some(gd) => {
             game = gd;
@@ -214,40 +240,20 @@ pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
             }));
         }
     }
-    if game.game_status != GAME_POOL_OPEN {
+    // An open game past lock_at is already closed for bids, but locking it
+    // still flags the pools that did not fill up for refund
+    if game.game_status != GameStatus::PoolOpen {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Cant lock this game as it is not open for bidding"),
         }));
     }
 
-    GAME_DETAILS.save(
-        deps.storage,
-        game_id.clone(),
-        &GameDetails {
-            game_id: game_id.clone(),
-            game_status: GAME_POOL_CLOSED,
-        },
-    )?;
+    game.game_status = GameStatus::PoolClosed;
+    games().save(deps.storage, game_id.clone(), &game)?;
 
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        let mut pool;
-        let pd = POOL_DETAILS.may_load(deps.storage, pool_id.clone())?;
-
-        match pd {
-            Some(pd) => {
-                pool = pd;
-            }
-            None => {
-                return Err(ContractError::Std(StdError::GenericErr {
-                    msg: String::from("No pool details found for pool"),
-                }));
-            }
-        };
+    // Get the pools of this game
+    let all_pools = query_all_pools_in_game(deps.storage, game_id.clone())?;
+    for mut pool in all_pools {
         let pool_type;
         let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool.pool_type.clone())?;
         match ptd {
@@ -264,7 +270,7 @@ pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
             continue;
         }
         pool.pool_refund_status = true; // We skip the iteration and update the status
-        POOL_DETAILS.save(deps.storage, pool_id.clone(), &pool)?;
+        pools().save(deps.storage, pool.pool_id.clone(), &pool)?;
     }
     return Ok(Response::new()
         .add_attribute("game_id", game_id.clone())
@@ -272,8 +278,9 @@ pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
 }
 pub fn create_pool(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
+    game_id: String,
     pool_type: String,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -282,8 +289,7 @@ pub fn create_pool(
             invoker: info.sender.to_string(),
         });
     }
-    let game_id = config.game_id;
-    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let gd = games().may_load(deps.storage, game_id.clone())?;
     let game;
     match gd {
         Some(gd) => {
@@ -295,11 +301,16 @@ pub fn create_pool(
             }));
         }
     }
-    if game.game_status != GAME_POOL_OPEN {
+    if game.status_at(env.block.time) != GameStatus::PoolOpen {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Game is not open for bidding"),
         }));
     }
+    if !game.pool_types.contains(&pool_type) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool type is not offered in this game"),
+        }));
+    }
 
     let dummy_wallet = String::from(DUMMY_WALLET);
     let address = deps.api.addr_validate(dummy_wallet.clone().as_str())?;
@@ -324,7 +335,7 @@ pub fn create_pool(
     count += Uint128::from(1u128);
     let pool_id_str: String = count.to_string();
 
-    POOL_DETAILS.save(
+    pools().save(
         deps.storage,
         pool_id_str.clone(),
         &PoolDetails {
@@ -366,7 +377,6 @@ pub fn game_pool_bid_submit(
     pool_id: String,
     team_id: String,
     amount: Uint128,
-    testing: bool,
     max_spread: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     // Check if gamer is the same as invoker
@@ -379,9 +389,10 @@ pub fn game_pool_bid_submit(
     let config = CONFIG.load(deps.storage)?;
     // Calculate
     let platform_fee = config.platform_fee; // Should be in %
-    let game_id = config.clone().game_id;
+    let mut pool_details = query_pool_details(deps.storage, pool_id.clone())?;
+    let game_id = pool_details.game_id.clone();
     let mut messages = Vec::new(); // Use this to append any execute messages in the function
-    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let gd = games().may_load(deps.storage, game_id.clone())?;
     let game;
     match gd {
         Some(gd) => {
@@ -393,7 +404,7 @@ pub fn game_pool_bid_submit(
             }));
         }
     }
-    if game.game_status != GAME_POOL_OPEN {
+    if game.status_at(env.block.time) != GameStatus::PoolOpen {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Game is not open for bidding"),
         }));
@@ -411,62 +422,26 @@ pub fn game_pool_bid_submit(
             }));
         }
     }
-    let required_platform_fee_ust;
-    let transaction_fee;
-    match testing {
-        true => {
-            required_platform_fee_ust = Uint128::zero();
-            transaction_fee = Uint128::zero();
-        }
-        false => {
-            let fee_details = query_platform_fees(
-                pool_type_details.pool_fee,
-                platform_fee,
-                config.transaction_fee,
-            )?;
-            required_platform_fee_ust = fee_details.platform_fee;
-            transaction_fee = fee_details.transaction_fee;
-        }
-    }
+    let fee_details = query_platform_fees(
+        pool_type_details.pool_fee,
+        platform_fee,
+        config.transaction_fee,
+    )?;
+    let required_platform_fee_ust = fee_details.platform_fee;
+    let transaction_fee = fee_details.transaction_fee;
 
-    if !testing {
-        if info.funds.clone().len() != 1 {
-            return Err(ContractError::InvalidNumberOfCoinsSent {});
-        }
-        let mut asset: Asset = Asset {
-            info: AssetInfo::NativeToken {
-                denom: info.funds[0].denom.clone(),
-            },
-            amount: info.funds[0].amount,
-        };
-        let fund = info.funds.clone();
-        if fund[0].denom == native_usdc(&deps)?.as_str() {
-            if fund[0].amount >= required_platform_fee_ust.add(transaction_fee) {
-                asset = Asset {
-                    info: AssetInfo::NativeToken {
-                        denom: fund[0].denom.clone(),
-                    },
-                    amount: fund[0].amount,
-                };
-                println!("Asset {:?}", asset);
-            } else {
-                return Err(ContractError::InsufficientFeesUst {});
-            }
-        } else {
-            return Err(ContractError::InsufficientFeesUst {});
-        }
-        println!("Asset {:?}", asset);
+    // Fees are paid in USDC and the bid in fury, both kept by the contract
+    if funds_sent_in(&info, &config.usdc_denom) < required_platform_fee_ust.add(transaction_fee) {
+        return Err(ContractError::InsufficientFeesUsdc {});
     }
-
-    let mut pool_fee: Uint128 = pool_type_details.pool_fee;
-    if !testing {
-        pool_fee = deps.querier.query_wasm_smart(
-            native_usdc(&deps)?.as_str(),
-            &NativeBankQueryMsg::GetFuryEquivalent {
-                ust_count: pool_type_details.pool_fee,
-            },
-        )?;
+    if funds_sent_in(&info, &config.fury_denom) != amount {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Fury sent does not match the amount being bid"),
+        }));
     }
+
+    let fee_oracle = ProxyFeeOracle::new(deps.querier, config.astro_proxy_address.to_string());
+    let pool_fee = fee_oracle.fury_equivalent_to_usdc(pool_type_details.pool_fee)?;
     let max_teams_for_pool = pool_type_details.max_teams_for_pool;
     let max_teams_for_gamer = pool_type_details.max_teams_for_gamer;
     let amount_required = pool_fee
@@ -479,7 +454,7 @@ pub fn game_pool_bid_submit(
     }
     let mut user_team_count = 0;
     // Here we load the details based on the user placing the bid
-    let ptd = POOL_TEAM_DETAILS.may_load(deps.storage, (&pool_id.clone(), &gamer))?;
+    let ptd = POOL_TEAM_DETAILS.may_load(deps.storage, (&game_id, &pool_id, &gamer))?;
     match ptd {
         Some(std) => {
             let all_teams = std;
@@ -498,13 +473,12 @@ pub fn game_pool_bid_submit(
     }
 
     let pool_id_return;
-    let mut pool_details = query_pool_details(deps.storage, pool_id.clone())?;
 
     // check if the pool can accommodate the team
     if pool_details.current_teams_count < max_teams_for_pool {
         pool_id_return = pool_id.clone();
         pool_details.current_teams_count += 1;
-        POOL_DETAILS.save(
+        pools().save(
             deps.storage,
             pool_id.clone(),
             &PoolDetails {
@@ -539,56 +513,37 @@ pub fn game_pool_bid_submit(
         }));
     }
 
-    // Sending Fury token to the contract
-    let transfer_msg = NativeBankExecuteMsg::Send {
-        to_address: env.contract.address.into_string(),
-        amount: vec![Coin {
-            denom: native_fury_token(&deps)?.as_str(),
-            amount,
-        }],
-    };
-    messages.push(CosmosMsg::Bank(BankMsg::Send {
-        to: native_fury_bank(&deps)?.into(),
-        amount: vec![Coin {
-            denom: native_fury_token(&deps)?.as_str(),
+    // The bid is swapped into USDC, in which the pool collection is kept
+    let swap_message = ProxyExecuteMsg::Swap {
+        offer_asset: Asset {
+            info: AssetInfo::NativeToken {
+                denom: config.fury_denom.clone(),
+            },
             amount,
-        }],
-    }));
-
-    let increase_allowance_msg = NativeBankExecuteMsg::IncreaseAllowance {
-        spender: native_fury_bank(&deps)?.into(),
-        amount,
-    };
-    messages.push(CosmosMsg::Bank(BankMsg::ExecuteMsg {
-        msg: to_binary(&increase_allowance_msg).unwrap(),
-        funds: vec![],
-    }));
-
-    let fury_asset_info = Asset {
-        info: AssetInfo::Token {
-            contract_addr: native_fury_bank(&deps)?.to_string(),
         },
-        amount,
-    };
-    let swap_message = FurySwapExecuteMsg::Swap {
-        offer_asset: fury_asset_info,
         belief_price: None,
         max_spread,
-        to: Option::from(env.contract.address.to_string()),
+        to: Some(env.contract.address.to_string()),
     };
-    let platform_fees_for_swap = deps.querier.query_wasm_smart(
-        native_fury_token(&deps)?.as_str(),
+    let platform_fees_for_swap: Uint128 = deps.querier.query_wasm_smart(
+        config.swap_contract_address.to_string(),
         &QueryMsgSimulation::QueryPlatformFees {
             msg: to_binary(&swap_message)?,
         },
     )?;
     messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: native_fury_token(&deps)?.to_string(),
-        msg: to_binary(&swap_message).unwrap(),
-        funds: vec![Coin {
-            denom: native_usdc(&deps)?.as_str(),
-            amount: platform_fees_for_swap,
-        }],
+        contract_addr: config.swap_contract_address.to_string(),
+        msg: to_binary(&swap_message)?,
+        funds: bank_funds(vec![
+            Coin {
+                denom: config.fury_denom.clone(),
+                amount,
+            },
+            Coin {
+                denom: config.usdc_denom.clone(),
+                amount: platform_fees_for_swap,
+            },
+        ]),
     }));
     return Ok(Response::new()
         .add_attribute("pool_id", pool_id_return.clone())
@@ -612,7 +567,7 @@ pub fn save_team_details(
 ) -> Result<Response, ContractError> {
     // Get the existing teams for this pool
     let mut teams = Vec::new();
-    let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&pool_id.clone(), gamer.clone().as_ref()))?;
+    let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&game_id, &pool_id, &gamer))?;
     match all_teams {
         Some(some_teams) => {
             teams = some_teams;
@@ -633,7 +588,7 @@ pub fn save_team_details(
         team_points,
         team_rank,
     });
-    POOL_TEAM_DETAILS.save(storage, (&pool_id.clone(), gamer.as_ref()), &teams)?;
+    POOL_TEAM_DETAILS.save(storage, (&game_id, &pool_id, &gamer), &teams)?;
 
     return Ok(Response::new().add_attribute("team_id", team_id.clone()));
 }
@@ -646,6 +601,7 @@ pub fn claim_reward(
     deps: DepsMut,
     info: MessageInfo,
     gamer: String,
+    game_id: String,
     env: Env,
 ) -> Result<Response, ContractError> {
     let gamer_addr = deps.api.addr_validate(&gamer)?;
@@ -657,26 +613,16 @@ pub fn claim_reward(
     }
 
     let mut user_reward = Uint128::zero();
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
+    // Get the pools of this game
+    let all_pools = query_all_pools_in_game(deps.storage, game_id.clone())?;
+    for pool_details in all_pools {
+        let pool_id = pool_details.pool_id.clone();
         // Get the existing teams for this pool
-        let mut pool_details: PoolDetails = Default::default();
-        let pd = POOL_DETAILS.load(deps.storage, pool_id.clone());
-        match pd {
-            Ok(some) => { pool_details = some; }
-            Err(_) => {
-                continue;
-            }
-        }
         if !pool_details.pool_reward_status {
             continue;
         }
         let mut pool_team_details;
-        match POOL_TEAM_DETAILS.load(deps.storage, (&*pool_id.clone(), &gamer.clone())) {
+        match POOL_TEAM_DETAILS.load(deps.storage, (&pool_details.game_id, &pool_id, &gamer)) {
             Ok(some) => { pool_team_details = some; }
             Err(_) => {
                 continue;
@@ -684,7 +630,8 @@ pub fn claim_reward(
         }
         let mut updated_details = Vec::new();
         for team_details in pool_team_details {
-            if !team_details.claimed_reward {
+            // Teams not ranked yet stay unclaimed for the later batches
+            if !team_details.claimed_reward && !team_details.reward_amount.is_zero() {
                 let mut updated_team = team_details.clone();
                 user_reward += team_details.reward_amount;
                 updated_team.claimed_reward = true;
@@ -694,7 +641,7 @@ pub fn claim_reward(
             }
         }
         if !updated_details.is_empty() {
-            POOL_TEAM_DETAILS.save(deps.storage, (&*pool_id, &gamer.clone()), &updated_details)?
+            POOL_TEAM_DETAILS.save(deps.storage, (&pool_details.game_id, &pool_id, &gamer), &updated_details)?
         }
     }
 
@@ -707,38 +654,23 @@ pub fn claim_reward(
     // Do the transfer of reward to the actual gamer_addr from the contract
     let config = CONFIG.load(deps.storage)?;
     let mut messages = Vec::new();
-    let user_reward_in_usdc = deps.querier.query_wasm_smart(
-        config.clone().your_usdc_token_contract_address, // Replace with the actual contract address of your native USDC token
-        &YourUsdcQueryMsg::get_usdc_equivalent {
-            amount: user_reward,
-        },
-    )?;
+    let fee_oracle = ProxyFeeOracle::new(deps.querier, config.astro_proxy_address.to_string());
+    let user_reward_in_usdc = fee_oracle.usdc_equivalent_to_fury(user_reward)?;
     let fee_details = query_platform_fees(user_reward_in_usdc, config.platform_fee, config.transaction_fee)?;
-    // We only take the first coin object since we only expect USDC here
-    let funds_sent;
-    if info.funds.len() != 0 {
-        funds_sent = info.funds[0].clone();
-        if (funds_sent.denom != your_usdc_denom()) || (funds_sent.amount < fee_details.platform_fee.add(fee_details.transaction_fee)) {
-            return Err(ContractError::InsufficientFeesUsdc {});
-        }
-    } else {
+    if funds_sent_in(&info, &config.usdc_denom) < fee_details.platform_fee.add(fee_details.transaction_fee) {
         return Err(ContractError::InsufficientFeesUsdc {});
     }
 
-    let r = CosmosMsg::Bank(BankMsg::Send {
+    messages.push(CosmosMsg::Bank(BankMsg::Send {
         to_address: config.platform_fees_collector_wallet.to_string(),
         amount: info.funds,
-    });
-    messages.push(r);
-
-    let transfer_msg = Cw20ExecuteMsg::Transfer {
-        recipient: info.sender.into_string(),
-        amount: user_reward,
-    };
-    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: config.your_usdc_token_contract_address.to_string(), // Replace with the actual contract address of your native USDC token
-        msg: to_binary(&transfer_msg)?,
-        funds: vec![],
+    }));
+    messages.push(CosmosMsg::Bank(BankMsg::Send {
+        to_address: info.sender.into_string(),
+        amount: vec![Coin {
+            denom: config.fury_denom.clone(),
+            amount: user_reward,
+        }],
     }));
     return Ok(Response::new()
         .add_attribute("amount", user_reward.to_string())
@@ -757,11 +689,10 @@ pub fn claim_refund(
     deps: DepsMut,
     info: MessageInfo,
     gamer: String,
+    game_id: String,
     env: Env,
-    testing: Option<bool>,
     max_spread: Option<Decimal>,
 ) -> Result<Response, ContractError> {
-    let testing_status = testing.unwrap_or(false);
     let mut refund_in_usd_fees = Uint128::default();
     let gamer_addr = deps.api.addr_validate(&gamer)?;
     // Check if withdrawer is the same as the invoker
@@ -771,28 +702,37 @@ pub fn claim_refund(
         });
     }
     let config = CONFIG.load(deps.storage)?;
-    // Get all pools
-
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
+    // Get the pools of this game
+    let all_pools = query_all_pools_in_game(deps.storage, game_id.clone())?;
     let mut total_refund_amount = Uint128::zero();
-    for pool_id in all_pools {
-        let mut pool_details: PoolDetails = Default::default();
-        let pd = POOL_DETAILS.load(deps.storage, pool_id.clone());
-        match pd {
-            Ok(some) => { pool_details = some; }
-            Err(_) => {
-                continue;
-            }
-        }
-        if !pool_details.pool_refund_status {
+    let mut usdc_refund_amount = Uint128::zero();
+    let mut fury_refund_amount = Uint128::zero();
+    let mut refund_already_claimed = false;
+    for pool_details in all_pools {
+        let pool_id = pool_details.pool_id.clone();
+        if !is_pool_refundable(deps.storage, &pool_details, env.block.time)? {
             continue;
         }
         let pool_type = POOL_TYPE_DETAILS.load(deps.storage, pool_details.pool_type)?;
         let refund_amount = pool_type.pool_fee;
-        let pool_team_details = POOL_TEAM_DETAILS.load(deps.storage, (pool_id.as_ref(), &gamer.clone()));
+        // Once swapped, the pool escrows the fury bought and the USDC left for the rake
+        // instead of the pool fees, each team gets back its share of both
+        let team_refund_in_usdc;
+        let team_refund_in_fury;
+        match SWAP_BALANCE_INFO.may_load(deps.storage, pool_id.clone())? {
+            Some(swap_info) => {
+                let teams_in_pool = pool_details.current_teams_count.max(1) as u128;
+                team_refund_in_usdc = swap_info.usdc_for_rake.multiply_ratio(1u128, teams_in_pool);
+                team_refund_in_fury = swap_info
+                    .usdc_amount_swapped
+                    .multiply_ratio(swap_info.exchange_rate, HUNDRED_PERCENT * teams_in_pool);
+            }
+            None => {
+                team_refund_in_usdc = refund_amount;
+                team_refund_in_fury = Uint128::zero();
+            }
+        }
+        let pool_team_details = POOL_TEAM_DETAILS.load(deps.storage, (&pool_details.game_id, &pool_id, &gamer));
         match pool_team_details {
             Ok(some) => {
                 let mut updated_details = Vec::new();
@@ -801,14 +741,17 @@ pub fn claim_refund(
                         let mut updated_team = team_details.clone();
                         updated_team.refund_amount = refund_amount;
                         total_refund_amount += refund_amount;
+                        usdc_refund_amount += team_refund_in_usdc;
+                        fury_refund_amount += team_refund_in_fury;
                         updated_team.claimed_refund = true;
                         updated_details.push(updated_team);
                     } else {
-                        return Err(ContractError::RefundAlreadyClaimed {});
+                        refund_already_claimed = true;
+                        updated_details.push(team_details);
                     }
                 }
                 if !updated_details.is_empty() {
-                    POOL_TEAM_DETAILS.save(deps.storage, (pool_id.as_ref(), &gamer.clone()), &updated_details)?
+                    POOL_TEAM_DETAILS.save(deps.storage, (&pool_details.game_id, &pool_id, &gamer), &updated_details)?
                 }
             }
             Err(_) => {
@@ -818,6 +761,9 @@ pub fn claim_refund(
     }
 
     if total_refund_amount == Uint128::zero() {
+        if refund_already_claimed {
+            return Err(ContractError::RefundAlreadyClaimed {});
+        }
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("No refund for this user"),
         }));
@@ -826,64 +772,73 @@ pub fn claim_refund(
     refund_in_usd_fees = refund_details.transaction_fee.add(refund_details.platform_fee);
     // Do the transfer of refund to the actual gamer_addr from the contract
     let mut messages = Vec::new();
-    let usd_asset = Asset {
-        info: AssetInfo::NativeToken {
-            denom: config.usdc_ibc_symbol.clone()
-        },
-        amount: total_refund_amount,
-    };
-    let swap_message = AstroPortExecute::Swap {
-        offer_asset: usd_asset.clone(),
-        belief_price: None,
-        max_spread: max_spread,
-        to: Option::from(info.sender.to_string()),
-    };
+    let mut final_amount = Uint128::zero();
+    if !usdc_refund_amount.is_zero() {
+        let usd_asset = Asset {
+            info: AssetInfo::NativeToken {
+                denom: config.usdc_denom.clone()
+            },
+            amount: usdc_refund_amount,
+        };
+        let swap_message = ProxyExecuteMsg::Swap {
+            offer_asset: usd_asset.clone(),
+            belief_price: None,
+            max_spread: max_spread,
+            to: Option::from(info.sender.to_string()),
+        };
 
-    let mut swap_fee = Uint128::zero();
-    // Swap fee should be platform + transaction fee for the transaction
-    if !testing_status {
-        swap_fee = deps.querier.query_wasm_smart(
-            config.clone().furyswap_factory_contract_address, // Replace with the actual contract address of Furyswap factory
+        // Swap fee should be platform + transaction fee for the transaction
+        let swap_fee: Uint128 = deps.querier.query_wasm_smart(
+            config.swap_contract_address.to_string(),
             &QueryMsgSimulation::QueryPlatformFees {
                 msg: to_binary(&swap_message)?
             },
         )?;
+        final_amount = usd_asset.amount.clone().add(swap_fee);
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.swap_contract_address.to_string(),
+            msg: to_binary(&swap_message)?,
+            funds: vec![Coin {
+                denom: config.usdc_denom.clone(),
+                amount: final_amount,
+            }],
+        }));
     }
-    let final_amount = usd_asset.amount.clone().add(swap_fee);
-    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: config.furyswap_factory_contract_address.to_string(), // Replace with the actual contract address of Furyswap factory
-        msg: to_binary(&swap_message)?,
-        funds: vec![Coin {
-            denom: config.usdc_ibc_symbol.clone(),
-            amount: final_amount,
-        }],
-    }));
-    let refund = Coin {
-        denom: config.usdc_ibc_symbol.clone(),
+    if !fury_refund_amount.is_zero() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: config.fury_denom.clone(),
+                amount: fury_refund_amount,
+            }],
+        }));
+    }
+    let refund = bank_funds(vec![Coin {
+        denom: config.usdc_denom.clone(),
         amount: refund_in_usd_fees,
-    };
-    let mut refund_: Vec<Coin> = vec![];
-    refund_.push(refund);
-    messages.push(CosmosMsg::Bank(BankMsg::Send {
-        to_address: String::from(info.sender),
-        amount: refund_,
-    }));
+    }]);
+    if !refund.is_empty() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from(info.sender),
+            amount: refund,
+        }));
+    }
     return Ok(Response::new()
         .add_attribute("amount", final_amount.to_string())
         .add_attribute("action", "refund")
+        .add_attribute("fury_amount", fury_refund_amount.to_string())
         .add_messages(messages)
     );
 }
 pub fn game_pool_reward_distribute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     game_id: String,
     pool_id: String,
-    game_winners: Vec<GameResult>,
+    ranking: Vec<TeamRanking>,
     is_final_batch: bool,
-    testing: bool,
-    ust_for_rake: Uint128,
+    usdc_for_rake: Uint128,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if info.sender != config.admin_address {
@@ -891,12 +846,8 @@ pub fn game_pool_reward_distribute(
             invoker: info.sender.to_string(),
         });
     }
-    let platform_fee_in_percentage = config.platform_fee;
-    let platform_fee;
-    let game_id = config.game_id.clone();
-
-    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
-    let game;
+    let gd = games().may_load(deps.storage, game_id.clone())?;
+    let mut game;
     match gd {
         Some(gd) => {
             game = gd;
@@ -907,63 +858,50 @@ pub fn game_pool_reward_distribute(
             }));
         }
     }
-    if game.game_status == GAME_CANCELLED {
-        return Err(ContractError::Std(StdError::GenericErr {
-            msg: String::from("Rewards can't be distributed as the game is cancelled"),
-        }));
-    }
-    if game.game_status == GAME_POOL_OPEN {
-        return Err(ContractError::Std(StdError::GenericErr {
-            msg: String::from("Rewards can't be distributed as the game has not yet started"),
-        }));
-    }
     let reward_status;
-    let game_status;
     let pool_status_string;
-    let reward_status_string;
     if is_final_batch {
         reward_status = true;
-        game_status = GAME_COMPLETED;
-        reward_status_string = "GAME_COMPLETED";
         pool_status_string = "POOL_REWARD_DISTRIBUTED";
     } else {
-        reward_status_string = "GAME_NOT_COMPLETED";
         pool_status_string = "POOL_REWARD_DISTRIBUTED_INCOMPLETE";
         reward_status = false;
-        game_status = GAME_POOL_CLOSED;
     }
-    GAME_DETAILS.save(
-        deps.storage,
-        game_id.clone(),
-        &GameDetails {
-            game_id: game_id.clone(),
-            game_status: game_status,
-        },
-    )?;
-
     let pool_details = query_pool_details(deps.storage, pool_id.clone())?;
+    if pool_details.game_id != game_id {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool does not belong to this game"),
+        }));
+    }
+    if pool_details.pool_refund_status {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rewards can't be distributed as the pool is refunded"),
+        }));
+    }
+    // A pool that already paid out a batch is kept out of refunds, so it
+    // can still finish settling once the game is cancelled or expired
+    let current_status = game.status_at(env.block.time);
+    if current_status == GameStatus::Cancelled && !pool_details.pool_reward_status {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rewards can't be distributed as the game is cancelled"),
+        }));
+    }
+    if current_status == GameStatus::PoolOpen {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rewards cant be distributed as game not yet started"),
+        }));
+    }
+    if game.settlement_missed(env.block.time) && !pool_details.pool_reward_status {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rewards can't be distributed as the settle deadline has passed"),
+        }));
+    }
     if pool_details.rewards_distributed == REWARDS_DISTRIBUTED {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Rewards are already distributed for this pool"),
         }));
     }
-    let pool_count = pool_details.current_teams_count;
-    let pool_type = pool_details.pool_type;
-
-
-    POOL_DETAILS.save(
-        deps.storage,
-        pool_id.clone(),
-        &PoolDetails {
-            game_id: game_id.clone(),
-            pool_id: pool_id.clone(),
-            pool_type: pool_type.clone(),
-            current_teams_count: pool_details.current_teams_count,
-            rewards_distributed: reward_status,
-            pool_refund_status: false,
-            pool_reward_status: true,
-        },
-    )?;
+    let pool_type = pool_details.pool_type.clone();
 
     let pool_type_details;
     let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool_type.clone())?;
@@ -977,76 +915,120 @@ pub fn game_pool_reward_distribute(
             }));
         }
     }
-    platform_fee = query_platform_fees(pool_type_details.pool_fee, platform_fee_in_percentage, config.transaction_fee.clone())?.platform_fee;
-
-    let pool_fee: Uint128 = pool_type_details.pool_fee;
-
 
-    let mut winner_rewards = Uint128::zero();
-    let winners = game_winners.clone();
-    for winner in winners {
-        winner_rewards += winner.reward_amount;
+    // The pool collection is escrowed as the fury bought by the admin swap
+    // and the USDC left over from it, out of which the rake is paid
+    let swap_info;
+    match SWAP_BALANCE_INFO.may_load(deps.storage, pool_id.clone())? {
+        Some(some) => {
+            swap_info = some;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Pool collection has not been swapped yet"),
+            }));
+        }
     }
+    if usdc_for_rake > swap_info.usdc_for_rake {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rake exceeds the USDC left in the pool"),
+        }));
+    }
+    let prize_pool = swap_info
+        .usdc_amount_swapped
+        .multiply_ratio(swap_info.exchange_rate, HUNDRED_PERCENT);
+    let payouts = compute_payouts(
+        &pool_type_details.payout.rank_shares(pool_details.current_teams_count),
+        prize_pool,
+        &ranking,
+        pool_details.current_teams_count,
+    )?;
+    let paid_ranks = record_paid_ranks(
+        PAID_RANKS_FOR_POOL.may_load(deps.storage, pool_id.clone())?.unwrap_or_default(),
+        &ranking,
+    )?;
 
-    let mut wallet_transfer_details: Vec<WalletTransferDetails> = Vec::new();
-
+    // Rank the teams, checking that each ranked team is held by the gamer in this pool
+    let mut gamers: Vec<String> = ranking.iter().map(|r| r.gamer_address.clone()).collect();
+    gamers.sort();
+    gamers.dedup();
     let mut reward_given_so_far = Uint128::zero();
-    let mut all_teams: Vec<PoolTeamDetails> = Vec::new();
-    for winner in game_winners.clone().into_iter() {
-        let ptd = POOL_TEAM_DETAILS.may_load(deps.storage, (&pool_id.clone(), winner.gamer_address.as_ref()))?;
-        match ptd {
-            Some(ptd) => {
-                all_teams = ptd;
-            }
-            None => {
+    let mut ranked_teams: Vec<(String, Vec<PoolTeamDetails>)> = Vec::new();
+    for gamer in gamers {
+        let mut teams = POOL_TEAM_DETAILS
+            .may_load(deps.storage, (&game_id, &pool_id, &gamer))?
+            .unwrap_or_default();
+        for (entry, payout) in ranking.iter().zip(payouts.iter()) {
+            if entry.gamer_address != gamer {
                 continue;
             }
-        }
-        let mut updated_teams: Vec<PoolTeamDetails> = Vec::new();
-        for team in &all_teams {
-            // No transfer to be done to the winners. Just update their reward amounts.
-            // They have to come and collect their rewards
-            let mut updated_team = team.clone();
-            let winners = game_winners.clone();
-            for winner in winners {
-                if team.gamer_address == winner.gamer_address
-                    && team.team_id == winner.team_id
-                    && team.game_id == game_id.clone()
-                {
-                    updated_team.reward_amount = winner.reward_amount;
-                    reward_given_so_far += winner.reward_amount;
-                    println!(
-                        "reward for {:?} is {:?}",
-                        team.team_id, updated_team.reward_amount
-                    );
+            let team = teams
+                .iter_mut()
+                .find(|t| t.team_id == entry.team_id && t.team_rank == INITIAL_TEAM_RANK);
+            match team {
+                Some(team) => {
+                    // No transfer to be done to the winners. Just update their reward amounts.
+                    // They have to come and collect their rewards
+                    team.team_points = entry.team_points;
+                    team.team_rank = entry.team_rank;
+                    team.reward_amount = *payout;
+                    reward_given_so_far += *payout;
+                }
+                None => {
+                    return Err(ContractError::Std(StdError::GenericErr {
+                        msg: format!("Team {} of {} is not in this pool or already ranked", entry.team_id, gamer),
+                    }));
                 }
             }
-            updated_teams.push(updated_team);
-        }
-        POOL_TEAM_DETAILS.save(deps.storage, (&pool_id.clone(), winner.gamer_address.as_ref()), &updated_teams)?;
-    }
-    let current_reward = CURRENT_REWARD_FOR_POOL.load(deps.storage, pool_id.clone());
-    let reward_total;
-    match current_reward {
-        Ok(some) => {
-            let total_current = some.add(reward_given_so_far.clone());
-            CURRENT_REWARD_FOR_POOL.save(deps.storage, pool_id.clone(), &total_current)?;
-            reward_total = total_current;
-        }
-        Err(_) => {
-            reward_total = reward_given_so_far;
-            CURRENT_REWARD_FOR_POOL.save(deps.storage, pool_id.clone(), &reward_given_so_far)?;
         }
+        ranked_teams.push((gamer, teams));
+    }
+    let reward_total = CURRENT_REWARD_FOR_POOL
+        .may_load(deps.storage, pool_id.clone())?
+        .unwrap_or_default()
+        .checked_add(reward_given_so_far)
+        .map_err(StdError::overflow)?;
+    if reward_total > prize_pool {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rewards exceed the escrowed balance of the pool"),
+        }));
+    }
+
+    pools().save(
+        deps.storage,
+        pool_id.clone(),
+        &PoolDetails {
+            game_id: game_id.clone(),
+            pool_id: pool_id.clone(),
+            pool_type: pool_type.clone(),
+            current_teams_count: pool_details.current_teams_count,
+            rewards_distributed: reward_status,
+            pool_refund_status: false,
+            pool_reward_status: true,
+        },
+    )?;
+    for (gamer, teams) in ranked_teams {
+        POOL_TEAM_DETAILS.save(deps.storage, (&game_id, &pool_id, &gamer), &teams)?;
+    }
+    CURRENT_REWARD_FOR_POOL.save(deps.storage, pool_id.clone(), &reward_total)?;
+    PAID_RANKS_FOR_POOL.save(deps.storage, pool_id.clone(), &paid_ranks)?;
+    // Settlement is tracked per pool, the game completes once its last pool is settled
+    let mut reward_status_string = "GAME_NOT_COMPLETED";
+    if is_final_batch && game.game_status != GameStatus::Cancelled && all_pools_settled(deps.storage, &game_id)? {
+        game.game_status = GameStatus::Completed;
+        games().save(deps.storage, game_id.clone(), &game)?;
+        reward_status_string = "GAME_COMPLETED";
     }
     // let mut swap_info = query_swap_data_for_pool(deps.storage, "1".to_string().clone())?;
+    let mut wallet_transfer_details: Vec<WalletTransferDetails> = Vec::new();
     let rsp;
     // Transfer rake_amount to all the rake wallets. Can also be only one rake wallet
     if is_final_batch {
-        // Only when we are on the final batch and UST for rake is not zero we perform this
-        if !ust_for_rake.is_zero() {
+        // Only when we are on the final batch and USDC for rake is not zero we perform this
+        if !usdc_for_rake.is_zero() {
             for wallet in pool_type_details.rake_list {
                 let wallet_address = wallet.wallet_address;
-                let rake_amount = ust_for_rake;
+                let rake_amount = usdc_for_rake;
                 let proportionate_amount = rake_amount
                     .checked_mul(Uint128::from(wallet.percentage))
                     .unwrap_or_default()
@@ -1063,7 +1045,6 @@ pub fn game_pool_reward_distribute(
                 wallet_transfer_details,
                 "rake_and_platform_fee".to_string(),
                 deps,
-                testing,
             )?;
         } else {
             rsp = Response::new();
@@ -1077,20 +1058,78 @@ pub fn game_pool_reward_distribute(
         .add_attribute("pool_status", pool_status_string.to_string())
         .add_attribute("pool_id", pool_id.clone()));
 }
+/// Whether every pool in the game is either rewarded or refunded
+fn all_pools_settled(storage: &dyn Storage, game_id: &str) -> StdResult<bool> {
+    for item in pools().idx.game.prefix(game_id.as_bytes().to_vec()).range(storage, None, None, Order::Ascending) {
+        let (_, pool) = item?;
+        if !pool.pool_refund_status && pool.rewards_distributed != REWARDS_DISTRIBUTED {
+            return Ok(false);
+        }
+    }
+    return Ok(true);
+}
+
+/// Adds the ranks paid by this batch to the ranks paid so far. A rank is
+/// paid once, and teams tied on a rank also take the ranks below it, so
+/// ties must be settled in the same batch.
+pub fn record_paid_ranks(mut paid_ranks: Vec<u64>, ranking: &[TeamRanking]) -> Result<Vec<u64>, ContractError> {
+    let mut batch_ranks: Vec<u64> = ranking.iter().map(|r| r.team_rank).collect();
+    batch_ranks.sort();
+    batch_ranks.dedup();
+    for team_rank in batch_ranks {
+        let tied = ranking.iter().filter(|r| r.team_rank == team_rank).count() as u64;
+        for rank in team_rank..team_rank + tied {
+            if paid_ranks.contains(&rank) {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: format!("Rank {} is already paid in this pool", rank),
+                }));
+            }
+            paid_ranks.push(rank);
+        }
+    }
+    return Ok(paid_ranks);
+}
+
+/// Rewards of the ranked teams from the shares of each rank. Teams tied
+/// on a rank split the prizes of the ranks they cover.
+pub fn compute_payouts(
+    rank_shares: &[u32],
+    prize_pool: Uint128,
+    ranking: &[TeamRanking],
+    teams_in_pool: u32,
+) -> Result<Vec<Uint128>, ContractError> {
+    let mut payouts = Vec::new();
+    for entry in ranking {
+        if entry.team_rank == 0 || entry.team_rank > teams_in_pool as u64 {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Team rank is out of range for this pool"),
+            }));
+        }
+        let tied = ranking.iter().filter(|r| r.team_rank == entry.team_rank).count();
+        let share: u128 = rank_shares
+            .iter()
+            .skip((entry.team_rank - 1) as usize)
+            .take(tied)
+            .map(|share| *share as u128)
+            .sum();
+        payouts.push(prize_pool.multiply_ratio(share, HUNDRED_PERCENT * tied as u128));
+    }
+    return Ok(payouts);
+}
+
 pub fn _transfer_to_multiple_wallets(
     wallet_details: Vec<WalletTransferDetails>,
     action: String,
     deps: DepsMut,
-    testing: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let mut rsp = Response::new();
-    if testing {
-        return Ok(rsp);
-    }
     for wallet in wallet_details {
-        let mut funds_to_send = vec![Coin {
-            denom: config.usdc_ibc_symbol.clone(),
+        if wallet.amount.is_zero() {
+            continue;
+        }
+        let funds_to_send = vec![Coin {
+            denom: config.usdc_denom.clone(),
             amount: wallet.amount,
         }];
         let transfer_msg = CosmosMsg::Bank(BankMsg::Send {
@@ -1129,40 +1168,36 @@ pub fn swap(
     } else {
         funds_for_rake = total_collection_in_pool - amount;
     }
-    let current_fury_balance: BalanceResponse = deps.querier.query_wasm_smart(
-        config.clone().native_bank_token,
-        &Cw20QueryMsg::Balance {
-            address: env.contract.address.clone().to_string()
-        },
-    )?;
+    let current_fury_balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), config.fury_denom.clone())?;
     let mut swap_info;
     match SWAP_BALANCE_INFO.load(deps.storage, pool_id.clone()) {
-        Ok(mut swap) => {
+        Ok(swap) => {
             swap_info = swap;
-            swap_info.ust_amount_swapped = amount;
-            swap_info.ust_for_rake = funds_for_rake;
+            swap_info.usdc_amount_swapped = amount;
+            swap_info.usdc_for_rake = funds_for_rake;
         }
         Err(_) => {
             swap_info = SwapBalanceDetails {
                 balance_pre_swap: Default::default(),
                 balance_post_swap: Default::default(),
                 exchange_rate: Default::default(),
-                ust_amount_swapped: amount.clone(),
-                ust_for_rake: funds_for_rake,
+                usdc_amount_swapped: amount.clone(),
+                usdc_for_rake: funds_for_rake,
             }
         }
     }
-    swap_info.balance_pre_swap = current_fury_balance.balance;
+    swap_info.balance_pre_swap = current_fury_balance.amount;
     SWAP_BALANCE_INFO.save(deps.storage, pool_id.clone(), &swap_info)?;
-    let ust_asset = Asset {
+    let usdc_asset = Asset {
         info: AssetInfo::NativeToken {
-            denom: config.usdc_ibc_symbol.clone()
+            denom: config.usdc_denom.clone()
         },
         amount,
     };
-    //let tax = ust_asset.compute_tax(&deps.querier)?;
-    let swap_message = AstroPortExecute::Swap {
-        offer_asset: ust_asset.clone(),
+    let swap_message = ProxyExecuteMsg::Swap {
+        offer_asset: usdc_asset.clone(),
         belief_price: None,
         max_spread: max_spread,
         to: Option::from(env.contract.address.to_string()),
@@ -1170,26 +1205,25 @@ pub fn swap(
 
     // Swap fee should be platform+transaction fee for the transaction
     let swap_fee: Uint128 = deps.querier.query_wasm_smart(
-        config.clone().furyswap,
+        config.swap_contract_address.to_string(),
         &QueryMsgSimulation::QueryPlatformFees {
             msg: to_binary(&swap_message)?
         },
     )?;
-    //let final_amount = ust_asset.amount.clone().add(swap_fee).add(tax);
-    let final_amount = ust_asset.amount.clone().add(swap_fee);
+    let final_amount = usdc_asset.amount.clone().add(swap_fee);
 
     let submsg = SubMsg::reply_on_success(
         CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config.furyswap.to_string(),
+            contract_addr: config.swap_contract_address.to_string(),
             msg: to_binary(&swap_message)?,
             funds: vec![Coin {
-                denom: config.usdc_ibc_symbol.clone(),
+                denom: config.usdc_denom.clone(),
                 amount: final_amount,
             }],
         }),
         pool_id.parse::<u64>().unwrap(),
     );
-    return Ok(Response::new().add_submessage(submsg).add_attribute("fury_balance_pre_swap", current_fury_balance.balance.to_string()));
+    return Ok(Response::new().add_submessage(submsg).add_attribute("fury_balance_pre_swap", current_fury_balance.amount.to_string()));
 }
 
 pub fn execute_sweep(
@@ -1213,10 +1247,18 @@ pub fn execute_sweep(
         .add_attribute("action", "execute_sweep"))
 }
 
-pub fn uusd(
-    deps: DepsMut,
-) -> Result<String, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    // Replace the following line based on your Cosmos SDK chain
-    Ok(config.native_usdc_symbol.to_string())
-}
\ No newline at end of file
+/// Total amount of the given denom sent along with the message
+pub fn funds_sent_in(info: &MessageInfo, denom: &str) -> Uint128 {
+    info.funds
+        .iter()
+        .filter(|coin| coin.denom == denom)
+        .map(|coin| coin.amount)
+        .sum()
+}
+
+/// Drops zero amounts and sorts by denom, as the bank module expects
+pub fn bank_funds(mut funds: Vec<Coin>) -> Vec<Coin> {
+    funds.retain(|coin| !coin.amount.is_zero());
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+    funds
+}
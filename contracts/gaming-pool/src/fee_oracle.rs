@@ -0,0 +1,125 @@
+use cosmwasm_std::{QuerierWrapper, StdResult, Uint128};
+
+use crate::msg::ProxyQueryMsgs;
+
+/// Prices the stable denom in fury so that pool fees can be charged
+pub trait FeeOracle {
+    /// Returns the Fury equivalent for some USDC amount
+    fn fury_equivalent_to_usdc(&self, usdc_count: Uint128) -> StdResult<Uint128>;
+    /// Returns the USDC equivalent for some Fury amount
+    fn usdc_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128>;
+}
+
+/// Fee oracle backed by the astro proxy contract
+pub struct ProxyFeeOracle<'a> {
+    querier: QuerierWrapper<'a>,
+    proxy_address: String,
+}
+
+impl<'a> ProxyFeeOracle<'a> {
+    pub fn new(querier: QuerierWrapper<'a>, proxy_address: String) -> Self {
+        ProxyFeeOracle {
+            querier,
+            proxy_address,
+        }
+    }
+}
+
+impl<'a> FeeOracle for ProxyFeeOracle<'a> {
+    fn fury_equivalent_to_usdc(&self, usdc_count: Uint128) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &ProxyQueryMsgs::get_fury_equivalent_to_usdc { usdc_count },
+        )
+    }
+
+    fn usdc_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &ProxyQueryMsgs::get_usdc_equivalent_to_fury { fury_count },
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{
+        from_binary, to_binary, ContractResult, Decimal, Fraction, OwnedDeps, StdError, StdResult,
+        SystemError, SystemResult, Uint128, WasmQuery,
+    };
+
+    use crate::msg::{ProxyQueryMsgs, QueryMsgSimulation};
+
+    use super::FeeOracle;
+
+    /// Fee oracle with a fixed fury price, answering proxy queries in unit tests
+    #[derive(Clone, Copy, Debug)]
+    pub struct MockFeeOracle {
+        pub fury_per_usdc: Decimal,
+        /// USDC platform fee the swap contract charges for any swap
+        pub swap_fee: Uint128,
+    }
+
+    impl Default for MockFeeOracle {
+        fn default() -> Self {
+            MockFeeOracle {
+                fury_per_usdc: Decimal::one(),
+                swap_fee: Uint128::zero(),
+            }
+        }
+    }
+
+    impl MockFeeOracle {
+        pub fn new(fury_per_usdc: Decimal) -> Self {
+            MockFeeOracle {
+                fury_per_usdc,
+                swap_fee: Uint128::zero(),
+            }
+        }
+    }
+
+    impl FeeOracle for MockFeeOracle {
+        fn fury_equivalent_to_usdc(&self, usdc_count: Uint128) -> StdResult<Uint128> {
+            Ok(usdc_count * self.fury_per_usdc)
+        }
+
+        fn usdc_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128> {
+            if self.fury_per_usdc.is_zero() {
+                return Err(StdError::generic_err("USDC has no price"));
+            }
+            Ok(fury_count.multiply_ratio(self.fury_per_usdc.denominator(), self.fury_per_usdc.numerator()))
+        }
+    }
+
+    /// Mock dependencies whose querier answers proxy price and swap fee queries from the given oracle
+    pub fn mock_dependencies_with_fee_oracle(
+        oracle: MockFeeOracle,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { msg, .. } => match from_binary(msg) {
+                Ok(ProxyQueryMsgs::get_fury_equivalent_to_usdc { usdc_count }) => {
+                    let result = oracle.fury_equivalent_to_usdc(usdc_count).and_then(|fury| to_binary(&fury));
+                    SystemResult::Ok(ContractResult::from(result))
+                }
+                Ok(ProxyQueryMsgs::get_usdc_equivalent_to_fury { fury_count }) => {
+                    let result = oracle.usdc_equivalent_to_fury(fury_count).and_then(|usdc| to_binary(&usdc));
+                    SystemResult::Ok(ContractResult::from(result))
+                }
+                Err(_) => match from_binary(msg) {
+                    Ok(QueryMsgSimulation::QueryPlatformFees { .. }) => {
+                        SystemResult::Ok(ContractResult::from(to_binary(&oracle.swap_fee)))
+                    }
+                    _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                        kind: String::from("proxy query"),
+                    }),
+                },
+            },
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: String::from("wasm query"),
+            }),
+        });
+        deps
+    }
+}
@@ -1,9 +1,8 @@
 pub use crate::error::ContractError;
 
-pub mod allowances;
 pub mod contract;
-pub mod enumerable;
 mod error;
+mod fee_oracle;
 pub mod msg;
 pub mod state;
 mod testing;
@@ -1,12 +1,9 @@
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128};
+use cosmwasm_std::{Binary, Coin, Decimal, Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use terraswap::asset::Asset;
 
-// Replace terraswap with the appropriate module for native bank token (e.g., fury)
-use fury::asset::Asset; // Make sure to replace fury with the correct module
-
-use crate::ContractError;
-use crate::state::{GameResult, SwapBalanceDetails, WalletPercentage};
+use crate::state::{GameStatus, PayoutStructure, TeamRanking, WalletPercentage};
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct InstantiateMarketingInfo {
@@ -19,13 +16,15 @@ pub struct InstantiateMarketingInfo {
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct InstantiateMsg {
     pub admin_address: String,
-    // Remove minting_contract_address and astro_proxy_address
-    // Replace UST with native bank token symbol (e.g., FURY)
-    pub usdc_ibc_symbol: String,
+    /// Bank denom of fury, e.g. ufury
+    pub fury_denom: String,
+    /// IBC denom of USDC, e.g. ibc/...
+    pub usdc_denom: String,
     pub platform_fees_collector_wallet: String,
+    pub astro_proxy_address: String,
+    pub swap_contract_address: String,
     pub transaction_fee: Uint128,
     pub platform_fee: Uint128,
-    pub game_id: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -44,24 +43,39 @@ pub enum ExecuteMsg {
         max_teams_for_pool: u32,
         max_teams_for_gamer: u32,
         wallet_percentages: Vec<WalletPercentage>,
+        payout: PayoutStructure,
+    },
+    CreateGame {
+        game_id: String,
+        lock_at: Timestamp,
+        start_at: Timestamp,
+        settle_deadline: Timestamp,
+        pool_types: Vec<String>,
+    },
+    CancelGame {
+        game_id: String,
+    },
+    LockGame {
+        game_id: String,
     },
-    CancelGame {},
-    LockGame {},
     CreatePool {
+        game_id: String,
         pool_type: String,
     },
     ClaimReward {
         gamer: String,
+        game_id: String,
     },
     ClaimRefund {
         gamer: String,
+        game_id: String,
         max_spread: Option<Decimal>,
     },
     GamePoolRewardDistribute {
         pool_id: String,
-        game_winners: Vec<GameResult>,
+        ranking: Vec<TeamRanking>,
         is_final_batch: bool,
-        usdc_for_rake: Uint128, // Replace ust_for_rake with usdc_for_rake
+        usdc_for_rake: Uint128,
         game_id: String,
     },
     GamePoolBidSubmitCommand {
@@ -94,25 +108,37 @@ pub enum QueryMsg {
         pool_type: String,
     },
     AllPoolTypeDetails {},
-    AllTeams { users: Vec<String> },
+    AllTeams { users: Vec<String>, game_id: String },
     QueryReward {
         gamer: String,
+        game_id: String,
     },
     QueryRefund {
         gamer: String,
+        game_id: String,
     },
     QueryGameResult {
         gamer: String,
         pool_id: String,
         team_id: String,
     },
-    GameDetails {},
+    GameDetails {
+        game_id: String,
+    },
+    /// Lists games in the order of their ids, optionally only those with the given status
+    Games {
+        status: Option<GameStatus>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     PoolTeamDetailsWithTeamId {
         pool_id: String,
         team_id: String,
         gamer: String,
     },
-    AllPoolsInGame {},
+    AllPoolsInGame {
+        game_id: String,
+    },
     PoolCollection {
         pool_id: String,
     },
@@ -129,28 +155,12 @@ pub enum QueryMsg {
     },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum ReceivedMsg {
-    GamePoolBidSubmit(GamePoolBidSubmitCommand),
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct GamePoolBidSubmitCommand {
-    pub gamer: String,
-    pub pool_type: String,
-    pub pool_id: String,
-    pub team_id: String,
-}
-
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub enum ProxyQueryMsgs {
     get_fury_equivalent_to_usdc {
-        // Rename from get_fury_equivalent_to_ust
         usdc_count: Uint128,
     },
     get_usdc_equivalent_to_fury {
-        // Rename from get_ust_equivalent_to_fury
         fury_count: Uint128,
     },
 }
@@ -173,13 +183,6 @@ pub enum ProxyExecuteMsg {
     },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum AssetInfo {
-    Token { contract_addr: Addr },
-    NativeToken { denom: String },
-}
-
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsgSimulation {
@@ -188,7 +191,3 @@ pub enum QueryMsgSimulation {
     QueryPlatformFees { msg: Binary },
 }
 
-#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
-pub struct BalanceResponse {
-    pub balance: Uint128,
-}
@@ -1,11 +1,10 @@
-use cosmwasm_std::{Deps, Order, StdError, StdResult, Storage, Uint128};
-
-// Replace cw20 with the appropriate module for native bank token (e.g., fury)
-use fury::query_platform_fees; 
+use cosmwasm_std::{Deps, Order, StdError, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Bound;
 
 use crate::contract::{DUMMY_WALLET, INITIAL_TEAM_POINTS, INITIAL_TEAM_RANK,
                       UNCLAIMED_REFUND, UNCLAIMED_REWARD};
-use crate::state::{CONFIG, FeeDetails, GAME_DETAILS, GAME_RESULT_DUMMY, GameDetails, GameResult, POOL_DETAILS, POOL_TEAM_DETAILS, POOL_TYPE_DETAILS, PoolDetails, PoolTeamDetails, PoolTypeDetails, SWAP_BALANCE_INFO, SwapBalanceDetails};
+use crate::execute::query_platform_fees;
+use crate::state::{CONFIG, FeeDetails, GAME_RESULT_DUMMY, GameDetails, GameResult, games, GameStatus, POOL_TEAM_DETAILS, pools, POOL_TYPE_DETAILS, PoolDetails, PoolTeamDetails, PoolTypeDetails, SWAP_BALANCE_INFO, SwapBalanceDetails};
 
 pub fn query_pool_type_details(
     storage: &dyn Storage,
@@ -45,22 +44,21 @@ pub fn query_pool_team_details(
     pool_id: String,
     user: String,
 ) -> StdResult<Vec<PoolTeamDetails>> {
-    let ptd = POOL_TEAM_DETAILS.may_load(storage, (&*pool_id, user.as_ref()))?;
+    let pool = query_pool_details(storage, pool_id.clone())?;
+    let ptd = POOL_TEAM_DETAILS.may_load(storage, (&pool.game_id, &pool_id, &user))?;
     match ptd {
         Some(ptd) => return Ok(ptd),
         None => return Err(StdError::generic_err("No team details found")),
     };
 }
 
-pub fn query_all_teams(storage: &dyn Storage, users: Vec<String>) -> StdResult<Vec<PoolTeamDetails>> {
+pub fn query_all_teams(storage: &dyn Storage, users: Vec<String>, game_id: String) -> StdResult<Vec<PoolTeamDetails>> {
     let mut all_teams = Vec::new();
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
+    let all_pools = query_all_pools_in_game(storage, game_id)?;
+    for pool in all_pools {
+        let pool_id = pool.pool_id.clone();
         for user in users.clone() {
-            let team_details = POOL_TEAM_DETAILS.load(storage, (&*pool_id.clone(), user.as_ref()));
+            let team_details = POOL_TEAM_DETAILS.load(storage, (&pool.game_id, &pool_id, &user));
             match team_details {
                 Ok(teams) => {
                     for team in teams {
@@ -76,17 +74,15 @@ pub fn query_all_teams(storage: &dyn Storage, users: Vec<String>) -> StdResult<V
     return Ok(all_teams);
 }
 
-pub fn query_reward(storage: &dyn Storage, gamer: String) -> StdResult<Uint128> {
+pub fn query_reward(storage: &dyn Storage, gamer: String, game_id: String) -> StdResult<Uint128> {
     let mut user_reward = Uint128::zero();
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
+    // Get the pools of this game
+    let all_pools = query_all_pools_in_game(storage, game_id)?;
+    for pool in all_pools {
+        let pool_id = pool.pool_id.clone();
         // Get the existing teams for this pool
         let mut teams = Vec::new();
-        let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
+        let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&pool.game_id, &pool_id, &gamer))?;
         match all_teams {
             Some(some_teams) => {
                 teams = some_teams;
@@ -102,28 +98,31 @@ pub fn query_reward(storage: &dyn Storage, gamer: String) -> StdResult<Uint128>
     return Ok(user_reward);
 }
 
-pub fn query_refund(storage: &dyn Storage, gamer: String) -> StdResult<Uint128> {
+/// A pool can be refunded once flagged by the admin, or when its game missed the settle deadline
+pub fn is_pool_refundable(storage: &dyn Storage, pool: &PoolDetails, now: Timestamp) -> StdResult<bool> {
+    if pool.pool_refund_status {
+        return Ok(true);
+    }
+    // Any distributed batch rules out a refund, or the pool would pay twice
+    if pool.pool_reward_status {
+        return Ok(false);
+    }
+    let game = games().load(storage, pool.game_id.clone())?;
+    return Ok(game.settlement_missed(now));
+}
+
+pub fn query_refund(storage: &dyn Storage, gamer: String, game_id: String, now: Timestamp) -> StdResult<Uint128> {
     let mut user_refund = Uint128::zero();
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        let mut pool_details: PoolDetails = Default::default();
-        let pd = POOL_DETAILS.load(storage, pool_id.clone());
-        match pd {
-            Ok(some) => { pool_details = some; }
-            Err(_) => {
-                continue;
-            }
-        }
-        if !pool_details.pool_refund_status {
+    // Get the pools of this game
+    let all_pools = query_all_pools_in_game(storage, game_id)?;
+    for pool_details in all_pools {
+        let pool_id = pool_details.pool_id.clone();
+        if !is_pool_refundable(storage, &pool_details, now)? {
             continue;
         }
         let ptd = POOL_TYPE_DETAILS.load(storage, pool_details.pool_type)?;
         let mut teams = Vec::new();
-        let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
+        let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&pool_details.game_id, &pool_id, &gamer))?;
         match all_teams {
             Some(some_teams) => {
                 teams = some_teams;
@@ -145,8 +144,7 @@ pub fn query_game_result(
     pool_id: String,
     team_id: String,
 ) -> StdResult<GameResult> {
-    let config = CONFIG.load(deps.storage)?;
-    let game_id = config.game_id;
+    let game_id = query_pool_details(deps.storage, pool_id.clone())?.game_id;
 
     let mut reward_amount = Uint128::zero();
     let mut refund_amount = Uint128::zero();
@@ -166,7 +164,7 @@ pub fn query_game_result(
 
     // Get the existing teams for this pool
     let mut teams = Vec::new();
-    let all_teams = POOL_TEAM_DETAILS.may_load(deps.storage, (&*pool_id.clone(), gamer.as_ref()))?;
+    let all_teams = POOL_TEAM_DETAILS.may_load(deps.storage, (&game_id, &pool_id, &gamer))?;
     match all_teams {
         Some(some_teams) => {
             teams = some_teams;
@@ -196,7 +194,7 @@ pub fn query_game_result(
 }
 
 pub fn query_pool_details(storage: &dyn Storage, pool_id: String) -> StdResult<PoolDetails> {
-    let pd = POOL_DETAILS.may_load(storage, pool_id.clone())?;
+    let pd = pools().may_load(storage, pool_id.clone())?;
     match pd {
         Some(pd) => return Ok(pd),
         None => return Err(StdError::generic_err("No pool details found")),
@@ -210,12 +208,15 @@ pub fn get_team_count_for_user_in_pool_type(
     pool_type: String,
 ) -> StdResult<u32> {
     let mut count = 0;
-    let all_pools: Vec<String> = POOL_TEAM_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        let team_details = POOL_TEAM_DETAILS.load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
+    let all_pools = query_all_pools_in_game(storage, game_id.clone())?;
+    for pool in all_pools {
+        if pool.pool_type != pool_type {
+            continue;
+        }
+        let pool_id = pool.pool_id;
+        let team_details = POOL_TEAM_DETAILS
+            .may_load(storage, (&game_id, &pool_id, &gamer))?
+            .unwrap_or_default();
         for team in team_details {
             if team.pool_type == pool_type && team.game_id == game_id && team.gamer_address == gamer && team.pool_id == pool_id
             {
@@ -227,24 +228,91 @@ pub fn get_team_count_for_user_in_pool_type(
     return Ok(count);
 }
 
-pub fn query_game_details(storage: &dyn Storage) -> StdResult<GameDetails> {
-    let config = CONFIG.load(storage)?;
-    let game_id = config.game_id;
-
-    let game_detail = GAME_DETAILS.may_load(storage, game_id)?;
+/// Game details with the status as of `now`
+pub fn query_game_details(storage: &dyn Storage, game_id: String, now: Timestamp) -> StdResult<GameDetails> {
+    let game_detail = games().may_load(storage, game_id)?;
     match game_detail {
-        Some(game_detail) => return Ok(game_detail),
+        Some(mut game_detail) => {
+            game_detail.game_status = game_detail.status_at(now);
+            return Ok(game_detail);
+        }
         None => return Err(StdError::generic_err("No Game detail found")),
     };
 }
 
+// Settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+pub fn query_games(
+    storage: &dyn Storage,
+    status: Option<GameStatus>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    now: Timestamp,
+) -> StdResult<Vec<GameDetails>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let mut games_found: Vec<GameDetails> = match status {
+        None => {
+            let start = start_after.map(Bound::exclusive);
+            games()
+                .range(storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| item.map(|(_, game)| game))
+                .collect::<StdResult<_>>()?
+        }
+        // An open game past lock_at is still stored as open, so closed games
+        // are looked up under both stored statuses and merged by game id
+        Some(GameStatus::PoolClosed) => {
+            let mut closed = query_games_stored_as(storage, GameStatus::PoolClosed, start_after.clone(), None, limit, now)?;
+            closed.extend(query_games_stored_as(storage, GameStatus::PoolOpen, start_after, Some(GameStatus::PoolClosed), limit, now)?);
+            closed.sort_by(|a, b| a.game_id.cmp(&b.game_id));
+            closed
+        }
+        Some(GameStatus::PoolOpen) => {
+            query_games_stored_as(storage, GameStatus::PoolOpen, start_after, Some(GameStatus::PoolOpen), limit, now)?
+        }
+        Some(status) => query_games_stored_as(storage, status, start_after, None, limit, now)?,
+    };
+    games_found.truncate(limit);
+    for game in games_found.iter_mut() {
+        game.game_status = game.status_at(now);
+    }
+    return Ok(games_found);
+}
+
+/// Games stored with the given status, optionally only those in `status_at` as of `now`
+fn query_games_stored_as(
+    storage: &dyn Storage,
+    stored_status: GameStatus,
+    start_after: Option<String>,
+    status_at: Option<GameStatus>,
+    limit: usize,
+    now: Timestamp,
+) -> StdResult<Vec<GameDetails>> {
+    let start = start_after.map(Bound::exclusive);
+    games()
+        .idx
+        .status
+        .prefix(stored_status.as_str().as_bytes().to_vec())
+        .range(storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, game)| game))
+        .filter(|game| match (game, status_at) {
+            (Ok(game), Some(status)) => game.status_at(now) == status,
+            _ => true,
+        })
+        .take(limit)
+        .collect()
+}
+
 pub fn query_team_details(
     storage: &dyn Storage,
     pool_id: String,
     team_id: String,
     gamer: String,
 ) -> StdResult<PoolTeamDetails> {
-    let team_details = POOL_TEAM_DETAILS.load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
+    let pool = query_pool_details(storage, pool_id.clone())?;
+    let team_details = POOL_TEAM_DETAILS.load(storage, (&pool.game_id, &pool_id, &gamer))?;
     for team in team_details {
         if team.team_id == team_id.to_string() {
             return Ok(team.clone());
@@ -253,26 +321,18 @@ pub fn query_team_details(
     return Err(StdError::generic_err("Pool Team Details not found"));
 }
 
-pub fn query_all_pools_in_game(storage: &dyn Storage) -> StdResult<Vec<PoolDetails>> {
-    let config = CONFIG.load(storage)?;
-    let game_id = config.game_id;
-
-    let mut all_pool_details = Vec::new();
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_name in all_pools {
-        let pool_details = POOL_DETAILS.load(storage, pool_name)?;
-        if pool_details.game_id == game_id {
-            all_pool_details.push(pool_details);
-        }
-    }
-    return Ok(all_pool_details);
+pub fn query_all_pools_in_game(storage: &dyn Storage, game_id: String) -> StdResult<Vec<PoolDetails>> {
+    pools()
+        .idx
+        .game
+        .prefix(game_id.as_bytes().to_vec())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, pool)| pool))
+        .collect()
 }
 
 pub fn query_pool_collection(storage: &dyn Storage, pool_id: String) -> StdResult<Uint128> {
-    let pd = POOL_DETAILS.may_load(storage, pool_id.clone())?;
+    let pd = pools().may_load(storage, pool_id.clone())?;
     let pool;
     match pd {
         Some(pd) => pool = pd,
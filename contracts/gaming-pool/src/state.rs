@@ -1,21 +1,26 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, StdError, StdResult, Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-// Replace cw20 with the appropriate module for native bank token (e.g., fury)
-use fury::AllowanceResponse;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+
+// Basis points of a whole share
+pub const HUNDRED_PERCENT: u128 = 10000u128;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub admin_address: Addr,
-    // Remove minting_contract_address and astro_proxy_address
     pub platform_fees_collector_wallet: Addr,
+    /// Proxy contract used to price pool fees in fury
+    pub astro_proxy_address: Addr,
+    /// Contract swapping between fury and the stable denom
+    pub swap_contract_address: Addr,
     pub platform_fee: Uint128,
     pub transaction_fee: Uint128,
-    pub game_id: String,
-    // Replace UST with native bank token symbol on Furya chain (e.g., USDC)
-    pub usdc_ibc_symbol: String,
+    /// Bank denom of fury, in which bids are made and rewards are paid
+    pub fury_denom: String,
+    /// IBC denom of USDC, in which pool fees and platform fees are charged
+    pub usdc_denom: String,
 }
 
 pub const CONFIG_KEY: &str = "config";
@@ -23,15 +28,75 @@ pub const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
 // This param will enable or disable the contract completely as a fail-safe
 pub const ACTIVATION_STATUS: Item<bool> = Item::new("activation_status");
 
-/// This is used for saving various vesting details
+/// Lifecycle of a game
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum GameStatus {
+    /// Pools can be created and bids submitted
+    PoolOpen,
+    /// Bidding is closed, waiting for the rewards to be distributed
+    PoolClosed,
+    /// All pools of the game are refunded
+    Cancelled,
+    /// Rewards are distributed
+    Completed,
+}
+
+impl GameStatus {
+    /// Key of the status in the status index of games
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameStatus::PoolOpen => "pool_open",
+            GameStatus::PoolClosed => "pool_closed",
+            GameStatus::Cancelled => "cancelled",
+            GameStatus::Completed => "completed",
+        }
+    }
+}
+
+impl Default for GameStatus {
+    fn default() -> Self {
+        GameStatus::PoolOpen
+    }
+}
+
+/// This is used for saving the details of a game
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct GameDetails {
     /// The game id
     pub game_id: String,
 
-    /// Current status of the game - open, close, canceled
-    pub game_status: u64,
+    /// Status of the game as last set by the admin, see `status_at`
+    pub game_status: GameStatus,
+
+    /// Bids are not accepted from this time on
+    pub lock_at: Timestamp,
+
+    /// Time at which the game starts
+    pub start_at: Timestamp,
+
+    /// If rewards are not distributed by this time, gamers can claim refunds
+    pub settle_deadline: Timestamp,
+
+    /// The pool types in which pools can be created for this game
+    pub pool_types: Vec<String>,
+}
+
+impl GameDetails {
+    /// Status of the game at the given time, as an open game closes by itself at lock_at
+    pub fn status_at(&self, now: Timestamp) -> GameStatus {
+        if self.game_status == GameStatus::PoolOpen && now >= self.lock_at {
+            GameStatus::PoolClosed
+        } else {
+            self.game_status
+        }
+    }
+
+    /// Whether the settle deadline passed without the game being completed
+    pub fn settlement_missed(&self, now: Timestamp) -> bool {
+        self.status_at(now) == GameStatus::PoolClosed && now >= self.settle_deadline
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
@@ -73,6 +138,125 @@ pub struct PoolTypeDetails {
 
     /// Rake distribution
     pub rake_list: Vec<WalletPercentage>,
+
+    /// How the prize pool is split between the ranked teams
+    pub payout: PayoutStructure,
+}
+
+/// Ranks from first_rank to last_rank each win percentage basis points
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PayoutTier {
+    pub first_rank: u32,
+    pub last_rank: u32,
+    pub percentage: u32,
+}
+
+/// Split of the prize pool between ranks, percentages are in basis points
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutStructure {
+    /// Rank 1 wins the whole prize pool
+    WinnerTakesAll {},
+    /// Rank n wins the nth percentage
+    TopN { percentages: Vec<u32> },
+    /// The top half of the teams split the prize pool equally
+    FiftyFifty {},
+    /// Ascending, non-overlapping rank ranges starting at rank 1
+    Tiered { tiers: Vec<PayoutTier> },
+}
+
+impl Default for PayoutStructure {
+    fn default() -> Self {
+        PayoutStructure::WinnerTakesAll {}
+    }
+}
+
+impl PayoutStructure {
+    /// Checks the payout covers the whole prize pool within the pool size
+    pub fn validate(&self, max_teams_for_pool: u32) -> StdResult<()> {
+        let mut paid_ranks = 1u32;
+        let mut total = 0u128;
+        match self {
+            PayoutStructure::WinnerTakesAll {} | PayoutStructure::FiftyFifty {} => {
+                total = HUNDRED_PERCENT;
+            }
+            PayoutStructure::TopN { percentages } => {
+                paid_ranks = percentages.len() as u32;
+                total = percentages.iter().map(|p| *p as u128).sum();
+            }
+            PayoutStructure::Tiered { tiers } => {
+                let mut next_rank = 1u32;
+                for tier in tiers {
+                    if tier.first_rank != next_rank || tier.last_rank < tier.first_rank {
+                        return Err(StdError::generic_err(
+                            "Payout tiers must be ascending rank ranges starting at rank 1",
+                        ));
+                    }
+                    if tier.last_rank > max_teams_for_pool {
+                        return Err(StdError::generic_err(
+                            "Payout must pay between one rank and the max teams for the pool",
+                        ));
+                    }
+                    let ranks = (tier.last_rank - tier.first_rank + 1) as u128;
+                    total += ranks * tier.percentage as u128;
+                    next_rank = tier.last_rank + 1;
+                }
+                paid_ranks = next_rank - 1;
+            }
+        }
+        if total != HUNDRED_PERCENT {
+            return Err(StdError::generic_err("Payout must add up to 100%"));
+        }
+        if paid_ranks == 0 || paid_ranks > max_teams_for_pool {
+            return Err(StdError::generic_err(
+                "Payout must pay between one rank and the max teams for the pool",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Share of the prize pool for each rank in basis points, the first entry being rank 1.
+    /// A pool with fewer teams than paid ranks shares the unpaid ranks out to the paid ones
+    pub fn rank_shares(&self, teams_in_pool: u32) -> Vec<u32> {
+        let mut shares = self.full_rank_shares(teams_in_pool);
+        let paid_ranks = teams_in_pool.max(1) as usize;
+        if shares.len() > paid_ranks {
+            shares.truncate(paid_ranks);
+            let paid_total: u128 = shares.iter().map(|share| *share as u128).sum();
+            shares = shares
+                .iter()
+                .map(|share| (*share as u128 * HUNDRED_PERCENT).checked_div(paid_total).unwrap_or(0) as u32)
+                .collect();
+            // Rank 1 takes the remainder, so the shares add up to 100%
+            let rescaled_total: u32 = shares.iter().sum();
+            shares[0] += HUNDRED_PERCENT as u32 - rescaled_total;
+        }
+        shares
+    }
+
+    fn full_rank_shares(&self, teams_in_pool: u32) -> Vec<u32> {
+        match self {
+            PayoutStructure::WinnerTakesAll {} => vec![HUNDRED_PERCENT as u32],
+            PayoutStructure::TopN { percentages } => percentages.clone(),
+            PayoutStructure::FiftyFifty {} => {
+                // Rank 1 takes the remainder, so the shares add up to 100%
+                let winners = (teams_in_pool / 2).max(1);
+                let mut shares = vec![HUNDRED_PERCENT as u32 / winners; winners as usize];
+                shares[0] += HUNDRED_PERCENT as u32 % winners;
+                shares
+            }
+            PayoutStructure::Tiered { tiers } => {
+                let mut shares = Vec::new();
+                for tier in tiers {
+                    for _ in tier.first_rank..=tier.last_rank {
+                        shares.push(tier.percentage);
+                    }
+                }
+                shares
+            }
+        }
+    }
 }
 
 /// This is used for saving various vesting details
@@ -146,6 +330,16 @@ pub struct GameResult {
     pub reward_amount: Uint128,
 }
 
+/// Final standing of a team in a pool, used to settle the pool
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct TeamRanking {
+    pub gamer_address: String,
+    pub team_id: String,
+    pub team_points: u64,
+    pub team_rank: u64,
+}
+
 /// This is used for transferring tokens to multiple wallets
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
@@ -154,26 +348,64 @@ pub struct WalletTransferDetails {
     pub amount: Uint128,
 }
 
-pub const ALLOWANCES: Map<(&Addr, &Addr), AllowanceResponse> = Map::new("allowance");
+pub struct GameIndexes<'a> {
+    /// index on the stored game status, the last element is the game id
+    pub status: MultiIndex<'a, (Vec<u8>, Vec<u8>), GameDetails>,
+}
+
+impl<'a> IndexList<GameDetails> for GameIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<GameDetails>> + '_> {
+        let v: Vec<&dyn Index<GameDetails>> = vec![&self.status];
+        Box::new(v.into_iter())
+    }
+}
 
 /// Map of games. The key is the game id and the
-/// PoolDetails will contain information about the game
-pub const GAME_DETAILS: Map<String, GameDetails> =
-    Map::new("game_details");
+/// GameDetails will contain information about the game
+pub fn games<'a>() -> IndexedMap<'a, String, GameDetails, GameIndexes<'a>> {
+    let indexes = GameIndexes {
+        status: MultiIndex::new(
+            |d: &GameDetails, pk: Vec<u8>| (d.game_status.as_str().as_bytes().to_vec(), pk),
+            "game_details",
+            "game_details__status",
+        ),
+    };
+    IndexedMap::new("game_details", indexes)
+}
 
 /// Map of pools types. The key is the pool type and the
 /// PoolTypeDetails will contain information about the pool type
 pub const POOL_TYPE_DETAILS: Map<String, PoolTypeDetails> =
     Map::new("pool_type_details");
 
+pub struct PoolIndexes<'a> {
+    /// index on game id, the last element is the pool id
+    pub game: MultiIndex<'a, (Vec<u8>, Vec<u8>), PoolDetails>,
+}
+
+impl<'a> IndexList<PoolDetails> for PoolIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<PoolDetails>> + '_> {
+        let v: Vec<&dyn Index<PoolDetails>> = vec![&self.game];
+        Box::new(v.into_iter())
+    }
+}
+
 /// Map of pools. The key is the pool id and the
 /// PoolDetails will contain information about the pool
-pub const POOL_DETAILS: Map<String, PoolDetails> =
-    Map::new("pool_details");
+pub fn pools<'a>() -> IndexedMap<'a, String, PoolDetails, PoolIndexes<'a>> {
+    let indexes = PoolIndexes {
+        game: MultiIndex::new(
+            |d: &PoolDetails, pk: Vec<u8>| (d.game_id.as_bytes().to_vec(), pk),
+            "pool_details",
+            "pool_details__game",
+        ),
+    };
+    IndexedMap::new("pool_details", indexes)
+}
 
-/// Map of pools and its gamers. The key is the pool id and the
-/// PoolBettingDetails will contain information about the betters and amount betted
-pub const POOL_TEAM_DETAILS: Map<(&str, &str), Vec<PoolTeamDetails>> =
+/// Map of pools and its gamers. The key is the game id, pool id and gamer and the
+/// PoolTeamDetails will contain information about the teams of the gamer in the pool
+pub const POOL_TEAM_DETAILS: Map<(&str, &str, &str), Vec<PoolTeamDetails>> =
     Map::new("pool_team_details");
 
 pub const CONTRACT_POOL_COUNT: Map<&Addr, Uint128> = Map::new("contract_pool_count");
@@ -184,17 +416,20 @@ pub const PLATFORM_WALLET_PERCENTAGES: Map<String, WalletPercentage> = Map::new(
 
 pub const CURRENT_REWARD_FOR_POOL: Map<String, Uint128> = Map::new("current_reward_for_pool");
 
+// Ranks of the pool paid so far, including the ranks covered by ties
+pub const PAID_RANKS_FOR_POOL: Map<String, Vec<u64>> = Map::new("paid_ranks_for_pool");
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct SwapBalanceDetails {
     pub balance_pre_swap: Uint128,
     pub balance_post_swap: Uint128,
     pub exchange_rate: Uint128,
-    pub usdc_amount_swapped: Uint128, // Replace ust_amount_swapped with usdc_amount_swapped
-    pub usdc_for_rake: Uint128,       // Replace ust_for_rake with usdc_for_rake
+    pub usdc_amount_swapped: Uint128,
+    pub usdc_for_rake: Uint128,
 }
 
-// This is a simple store we use to save the balance of the contract
-// pre-swap and use it to compute the amount of CW20 tokens gained
+// This is a simple store we use to save the fury balance of the contract
+// pre-swap and use it to compute the amount of fury gained
 // In the swap
-pub const SWAP_BALANCE_INFO: Map<String, SwapBalanceDetails> = Map::new("current_reward_for_pool");
+pub const SWAP_BALANCE_INFO: Map<String, SwapBalanceDetails> = Map::new("swap_balance_info");
@@ -2,31 +2,57 @@
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coin, Uint128};
+    use cosmwasm_std::{BankMsg, coin, CosmosMsg, DepsMut, MessageInfo, Uint128, WasmMsg};
     use cosmwasm_std::Addr;
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::testing::{mock_env, mock_info};
 
-    use crate::contract::{CLAIMED_REWARD, GAME_CANCELLED, GAME_COMPLETED, GAME_POOL_OPEN, INITIAL_REFUND_AMOUNT, instantiate};
-    use crate::execute::{cancel_game, claim_refund, claim_reward, create_pool, game_pool_bid_submit, game_pool_reward_distribute, lock_game, save_team_details, set_platform_fee_wallets, set_pool_type_params};
+    use crate::ContractError;
+    use crate::contract::{CLAIMED_REWARD, INITIAL_REFUND_AMOUNT, instantiate};
+    use crate::execute::{cancel_game, claim_refund, claim_reward, create_game, create_pool, game_pool_bid_submit, game_pool_reward_distribute, lock_game, save_team_details, set_platform_fee_wallets, set_pool_type_params};
+    use crate::fee_oracle::mock::{mock_dependencies_with_fee_oracle, MockFeeOracle};
     use crate::msg::InstantiateMsg;
-    use crate::query::{get_team_count_for_user_in_pool_type, query_game_details, query_pool_details, query_team_details};
-    use crate::state::{GameResult, PLATFORM_WALLET_PERCENTAGES, POOL_TEAM_DETAILS, WalletPercentage};
+    use crate::query::{get_team_count_for_user_in_pool_type, query_all_pools_in_game, query_game_details, query_games, query_pool_details, query_pool_type_details, query_refund, query_reward, query_team_details};
+    use crate::state::{GameStatus, PayoutStructure, PayoutTier, PLATFORM_WALLET_PERCENTAGES, POOL_TEAM_DETAILS, SWAP_BALANCE_INFO, SwapBalanceDetails, TeamRanking, WalletPercentage};
+
+    fn create_test_game(deps: DepsMut, adminInfo: MessageInfo) {
+        create_game(
+            deps,
+            mock_env(),
+            adminInfo,
+            "Game001".to_string(),
+            mock_env().block.time.plus_seconds(86400),
+            mock_env().block.time.plus_seconds(90000),
+            mock_env().block.time.plus_seconds(172800),
+            vec!["oneToOne".to_string(), "oneToTwo".to_string(), "multiple".to_string()],
+        ).unwrap();
+    }
+
+    fn record_test_swap(deps: DepsMut, poolId: String, furyBought: u128, usdcForRake: u128) {
+        // Swapped one to one, so the fury bought is also the USDC swapped
+        SWAP_BALANCE_INFO.save(deps.storage, poolId, &SwapBalanceDetails {
+            balance_pre_swap: Uint128::zero(),
+            balance_post_swap: Uint128::from(furyBought),
+            exchange_rate: Uint128::from(10000u128),
+            usdc_amount_swapped: Uint128::from(furyBought),
+            usdc_for_rake: Uint128::from(usdcForRake),
+        }).unwrap();
+    }
 
     #[test]
     fn test_create_and_query_game() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let platform_fee = Uint128::from(300000u128);
         let transaction_fee = Uint128::from(100000u128);
-        let _owner1_info = mock_info("owner001", &[coin(1000, "stake")]);
+        let _owner1_info = mock_info("owner001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let instantiate_msg = InstantiateMsg {
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
             transaction_fee: transaction_fee,
-            game_id: "Game001".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -35,12 +61,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
-        let queryRes = query_game_details(&mut deps.storage);
+        let queryRes = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match queryRes {
             Ok(gameDetail) => {
                 assert_eq!(gameDetail.game_id, "Game001".to_string());
-                assert_eq!(gameDetail.game_status, GAME_POOL_OPEN);
+                assert_eq!(gameDetail.game_status, GameStatus::PoolOpen);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
@@ -51,20 +78,20 @@ mod tests {
 
     #[test]
     fn test_create_and_query_pool_detail() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("owner001", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("owner001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
         let transaction_fee = Uint128::from(100000u128);
 
         let instantiate_msg = InstantiateMsg {
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             transaction_fee: transaction_fee,
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -73,11 +100,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
         );
         let mut poolId = String::new();
@@ -107,20 +136,20 @@ mod tests {
 
     #[test]
     fn test_save_and_query_team_detail() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("owner001", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("owner001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -129,11 +158,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
         );
         let mut poolId = String::new();
@@ -180,7 +211,7 @@ mod tests {
         match queryRes {
             Ok(poolTeamDetail) => {
                 assert_eq!(poolTeamDetail.pool_id, poolId.to_string());
-                //assert_eq!(gameDetail.game_status, GAME_POOL_OPEN);
+                //assert_eq!(gameDetail.game_status, GameStatus::PoolOpen);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
@@ -191,21 +222,21 @@ mod tests {
 
     #[test]
     fn test_game_pool_bid_submit_when_pool_team_in_range() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer001", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -214,6 +245,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
 
@@ -247,12 +279,14 @@ mod tests {
             10,
             2,
             rake_list,
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
         );
         let mut poolId = String::new();
@@ -277,7 +311,6 @@ mod tests {
             poolId.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         let queryRes = query_pool_details(&mut deps.storage, "1".to_string());
@@ -294,21 +327,21 @@ mod tests {
 
     #[test]
     fn test_game_pool_bid_submit_when_pool_team_not_in_range() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer001", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -317,6 +350,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
 
@@ -350,12 +384,14 @@ mod tests {
             1,
             1,
             rake_list,
+            PayoutStructure::WinnerTakesAll {},
         );
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
         );
         let mut poolId = String::new();
@@ -380,7 +416,6 @@ mod tests {
             poolId.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -392,7 +427,6 @@ mod tests {
             poolId.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         let queryRes = query_pool_details(&mut deps.storage, "2".to_string());
@@ -410,21 +444,21 @@ mod tests {
 
     #[test]
     fn test_crete_different_pool_type_and_add_multiple_game_for_given_user() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer001", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -433,6 +467,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
 
@@ -466,6 +501,7 @@ mod tests {
             10,
             10,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
         set_pool_type_params(
             deps.as_mut(),
@@ -477,6 +513,7 @@ mod tests {
             10,
             10,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -485,6 +522,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
         );
         match rsp_1 {
@@ -502,6 +540,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "multiple".to_string(),
         );
         match rsp_2 {
@@ -520,6 +559,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
         );
         match rsp_3 {
@@ -534,7 +574,7 @@ mod tests {
 
         let rewardInfo = mock_info("rewardinfo", &[]);
         // Adding multile team to pool_1 for Game001
-        let ownerXInfo = mock_info("gamer001", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer001", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -545,7 +585,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -557,7 +596,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -569,7 +607,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -581,7 +618,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -606,7 +642,6 @@ mod tests {
             pool_id_2.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -618,7 +653,6 @@ mod tests {
             pool_id_2.to_string(),
             "Team004".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -630,7 +664,6 @@ mod tests {
             pool_id_2.to_string(),
             "Team005".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -655,7 +688,6 @@ mod tests {
             pool_id_3.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -667,7 +699,6 @@ mod tests {
             pool_id_3.to_string(),
             "Team004".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         let query_pool_details_3 = query_pool_details(&mut deps.storage, pool_id_3.to_string());
@@ -684,21 +715,21 @@ mod tests {
 
     #[test]
     fn test_max_team_per_pool_type_for_given_user() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -707,6 +738,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -739,6 +771,7 @@ mod tests {
             10,
             2,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -747,6 +780,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -759,7 +793,7 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -770,7 +804,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -782,7 +815,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -794,7 +826,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -813,21 +844,21 @@ mod tests {
 
     #[test]
     fn test_game_pool_reward_distribute() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -836,6 +867,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -868,6 +900,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -876,6 +909,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -888,7 +922,7 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -899,7 +933,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -911,7 +944,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -923,7 +955,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -939,27 +970,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -975,14 +1009,14 @@ mod tests {
             }
         }
 
+        record_test_swap(deps.as_mut(), pool_id_1.to_string(), 1000, 0);
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-            "GAME0001".to_string(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
-            game_results,
-            true,
+            ranking,
             true,
             Uint128::zero(),
         );
@@ -995,40 +1029,40 @@ mod tests {
             }
         }
 
-        let query_game_status_res = query_game_details(&mut deps.storage);
+        let query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
                 assert_eq!(1, 2);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
-            assert_eq!(team[0].reward_amount, Uint128::from(100u128));
-            assert_eq!(team[1].reward_amount, Uint128::from(200u128));
-            assert_eq!(team[2].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[0].reward_amount, Uint128::from(500u128));
+            assert_eq!(team[1].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[2].reward_amount, Uint128::from(200u128));
         }
     }
 
     #[test]
     fn test_claim_refund() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -1037,6 +1071,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1069,6 +1104,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -1077,6 +1113,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -1089,7 +1126,7 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("cwtoken11111", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("cwtoken11111", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -1100,18 +1137,17 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
         let cancelInfo = mock_info("cancelInfo", &[]);
-        let cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
 
         /*
                 23 Mar 2022, commenting this out because call to proxy cannot be made
                 it succeeds till calculating refund amount = 444262
 
-                let claim_refund_rsp = claim_refund(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), mock_env());
+                let claim_refund_rsp = claim_refund(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), "Game001".to_string(), mock_env());
                 match claim_refund_rsp {
                     Ok(claim_refund_rsp) => {
                         let amt = claim_refund_rsp.attributes[0].value.clone();
@@ -1129,21 +1165,21 @@ mod tests {
 
     #[test]
     fn test_cancel_game() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -1152,6 +1188,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1184,6 +1221,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -1192,6 +1230,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -1204,7 +1243,7 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -1215,7 +1254,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1227,7 +1265,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1239,7 +1276,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -1255,27 +1291,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1293,7 +1332,7 @@ mod tests {
 
         let cancelInfo = mock_info("cancelInfo", &[]);
         let game_pool_reward_distribute_rsp =
-            cancel_game(deps.as_mut(), mock_env(), adminInfo.clone());
+            cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
 
         match game_pool_reward_distribute_rsp {
             Ok(game_pool_reward_distribute_rsp) => {}
@@ -1303,17 +1342,17 @@ mod tests {
             }
         }
 
-        let query_game_status_res = query_game_details(&mut deps.storage);
+        let query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_CANCELLED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Cancelled);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::zero());
             assert_eq!(team[1].reward_amount, Uint128::zero());
@@ -1323,21 +1362,21 @@ mod tests {
 
     #[test]
     fn test_claim_reward() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -1346,6 +1385,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1378,6 +1418,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -1386,6 +1427,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -1402,13 +1444,13 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let rewardInfo = mock_info("rewardinfo", &[]);
         instantiate(
@@ -1417,7 +1459,7 @@ mod tests {
             rewardInfo.clone(),
             instantiate_msg,
         );
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -1428,7 +1470,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1440,7 +1481,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1452,7 +1492,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -1468,27 +1507,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(500u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1504,16 +1546,16 @@ mod tests {
             }
         }
 
+        record_test_swap(deps.as_mut(), pool_id_1.to_string(), 1000, 0);
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
 
-            game_results,
-            true,
+            ranking,
             true,
             Uint128::zero(),
         );
@@ -1526,21 +1568,21 @@ mod tests {
             }
         }
 
-        let mut query_game_status_res = query_game_details(&mut deps.storage);
+        let mut query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(500u128));
-            assert_eq!(team[1].reward_amount, Uint128::from(200u128));
-            assert_eq!(team[2].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[1].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[2].reward_amount, Uint128::from(200u128));
         }
 
         /*
@@ -1548,7 +1590,7 @@ mod tests {
                 it succeeds till calculating reward amount = 1000
 
                 let claim_reward_rsp =
-                    claim_reward(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), mock_env());
+                    claim_reward(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), "Game001".to_string(), mock_env());
                 match claim_reward_rsp {
                     Ok(claim_reward_rsp) => {
                         //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1565,10 +1607,10 @@ mod tests {
                 }
         */
 
-        query_game_status_res = query_game_details(&mut deps.storage);
+        query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
@@ -1576,11 +1618,11 @@ mod tests {
             }
         }
 
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(500u128)); // TODO This reward should be 0 after full functionality working.
-            assert_eq!(team[1].reward_amount, Uint128::from(200u128)); // TODO This reward should be 0 after full functionality working.
-            assert_eq!(team[2].reward_amount, Uint128::from(300u128)); // TODO This reward should be 0 after full functionality working.
+            assert_eq!(team[1].reward_amount, Uint128::from(300u128)); // TODO This reward should be 0 after full functionality working.
+            assert_eq!(team[2].reward_amount, Uint128::from(200u128)); // TODO This reward should be 0 after full functionality working.
             /*
                         23 Mar 2022, commenting this out because call to proxy cannot be made
                         assert_eq!(team[0].claimed_reward, CLAIMED_REWARD);
@@ -1592,21 +1634,21 @@ mod tests {
 
     #[test]
     fn test_claim_reward_twice() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -1615,6 +1657,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1647,6 +1690,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -1655,6 +1699,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -1667,7 +1712,7 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -1678,7 +1723,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1690,7 +1734,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1702,7 +1745,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -1718,27 +1760,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1754,15 +1799,15 @@ mod tests {
             }
         }
 
+        record_test_swap(deps.as_mut(), pool_id_1.to_string(), 1000, 0);
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
-            game_results,
-            true,
+            ranking,
             true,
             Uint128::zero(),
         );
@@ -1775,21 +1820,21 @@ mod tests {
             }
         }
 
-        let mut query_game_status_res = query_game_details(&mut deps.storage);
+        let mut query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_str(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
-            assert_eq!(team[0].reward_amount, Uint128::from(100u128));
-            assert_eq!(team[1].reward_amount, Uint128::from(200u128));
-            assert_eq!(team[2].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[0].reward_amount, Uint128::from(500u128));
+            assert_eq!(team[1].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[2].reward_amount, Uint128::from(200u128));
         }
 
         /*
@@ -1797,7 +1842,7 @@ mod tests {
                 it succeeds till calculating reward amount = 600
 
                 let claim_reward_rsp =
-                    claim_reward(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), mock_env());
+                    claim_reward(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), "Game001".to_string(), mock_env());
                 match claim_reward_rsp {
                     Ok(claim_reward_rsp) => {
                         //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1813,10 +1858,10 @@ mod tests {
                     }
                 }
         */
-        query_game_status_res = query_game_details(&mut deps.storage);
+        query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
@@ -1824,11 +1869,11 @@ mod tests {
             }
         }
 
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
-            assert_eq!(team[0].reward_amount, Uint128::from(100u128)); // TODO This reward should be 0 after full functionality working.
-            assert_eq!(team[1].reward_amount, Uint128::from(200u128)); // TODO This reward should be 0 after full functionality working.
-            assert_eq!(team[2].reward_amount, Uint128::from(300u128)); // TODO This reward should be 0 after full functionality working.
+            assert_eq!(team[0].reward_amount, Uint128::from(500u128)); // TODO This reward should be 0 after full functionality working.
+            assert_eq!(team[1].reward_amount, Uint128::from(300u128)); // TODO This reward should be 0 after full functionality working.
+            assert_eq!(team[2].reward_amount, Uint128::from(200u128)); // TODO This reward should be 0 after full functionality working.
             /*
                         23 Mar 2022, commenting this out because call to proxy cannot be made
                         assert_eq!(team[0].claimed_reward, CLAIMED_REWARD);
@@ -1840,7 +1885,7 @@ mod tests {
         /*
                     23 Mar 2022, commenting this out because call to proxy cannot be made
                 let claim_reward_rsp_2 =
-                    claim_reward(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), mock_env());
+                    claim_reward(deps.as_mut(), owner1_info.clone(), "gamer002".to_string(), "Game001".to_string(), mock_env());
                 match claim_reward_rsp_2 {
                     Ok(claim_reward_rsp_2) => {
                         // IT should not come here
@@ -1860,21 +1905,21 @@ mod tests {
 
     #[test]
     fn test_refund_game_pool_close_with_team_less_than_minimum_team_count() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -1883,6 +1928,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1915,6 +1961,7 @@ mod tests {
             20,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -1923,6 +1970,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -1939,16 +1987,16 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -1959,7 +2007,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1971,7 +2018,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -1983,7 +2029,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -1999,27 +2044,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2034,7 +2082,7 @@ mod tests {
                 assert_eq!(3, 4);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         let mut teams = Vec::new();
         match team_details {
             Ok(some_teams) => {
@@ -2053,20 +2101,20 @@ mod tests {
 
     #[test]
     fn test_cancel_on_completed_game() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(144292, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(30u128);
 
         let transaction_fee = Uint128::from(10u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -2075,6 +2123,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -2107,6 +2156,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -2115,6 +2165,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -2128,7 +2179,7 @@ mod tests {
         }
         let rewardInfo = mock_info("rewardinfo", &[]);
 
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(144292, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -2139,7 +2190,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -2151,7 +2201,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -2163,7 +2212,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -2179,27 +2227,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2215,15 +2266,16 @@ mod tests {
             }
         }
 
+        record_test_swap(deps.as_mut(), pool_id_1.to_string(), 1000, 0);
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
-            game_results,
-            true, true,
+            ranking,
+            true,
             Uint128::zero(),
         );
 
@@ -2235,10 +2287,10 @@ mod tests {
             }
         }
 
-        let mut query_game_status_res = query_game_details(&mut deps.storage);
+        let mut query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
@@ -2246,7 +2298,7 @@ mod tests {
             }
         }
 
-        let game_cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let game_cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
 
         match game_cancel_rsp {
             Ok(game_cancel_rsp) => {
@@ -2264,21 +2316,21 @@ mod tests {
 
     #[test]
     fn test_reward_distribute_non_completed_game() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -2287,6 +2339,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -2319,6 +2372,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -2327,6 +2381,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -2339,35 +2394,37 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
         let mut game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
-            game_results.clone(),
-            true,
+            ranking.clone(),
             true,
             Uint128::zero(),
         );
@@ -2387,7 +2444,7 @@ mod tests {
         }
 
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -2398,7 +2455,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -2410,7 +2466,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -2422,7 +2477,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -2442,11 +2496,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
-            game_results,
-            true,
+            ranking,
             true,
             Uint128::zero(),
         );
@@ -2468,21 +2521,21 @@ mod tests {
 
     #[test]
     fn test_game_pool_reward_distribute_again() {
-        let mut deps = mock_dependencies();
-        let owner1_info = mock_info("gamer002", &[coin(1000, "stake")]);
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let owner1_info = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -2491,6 +2544,7 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_test_game(deps.as_mut(), adminInfo.clone());
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -2523,6 +2577,7 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
         );
 
         // create multiple pool
@@ -2531,6 +2586,7 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
         );
         match rsp_1 {
@@ -2547,16 +2603,16 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
         let rewardInfo = mock_info("rewardinfo", &[]);
-        let ownerXInfo = mock_info("gamer002", &[coin(1000, "stake")]);
+        let ownerXInfo = mock_info("gamer002", &[coin(444262, "ufury"), coin(10000000, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
@@ -2567,7 +2623,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -2579,7 +2634,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
         game_pool_bid_submit(
@@ -2591,7 +2645,6 @@ mod tests {
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
             None,
         );
 
@@ -2607,27 +2660,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let team_ranking_1 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team001".to_string(),
-            reward_amount: Uint128::from(100u128),
+            team_points: 300,
+            team_rank: 1,
         };
-        let game_result_2 = GameResult {
+        let team_ranking_2 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team002".to_string(),
-            reward_amount: Uint128::from(200u128),
+            team_points: 200,
+            team_rank: 2,
         };
-        let game_result_3 = GameResult {
+        let team_ranking_3 = TeamRanking {
             gamer_address: "gamer002".to_string(),
             team_id: "Team003".to_string(),
-            reward_amount: Uint128::from(300u128),
+            team_points: 100,
+            team_rank: 3,
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
-        game_results.push(game_result_1);
-        game_results.push(game_result_2);
-        game_results.push(game_result_3);
+        let mut ranking: Vec<TeamRanking> = Vec::new();
+        ranking.push(team_ranking_1);
+        ranking.push(team_ranking_2);
+        ranking.push(team_ranking_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2643,15 +2699,15 @@ mod tests {
             }
         }
 
+        record_test_swap(deps.as_mut(), pool_id_1.to_string(), 1000, 0);
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
-            game_results.clone(),
-            true,
+            ranking.clone(),
             true,
             Uint128::zero(),
         );
@@ -2664,32 +2720,31 @@ mod tests {
             }
         }
 
-        let query_game_status_res = query_game_details(&mut deps.storage);
+        let query_game_status_res = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time);
         match query_game_status_res {
             Ok(query_game_status_res) => {
-                assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
+                assert_eq!(query_game_status_res.game_status, GameStatus::Completed);
             }
             Err(e) => {
                 println!("error parsing header: {:?}", e);
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_str(), "gamer002".as_ref()));
+        let team_details = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", pool_id_1.as_str(), "gamer002"));
         for team in team_details {
-            assert_eq!(team[0].reward_amount, Uint128::from(100u128));
-            assert_eq!(team[1].reward_amount, Uint128::from(200u128));
-            assert_eq!(team[2].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[0].reward_amount, Uint128::from(500u128));
+            assert_eq!(team[1].reward_amount, Uint128::from(300u128));
+            assert_eq!(team[2].reward_amount, Uint128::from(200u128));
         }
 
         let game_pool_reward_distribute_rsp_2 = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
-                        "GAME0001".to_string(),
+                        "Game001".to_string(),
 
             pool_id_1.to_string(),
-            game_results,
-            true,
+            ranking,
             true,
             Uint128::zero(),
         );
@@ -2710,20 +2765,20 @@ mod tests {
 
     #[test]
     fn test_set_platform_fee_wallets() {
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
         let platform_fee = Uint128::from(300000u128);
 
         let transaction_fee = Uint128::from(100000u128);
         let instantiate_msg = InstantiateMsg {
             transaction_fee: transaction_fee,
 
-            minting_contract_address: "cwtoken11111".to_string(),
+            swap_contract_address: "furyswap".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "feewallet".to_string(),
             astro_proxy_address: "astroport".to_string(),
-            usdc_ibc_symbol: "uusd".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
         };
 
         let adminInfo = mock_info("admin11111", &[]);
@@ -2758,5 +2813,951 @@ mod tests {
             assert_eq!(wallet.wallet_name, "rake_3".to_string());
         }
     }
-}
+    #[test]
+    fn test_bid_escrow_and_reward_in_native_denoms() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle {
+            swap_fee: Uint128::from(100u128),
+            ..MockFeeOracle::default()
+        });
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+
+        // Fees are 5770480 uusd for this pool fee, so a smaller USDC amount is rejected
+        let lowFeeInfo = mock_info("gamer002", &[coin(144262, "ufury"), coin(5000000, "uusd")]);
+        let err = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            lowFeeInfo,
+            "gamer002".to_string(),
+            "oneToTwo".to_string(),
+            poolId.clone(),
+            "Team001".to_string(),
+            Uint128::from(144262u128),
+            None,
+        ).unwrap_err();
+        assert_eq!(err, ContractError::InsufficientFeesUsdc {});
+
+        // The fury attached must match the bid
+        let shortFuryInfo = mock_info("gamer002", &[coin(100000, "ufury"), coin(10000000, "uusd")]);
+        let err = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            shortFuryInfo,
+            "gamer002".to_string(),
+            "oneToTwo".to_string(),
+            poolId.clone(),
+            "Team001".to_string(),
+            Uint128::from(144262u128),
+            None,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Fury sent does not match the amount being bid");
+
+        // The escrowed bid is swapped into USDC along with the swap fee
+        let gamerInfo = mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]);
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            gamerInfo,
+            "gamer002".to_string(),
+            "oneToTwo".to_string(),
+            poolId.clone(),
+            "Team001".to_string(),
+            Uint128::from(144262u128),
+            None,
+        ).unwrap();
+        assert_eq!(rsp.messages.len(), 1);
+        match &rsp.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, funds, .. }) => {
+                assert_eq!(contract_addr, "furyswap");
+                assert_eq!(funds, &vec![coin(144262, "ufury"), coin(100, "uusd")]);
+            }
+            _ => panic!("Expected a swap on the swap contract"),
+        }
+
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        record_test_swap(deps.as_mut(), poolId.clone(), 500, 0);
+        game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            poolId.clone(),
+            vec![TeamRanking {
+                gamer_address: "gamer002".to_string(),
+                team_id: "Team001".to_string(),
+                team_points: 300,
+                team_rank: 1,
+            }],
+            true,
+            Uint128::zero(),
+        ).unwrap();
+
+        // Claim fees are 20000 uusd for a reward of 500 ufury
+        let claimInfo = mock_info("gamer002", &[coin(20000, "uusd")]);
+        let rsp = claim_reward(deps.as_mut(), claimInfo, "gamer002".to_string(), "Game001".to_string(), mock_env()).unwrap();
+        assert_eq!(rsp.attributes[0].value, "500".to_string());
+        assert_eq!(rsp.messages[0].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: "feewallet".to_string(),
+            amount: vec![coin(20000, "uusd")],
+        }));
+        assert_eq!(rsp.messages[1].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: "gamer002".to_string(),
+            amount: vec![coin(500, "ufury")],
+        }));
+        let team = query_team_details(&deps.storage, poolId, "Team001".to_string(), "gamer002".to_string()).unwrap();
+        assert_eq!(team.claimed_reward, CLAIMED_REWARD);
+    }
+    #[test]
+    fn test_multiple_games_in_one_instance() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+
+        let lockTime = mock_env().block.time.plus_seconds(3600);
+        let err = create_game(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("gamer002", &[]),
+            "Game002".to_string(),
+            lockTime,
+            lockTime.plus_seconds(3600),
+            lockTime.plus_seconds(86400),
+            vec!["oneToTwo".to_string()],
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "gamer002".to_string() });
+        let err = create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            lockTime,
+            lockTime.plus_seconds(3600),
+            lockTime.plus_seconds(86400),
+            vec!["oneToTwo".to_string()],
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Game already exists");
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            lockTime,
+            lockTime.plus_seconds(3600),
+            lockTime.plus_seconds(86400),
+            vec!["oneToTwo".to_string()],
+        ).unwrap();
+
+        let err = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game002".to_string(), "oneToOne".to_string()).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Pool type is not offered in this game");
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId1 = rsp.attributes[0].value.clone();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game002".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId2 = rsp.attributes[0].value.clone();
+
+        // The same gamer and team id can play in both games
+        for poolId in [poolId1.clone(), poolId2.clone()] {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]),
+                "gamer002".to_string(),
+                "oneToTwo".to_string(),
+                poolId,
+                "Team001".to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+        }
+        let teams = POOL_TEAM_DETAILS.load(&deps.storage, ("Game002", poolId2.as_str(), "gamer002")).unwrap();
+        assert_eq!(teams.len(), 1);
+        assert_eq!(teams[0].game_id, "Game002".to_string());
+        assert_eq!(get_team_count_for_user_in_pool_type(&deps.storage, "gamer002".to_string(), "Game001".to_string(), "oneToTwo".to_string()).unwrap(), 1);
+
+        // Cancelling one game leaves the pools of the other alone
+        cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        assert!(query_pool_details(&deps.storage, poolId1.clone()).unwrap().pool_refund_status);
+        assert!(!query_pool_details(&deps.storage, poolId2.clone()).unwrap().pool_refund_status);
+        assert_eq!(query_game_details(&deps.storage, "Game002".to_string(), mock_env().block.time).unwrap().game_status, GameStatus::PoolOpen);
+
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game002".to_string()).unwrap();
+        let winners = vec![TeamRanking {
+            gamer_address: "gamer002".to_string(),
+            team_id: "Team001".to_string(),
+            team_points: 300,
+            team_rank: 1,
+        }];
+        let err = game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            poolId1.clone(),
+            winners.clone(),
+            true,
+            Uint128::zero(),
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Pool does not belong to this game");
+        record_test_swap(deps.as_mut(), poolId2.clone(), 1000, 0);
+        game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            poolId2.clone(),
+            winners,
+            true,
+            Uint128::zero(),
+        ).unwrap();
+        assert_eq!(query_reward(&deps.storage, "gamer002".to_string(), "Game002".to_string()).unwrap(), Uint128::from(1000u128));
+        assert_eq!(query_reward(&deps.storage, "gamer002".to_string(), "Game001".to_string()).unwrap(), Uint128::zero());
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), mock_env().block.time).unwrap(), Uint128::from(144262u128));
+
+        let games = query_games(&deps.storage, None, None, None, mock_env().block.time).unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[1].lock_at, lockTime);
+        assert_eq!(games[1].pool_types, vec!["oneToTwo".to_string()]);
+        let games = query_games(&deps.storage, Some(GameStatus::Completed), None, None, mock_env().block.time).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].game_id, "Game002".to_string());
+        let games = query_games(&deps.storage, Some(GameStatus::Cancelled), None, None, mock_env().block.time).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].game_id, "Game001".to_string());
+        let games = query_games(&deps.storage, None, Some("Game001".to_string()), Some(1), mock_env().block.time).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].game_id, "Game002".to_string());
+        let pools = query_all_pools_in_game(&deps.storage, "Game001".to_string()).unwrap();
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[0].pool_id, poolId1);
+    }
+    #[test]
+    fn test_game_schedule_locks_bids_and_opens_refunds_after_deadline() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+
+        let now = mock_env().block.time;
+        let err = create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            now.plus_seconds(3600),
+            now.plus_seconds(7200),
+            now.plus_seconds(7200),
+            vec!["oneToTwo".to_string()],
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Game schedule must satisfy lock_at <= start_at < settle_deadline");
+        let err = create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            now,
+            now.plus_seconds(3600),
+            now.plus_seconds(7200),
+            vec!["oneToTwo".to_string()],
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Lock time must be in the future");
+
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+        let gamerInfo = mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]);
+        game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            gamerInfo.clone(),
+            "gamer002".to_string(),
+            "oneToTwo".to_string(),
+            poolId.clone(),
+            "Team001".to_string(),
+            Uint128::from(144262u128),
+            None,
+        ).unwrap();
 
+        // The game closes for bids at lock_at without the admin locking it
+        let mut lockedEnv = mock_env();
+        lockedEnv.block.time = now.plus_seconds(86400);
+        let err = game_pool_bid_submit(
+            deps.as_mut(),
+            lockedEnv.clone(),
+            gamerInfo.clone(),
+            "gamer002".to_string(),
+            "oneToTwo".to_string(),
+            poolId.clone(),
+            "Team002".to_string(),
+            Uint128::from(144262u128),
+            None,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Game is not open for bidding");
+        let game = query_game_details(&deps.storage, "Game001".to_string(), lockedEnv.block.time).unwrap();
+        assert_eq!(game.game_status, GameStatus::PoolClosed);
+        // The game is still stored as open, but is listed as closed past lock_at
+        let games = query_games(&deps.storage, Some(GameStatus::PoolClosed), None, None, lockedEnv.block.time).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].game_status, GameStatus::PoolClosed);
+        let games = query_games(&deps.storage, Some(GameStatus::PoolOpen), None, None, lockedEnv.block.time).unwrap();
+        assert_eq!(games.len(), 0);
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), lockedEnv.block.time).unwrap(), Uint128::zero());
+
+        // Once the settle deadline passes unsettled, gamers can refund themselves
+        let mut expiredEnv = mock_env();
+        expiredEnv.block.time = now.plus_seconds(172800);
+        let winners = vec![TeamRanking {
+            gamer_address: "gamer002".to_string(),
+            team_id: "Team001".to_string(),
+            team_points: 300,
+            team_rank: 1,
+        }];
+        let err = game_pool_reward_distribute(
+            deps.as_mut(),
+            expiredEnv.clone(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            poolId.clone(),
+            winners,
+            true,
+            Uint128::zero(),
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rewards can't be distributed as the settle deadline has passed");
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), expiredEnv.block.time).unwrap(), Uint128::from(144262u128));
+        let rsp = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone(), None).unwrap();
+        assert_eq!(rsp.attributes[1].value, "refund".to_string());
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), expiredEnv.block.time).unwrap(), Uint128::zero());
+    }
+    #[test]
+    fn test_refund_of_swapped_pool_after_deadline() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+        for teamId in ["Team001", "Team002"] {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]),
+                "gamer002".to_string(),
+                "oneToTwo".to_string(),
+                poolId.clone(),
+                teamId.to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        // The admin swapped the pool fees of both teams but never settled the pool
+        record_test_swap(deps.as_mut(), poolId.clone(), 250000, 38524);
+
+        // The refund pays out the fury bought and swaps the USDC left for the rake
+        let mut expiredEnv = mock_env();
+        expiredEnv.block.time = mock_env().block.time.plus_seconds(172800);
+        let rsp = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone(), None).unwrap();
+        assert_eq!(rsp.attributes[0].value, "38524".to_string());
+        assert_eq!(rsp.attributes[2].value, "250000".to_string());
+        match &rsp.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, funds, .. }) => {
+                assert_eq!(contract_addr, "furyswap");
+                assert_eq!(funds, &vec![coin(38524, "uusd")]);
+            }
+            msg => panic!("unexpected message {:?}", msg),
+        }
+        assert_eq!(rsp.messages[1].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: "gamer002".to_string(),
+            amount: vec![coin(250000, "ufury")],
+        }));
+    }
+    #[test]
+    fn test_settle_pool_from_ranking() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        let err = set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000] },
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Payout must add up to 100%");
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+        for teamId in ["Team001", "Team002", "Team003"] {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]),
+                "gamer002".to_string(),
+                "oneToTwo".to_string(),
+                poolId.clone(),
+                teamId.to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+
+        let ranked = |teamId: &str, points: u64, rank: u64| TeamRanking {
+            gamer_address: "gamer002".to_string(),
+            team_id: teamId.to_string(),
+            team_points: points,
+            team_rank: rank,
+        };
+        let settle = |deps: DepsMut, ranking: Vec<TeamRanking>, isFinalBatch: bool, usdcForRake: u128| {
+            game_pool_reward_distribute(
+                deps,
+                mock_env(),
+                mock_info("admin11111", &[]),
+                "Game001".to_string(),
+                poolId.clone(),
+                ranking,
+                isFinalBatch,
+                Uint128::from(usdcForRake),
+            )
+        };
+
+        let err = settle(deps.as_mut(), vec![ranked("Team001", 300, 1)], true, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Pool collection has not been swapped yet");
+        record_test_swap(deps.as_mut(), poolId.clone(), 1000, 50);
+        let err = settle(deps.as_mut(), vec![ranked("Team001", 300, 1)], true, 51).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rake exceeds the USDC left in the pool");
+        let err = settle(deps.as_mut(), vec![ranked("Team001", 300, 4)], true, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Team rank is out of range for this pool");
+        let err = settle(deps.as_mut(), vec![ranked("Team004", 300, 1)], true, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Team Team004 of gamer002 is not in this pool or already ranked");
+
+        // Team002 and Team003 tie on rank 2 and split the prizes of ranks 2 and 3
+        settle(deps.as_mut(), vec![ranked("Team001", 300, 1)], false, 0).unwrap();
+        let err = settle(deps.as_mut(), vec![ranked("Team001", 300, 2)], false, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Team Team001 of gamer002 is not in this pool or already ranked");
+        let err = settle(deps.as_mut(), vec![ranked("Team002", 200, 1), ranked("Team003", 100, 2)], false, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rank 1 is already paid in this pool");
+        let err = settle(deps.as_mut(), vec![ranked("Team002", 200, 2), ranked("Team003", 200, 2), ranked("Team003", 100, 3)], false, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rank 3 is already paid in this pool");
+        let rsp = settle(deps.as_mut(), vec![ranked("Team002", 200, 2), ranked("Team003", 200, 2)], true, 50).unwrap();
+        assert_eq!(rsp.messages.len(), 0);
+        let teams = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", poolId.as_str(), "gamer002")).unwrap();
+        assert_eq!(teams[0].reward_amount, Uint128::from(500u128));
+        assert_eq!(teams[0].team_points, 300);
+        assert_eq!(teams[0].team_rank, 1);
+        assert_eq!(teams[1].reward_amount, Uint128::from(250u128));
+        assert_eq!(teams[2].reward_amount, Uint128::from(250u128));
+        assert_eq!(teams[2].team_rank, 2);
+        assert_eq!(query_reward(&deps.storage, "gamer002".to_string(), "Game001".to_string()).unwrap(), Uint128::from(1000u128));
+    }
+    #[test]
+    fn test_pools_of_a_game_settle_independently() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "multiple".to_string(),
+            Uint128::from(144262u128),
+            2,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+
+        // The settled pool, the pool left unsettled and the pool refunded at lock
+        let mut poolIds: Vec<String> = Vec::new();
+        for (poolType, teamId) in [("oneToTwo", "Team001"), ("oneToTwo", "Team002"), ("multiple", "Team003")] {
+            let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), poolType.to_string()).unwrap();
+            let poolId = rsp.attributes[0].value.clone();
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]),
+                "gamer002".to_string(),
+                poolType.to_string(),
+                poolId.clone(),
+                teamId.to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+            poolIds.push(poolId);
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        let rsp = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), mock_env(), None).unwrap();
+        assert_eq!(rsp.attributes[0].value, "144262".to_string());
+
+        // Settling one pool leaves the game open for the others
+        record_test_swap(deps.as_mut(), poolIds[0].clone(), 1000, 0);
+        let rsp = game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            poolIds[0].clone(),
+            vec![TeamRanking {
+                gamer_address: "gamer002".to_string(),
+                team_id: "Team001".to_string(),
+                team_points: 300,
+                team_rank: 1,
+            }],
+            true,
+            Uint128::zero(),
+        ).unwrap();
+        assert_eq!(rsp.attributes[0].value, "GAME_NOT_COMPLETED".to_string());
+        let game = query_game_details(&deps.storage, "Game001".to_string(), mock_env().block.time).unwrap();
+        assert_eq!(game.game_status, GameStatus::PoolClosed);
+
+        // Past the deadline only the unsettled pool is refunded, the claimed one is skipped
+        let mut expiredEnv = mock_env();
+        expiredEnv.block.time = mock_env().block.time.plus_seconds(172800);
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), expiredEnv.block.time).unwrap(), Uint128::from(144262u128));
+        let rsp = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone(), None).unwrap();
+        assert_eq!(rsp.attributes[0].value, "144262".to_string());
+        let err = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone(), None).unwrap_err();
+        assert_eq!(err, ContractError::RefundAlreadyClaimed {});
+        assert_eq!(query_reward(&deps.storage, "gamer002".to_string(), "Game001".to_string()).unwrap(), Uint128::from(1000u128));
+    }
+    #[test]
+    fn test_partially_settled_pool_is_not_refunded() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+        let gamerInfo = mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]);
+        for teamId in ["Team001", "Team002", "Team003"] {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                gamerInfo.clone(),
+                "gamer002".to_string(),
+                "oneToTwo".to_string(),
+                poolId.clone(),
+                teamId.to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        record_test_swap(deps.as_mut(), poolId.clone(), 1000, 0);
+
+        let ranked = |teamId: &str, points: u64, rank: u64| TeamRanking {
+            gamer_address: "gamer002".to_string(),
+            team_id: teamId.to_string(),
+            team_points: points,
+            team_rank: rank,
+        };
+        let mut expiredEnv = mock_env();
+        expiredEnv.block.time = mock_env().block.time.plus_seconds(172800);
+        let settle = |deps: DepsMut, env, ranking: Vec<TeamRanking>, isFinalBatch: bool| {
+            game_pool_reward_distribute(
+                deps,
+                env,
+                mock_info("admin11111", &[]),
+                "Game001".to_string(),
+                poolId.clone(),
+                ranking,
+                isFinalBatch,
+                Uint128::zero(),
+            )
+        };
+        settle(deps.as_mut(), mock_env(), vec![ranked("Team001", 300, 1)], false).unwrap();
+
+        // The deadline passes with the pool half settled, the paid out rank keeps it from refunds
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), expiredEnv.block.time).unwrap(), Uint128::zero());
+        let err = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone(), None).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: No refund for this user");
+        let rsp = claim_reward(deps.as_mut(), gamerInfo.clone(), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone()).unwrap();
+        assert_eq!(rsp.attributes[0].value, "500".to_string());
+        cancel_game(deps.as_mut(), expiredEnv.clone(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        assert_eq!(query_refund(&deps.storage, "gamer002".to_string(), "Game001".to_string(), expiredEnv.block.time).unwrap(), Uint128::zero());
+
+        // The pool still finishes settling after the deadline and the cancel
+        settle(deps.as_mut(), expiredEnv.clone(), vec![ranked("Team002", 200, 2), ranked("Team003", 100, 3)], true).unwrap();
+        let rsp = claim_reward(deps.as_mut(), gamerInfo.clone(), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone()).unwrap();
+        assert_eq!(rsp.attributes[0].value, "500".to_string());
+        let err = claim_refund(deps.as_mut(), mock_info("gamer002", &[]), "gamer002".to_string(), "Game001".to_string(), expiredEnv.clone(), None).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: No refund for this user");
+    }
+    #[test]
+    fn test_short_pool_pays_out_the_whole_prize_pool() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            10,
+            5,
+            vec![],
+            PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] },
+        ).unwrap();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+        for teamId in ["Team001", "Team002"] {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]),
+                "gamer002".to_string(),
+                "oneToTwo".to_string(),
+                poolId.clone(),
+                teamId.to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        record_test_swap(deps.as_mut(), poolId.clone(), 1000, 0);
+
+        // Only two of the three paid ranks can be taken, rank 3's share goes to ranks 1 and 2
+        let ranking = vec![
+            TeamRanking {
+                gamer_address: "gamer002".to_string(),
+                team_id: "Team001".to_string(),
+                team_points: 300,
+                team_rank: 1,
+            },
+            TeamRanking {
+                gamer_address: "gamer002".to_string(),
+                team_id: "Team002".to_string(),
+                team_points: 200,
+                team_rank: 2,
+            },
+        ];
+        game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            poolId.clone(),
+            ranking,
+            true,
+            Uint128::zero(),
+        ).unwrap();
+        let teams = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", poolId.as_str(), "gamer002")).unwrap();
+        assert_eq!(teams[0].reward_amount, Uint128::from(625u128));
+        assert_eq!(teams[1].reward_amount, Uint128::from(375u128));
+        assert_eq!(query_reward(&deps.storage, "gamer002".to_string(), "Game001".to_string()).unwrap(), Uint128::from(1000u128));
+    }
+    #[test]
+    fn test_pool_type_payout_structures() {
+        let mut deps = mock_dependencies_with_fee_oracle(MockFeeOracle::default());
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            swap_contract_address: "furyswap".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "feewallet".to_string(),
+            astro_proxy_address: "astroport".to_string(),
+            fury_denom: "ufury".to_string(),
+            usdc_denom: "uusd".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+        create_test_game(deps.as_mut(), adminInfo.clone());
+
+        let tier = |firstRank: u32, lastRank: u32, percentage: u32| PayoutTier {
+            first_rank: firstRank,
+            last_rank: lastRank,
+            percentage: percentage,
+        };
+        let invalidPayouts = vec![
+            (PayoutStructure::TopN { percentages: vec![] }, "Payout must add up to 100%"),
+            (PayoutStructure::TopN { percentages: vec![2000; 5] }, "Payout must pay between one rank and the max teams for the pool"),
+            (PayoutStructure::Tiered { tiers: vec![tier(1, 1, 5000), tier(3, 4, 2500)] }, "Payout tiers must be ascending rank ranges starting at rank 1"),
+            (PayoutStructure::Tiered { tiers: vec![tier(1, 1, 4000), tier(2, 4, 2500)] }, "Payout must add up to 100%"),
+            (PayoutStructure::Tiered { tiers: vec![tier(1, 2, 2500), tier(3, 10, 625)] }, "Payout must pay between one rank and the max teams for the pool"),
+        ];
+        for (payout, msg) in invalidPayouts {
+            let err = set_pool_type_params(
+                deps.as_mut(),
+                mock_env(),
+                adminInfo.clone(),
+                "multiple".to_string(),
+                Uint128::from(144262u128),
+                2,
+                4,
+                4,
+                vec![],
+                payout,
+            ).unwrap_err();
+            assert_eq!(err.to_string(), format!("Generic error: {}", msg));
+        }
+
+        let tiered = PayoutStructure::Tiered { tiers: vec![tier(1, 1, 4000), tier(2, 4, 2000)] };
+        assert_eq!(tiered.rank_shares(4), vec![4000, 2000, 2000, 2000]);
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "multiple".to_string(),
+            Uint128::from(144262u128),
+            2,
+            4,
+            4,
+            vec![],
+            tiered.clone(),
+        ).unwrap();
+        assert_eq!(query_pool_type_details(&deps.storage, "multiple".to_string()).unwrap().payout, tiered);
+
+        // With four teams in a 50/50 pool, the top two split the prize pool
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            2,
+            4,
+            4,
+            vec![],
+            PayoutStructure::FiftyFifty {},
+        ).unwrap();
+        let rsp = create_pool(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string(), "oneToTwo".to_string()).unwrap();
+        let poolId = rsp.attributes[0].value.clone();
+        let mut ranking = Vec::new();
+        for (index, teamId) in ["Team001", "Team002", "Team003", "Team004"].iter().enumerate() {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("gamer002", &[coin(144262, "ufury"), coin(10000000, "uusd")]),
+                "gamer002".to_string(),
+                "oneToTwo".to_string(),
+                poolId.clone(),
+                teamId.to_string(),
+                Uint128::from(144262u128),
+                None,
+            ).unwrap();
+            ranking.push(TeamRanking {
+                gamer_address: "gamer002".to_string(),
+                team_id: teamId.to_string(),
+                team_points: 400 - 100 * index as u64,
+                team_rank: index as u64 + 1,
+            });
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        record_test_swap(deps.as_mut(), poolId.clone(), 1000, 0);
+        game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            poolId.clone(),
+            ranking,
+            true,
+            Uint128::zero(),
+        ).unwrap();
+        let teams = POOL_TEAM_DETAILS.load(&deps.storage, ("Game001", poolId.as_str(), "gamer002")).unwrap();
+        let rewards: Vec<Uint128> = teams.iter().map(|team| team.reward_amount).collect();
+        assert_eq!(rewards, vec![Uint128::from(500u128), Uint128::from(500u128), Uint128::zero(), Uint128::zero()]);
+
+        // Short pools share the ranks no team can take out to the paid ranks
+        assert_eq!(PayoutStructure::TopN { percentages: vec![5000, 3000, 2000] }.rank_shares(2), vec![6250, 3750]);
+        assert_eq!(tiered.rank_shares(3), vec![5000, 2500, 2500]);
+        assert_eq!(PayoutStructure::TopN { percentages: vec![3000, 3000, 4000] }.rank_shares(2), vec![5000, 5000]);
+
+        // An odd number of winners leaves a remainder that goes to rank 1
+        assert_eq!(PayoutStructure::FiftyFifty {}.rank_shares(7), vec![3334, 3333, 3333]);
+        assert_eq!(PayoutStructure::FiftyFifty {}.rank_shares(1), vec![10000]);
+    }
+}
@@ -5,6 +5,7 @@ use cosmwasm_std::{Addr, BankMsg, Binary, Coin,
                    StdError, StdResult, Storage, SubMsg, SubMsgResult,
                    Timestamp, to_binary, Uint128, Uint64, WasmMsg};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use fees::{split_fees, HUNDRED_PERCENT, NINETY_NINE_NINE_PERCENT};
 
 use terraswap::asset::{Asset, AssetInfo, PairInfo};
 use terraswap::pair::{
@@ -15,7 +16,7 @@ use terraswap::pair::ExecuteMsg as PairExecuteMsg;
 use terraswap::pair::QueryMsg::{Pair, Pool, ReverseSimulation, Simulation};
 
 use crate::error::ContractError;
-use crate::msg::{CW20Custom, ExecuteMsg, InstantiateMsg, ProxyCw20HookMsg, QueryMsg};
+use crate::msg::{CW20Custom, ExecuteMsg, FeeQuoteResponse, InstantiateMsg, ProxyCw20HookMsg, QueryMsg};
 use crate::state::{
     BONDED_REWARDS_DETAILS, BondedRewardsDetails, Config, CONFIG, CONTRACT,
     ContractVersion, SUB_MESSAGE_DETAILS, SUB_REQ_ID,
@@ -30,7 +31,9 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const FURY_PROVIDED: bool = true;
 const NO_FURY_PROVIDED: bool = false;
 
-const HUNDRED_PERCENT: u128 = 10000u128;
+const FEES_PAID_IN_FURY: bool = true;
+const FEES_PAID_IN_NATIVE: bool = false;
+
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -74,7 +77,7 @@ pub fn instantiate(
         swap_fees: msg.swap_fees,
         max_bonding_limit_per_user: msg.max_bonding_limit_per_user,
         usdc_ibc_symbol: msg.usdc_ibc_symbol,
-
+        fury_fee_discount: Uint128::zero(),
     };
     if let Some(pool_pair_addr) = msg.pool_pair_address {
         cfg.pool_pair_address = pool_pair_addr;
@@ -277,13 +280,74 @@ pub fn execute(
                 belief_price,
                 max_spread,
                 to_addr,
+                FEES_PAID_IN_NATIVE,
             )
         }
         ExecuteMsg::RewardClaim {
             receiver,
             withdrawal_amount,
-        } => claim_investment_reward(deps, env, info, receiver, withdrawal_amount),
+        } => claim_investment_reward(
+            deps,
+            env,
+            info,
+            receiver,
+            withdrawal_amount,
+            FEES_PAID_IN_NATIVE,
+        ),
+        ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount } => {
+            update_fury_fee_discount(deps, info, fury_fee_discount)
+        }
+    }
+}
+
+fn update_fury_fee_discount(
+    deps: DepsMut,
+    info: MessageInfo,
+    fury_fee_discount: Uint128,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin_address {
+        return Err(ContractError::Unauthorized {});
     }
+    if fury_fee_discount > Uint128::from(HUNDRED_PERCENT) {
+        return Err(ContractError::Std(StdError::generic_err(
+            "Fury fee discount can not be more than 100%",
+        )));
+    }
+    config.fury_fee_discount = fury_fee_discount;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_fury_fee_discount")
+        .add_attribute("fury_fee_discount", fury_fee_discount.to_string()))
+}
+
+/// Checks the fee paid in fury for msg and forwards it to the platform fees collector
+fn collect_fury_fees(deps: Deps, fury_fees: Uint128, msg: &ExecuteMsg) -> Result<Response, ContractError> {
+    let required_fury_fees = query_fury_fees(deps, to_binary(msg)?)?;
+    let adjusted_fury_fees =
+        required_fury_fees.multiply_ratio(NINETY_NINE_NINE_PERCENT, HUNDRED_PERCENT);
+    if fury_fees < adjusted_fury_fees {
+        return Err(ContractError::InsufficientFuryFees {
+            required: required_fury_fees,
+            received: fury_fees,
+        });
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let mut rsp = Response::new();
+    if !fury_fees.is_zero() {
+        let transfer_msg = Cw20ExecuteMsg::Transfer {
+            recipient: config.platform_fees_collector_wallet.into_string(),
+            amount: fury_fees,
+        };
+        rsp = rsp.add_message(WasmMsg::Execute {
+            contract_addr: config.custom_token_address.to_string(),
+            msg: to_binary(&transfer_msg)?,
+            funds: vec![],
+        });
+    }
+    return Ok(rsp
+        .add_attribute("fee_asset", "fury")
+        .add_attribute("fee_amount", fury_fees.to_string()));
 }
 
 fn configure_proxy(
@@ -358,6 +422,61 @@ fn process_received_message(
         Ok(ProxyCw20HookMsg::WithdrawLiquidity {}) => {
             withdraw_liquidity(deps, env, info, received_message)
         }
+        Ok(ProxyCw20HookMsg::PayFeesInFury { msg }) => {
+            let config = CONFIG.load(deps.storage)?;
+            if info.sender != config.custom_token_address {
+                return Err(ContractError::Unauthorized {});
+            }
+            let fees = collect_fury_fees(deps.as_ref(), received_message.amount, &msg)?;
+            let sender = deps.api.addr_validate(&received_message.sender)?;
+            let sender_info = MessageInfo {
+                sender: sender.clone(),
+                funds: vec![],
+            };
+            // Native funds can not come along with a cw20 send, so only the
+            // messages that need none can have their fees paid in fury
+            let response = match msg {
+                ExecuteMsg::Swap {
+                    offer_asset,
+                    belief_price,
+                    max_spread,
+                    to,
+                } if !offer_asset.is_native_token() => {
+                    let to_addr = if let Some(to_addr) = to {
+                        Some(deps.api.addr_validate(&to_addr)?)
+                    } else {
+                        Some(sender)
+                    };
+                    swap(
+                        deps,
+                        env,
+                        sender_info,
+                        offer_asset,
+                        belief_price,
+                        max_spread,
+                        to_addr,
+                        FEES_PAID_IN_FURY,
+                    )?
+                }
+                ExecuteMsg::RewardClaim {
+                    receiver,
+                    withdrawal_amount,
+                } => claim_investment_reward(
+                    deps,
+                    env,
+                    sender_info,
+                    receiver,
+                    withdrawal_amount,
+                    FEES_PAID_IN_FURY,
+                )?,
+                _ => {
+                    return Err(ContractError::Std(StdError::generic_err(
+                        "Fees in fury can only be paid for selling fury or claiming rewards",
+                    )));
+                }
+            };
+            Ok(response.add_submessages(fees.messages).add_attributes(fees.attributes))
+        }
         Err(err) => Err(ContractError::Std(err)),
     }
     // Ok(Response::default())
@@ -980,27 +1099,30 @@ fn claim_investment_reward(
     info: MessageInfo,
     receiver: String,
     withdrawal_amount: Uint128,
+    fees_paid_in_fury: bool,
 ) -> Result<Response, ContractError> {
     //Check if platform fees provided is sufficient
-    let required_ust_fees: Uint128;
-    required_ust_fees = query_platform_fees(
-        deps.as_ref(),
-        to_binary(&ExecuteMsg::RewardClaim {
-            receiver: receiver.clone(),
-            withdrawal_amount: withdrawal_amount.clone(),
-        })?,
-    )?;
     let mut fees = Uint128::zero();
-    for fund in info.funds.clone() {
-        if fund.denom == get_symbol(&deps)? {
-            fees = fees.checked_add(fund.amount).unwrap();
+    if !fees_paid_in_fury {
+        let required_ust_fees: Uint128;
+        required_ust_fees = query_platform_fees(
+            deps.as_ref(),
+            to_binary(&ExecuteMsg::RewardClaim {
+                receiver: receiver.clone(),
+                withdrawal_amount: withdrawal_amount.clone(),
+            })?,
+        )?;
+        for fund in info.funds.clone() {
+            if fund.denom == get_symbol(&deps)? {
+                fees = fees.checked_add(fund.amount).unwrap();
+            }
+        }
+        if fees < required_ust_fees {
+            return Err(ContractError::InsufficientFees {
+                required: required_ust_fees,
+                received: fees,
+            });
         }
-    }
-    if fees < required_ust_fees {
-        return Err(ContractError::InsufficientFees {
-            required: required_ust_fees,
-            received: fees,
-        });
     }
 
     let config = CONFIG.load(deps.storage)?;
@@ -1139,6 +1261,11 @@ fn claim_investment_reward(
         .add_attribute("action", action)
         .add_attribute("withdrawn", withdrawal_amount.clone().to_string())
         .set_data(data_msg);
+    if !fees_paid_in_fury {
+        rsp = rsp
+            .add_attribute("fee_asset", get_symbol(&deps)?)
+            .add_attribute("fee_amount", fees.to_string());
+    }
     return Ok(rsp);
 }
 
@@ -1151,6 +1278,7 @@ pub fn swap(
     belief_price: Option<Decimal>,
     max_spread: Option<Decimal>,
     to: Option<Addr>,
+    fees_paid_in_fury: bool,
 ) -> Result<Response, ContractError> {
     let config: Config = CONFIG.load(deps.storage)?;
     // Check if the swap_enable_date is passed
@@ -1161,33 +1289,40 @@ pub fn swap(
         ))));
     }
     // Swap is enabled so proceed
-    // Check if platform fees is provided
-    let required_ust_fees: Uint128;
-    required_ust_fees = query_platform_fees(
-        deps.as_ref(),
-        to_binary(&ExecuteMsg::Swap {
-            offer_asset: offer_asset.clone(),
-            belief_price: belief_price.clone(),
-            max_spread: max_spread.clone(),
-            to: Some(to.clone().unwrap().into_string()),
-        })?,
-    )?;
-    let mut fees = Uint128::zero();
-    for fund in info.funds.clone() {
-        if fund.denom == get_symbol(&deps)? {
-            fees = fees.checked_add(fund.amount).unwrap();
+    // Check if platform fees is provided, unless they were paid in fury
+    let mut required_ust_fees = Uint128::zero();
+    let mut fee_attributes = vec![];
+    if !fees_paid_in_fury {
+        required_ust_fees = query_platform_fees(
+            deps.as_ref(),
+            to_binary(&ExecuteMsg::Swap {
+                offer_asset: offer_asset.clone(),
+                belief_price: belief_price.clone(),
+                max_spread: max_spread.clone(),
+                to: Some(to.clone().unwrap().into_string()),
+            })?,
+        )?;
+        let mut fees = Uint128::zero();
+        for fund in info.funds.clone() {
+            if fund.denom == get_symbol(&deps)? {
+                fees = fees.checked_add(fund.amount).unwrap();
+            }
         }
-    }
-    if offer_asset.is_native_token() {
-        fees = fees.checked_sub(offer_asset.amount).unwrap();
-    }
-    // let native_tax = offer_asset.compute_tax(&deps.querier)?;
-    // fees = fees.checked_sub(native_tax).unwrap();
-    if fees < required_ust_fees {
-        return Err(ContractError::InsufficientFees {
-            required: required_ust_fees,
-            received: fees,
-        });
+        if offer_asset.is_native_token() {
+            fees = fees.checked_sub(offer_asset.amount).unwrap();
+        }
+        // let native_tax = offer_asset.compute_tax(&deps.querier)?;
+        // fees = fees.checked_sub(native_tax).unwrap();
+        if fees < required_ust_fees {
+            return Err(ContractError::InsufficientFees {
+                required: required_ust_fees,
+                received: fees,
+            });
+        }
+        fee_attributes = vec![
+            ("fee_asset", get_symbol(&deps)?),
+            ("fee_amount", fees.to_string()),
+        ];
     }
     //Platform fees provided is good
     //If offer asset is custom token (sell fury),
@@ -1209,7 +1344,7 @@ pub fn swap(
         }
     }
     if !offer_asset.is_native_token() {
-        return forward_swap_to_astro(
+        let resp = forward_swap_to_astro(
             deps,
             env,
             info,
@@ -1221,7 +1356,8 @@ pub fn swap(
             offer_asset.amount,
             funds_to_send,
             platform_fees,
-        );
+        )?;
+        return Ok(resp.add_attributes(fee_attributes));
     }
     //Check if assets provided are native tokens
     //offer_asset.info.check(deps.api)?;
@@ -1263,6 +1399,7 @@ pub fn swap(
 
     Ok(resp
         .add_attribute("action", "Sending swap message")
+        .add_attributes(fee_attributes)
         .set_data(data_msg))
 }
 
@@ -1414,6 +1551,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_binary(&get_fury_equivalent_to_ust(deps, ust_count)?)
         }
         QueryMsg::QueryPlatformFees { msg } => to_binary(&query_platform_fees(deps, msg)?),
+        QueryMsg::FeeQuote { msg } => to_binary(&query_fee_quote(deps, msg)?),
+        QueryMsg::QueryFuryFees { msg } => to_binary(&query_fury_fees(deps, msg)?),
     }
 }
 
@@ -1520,30 +1659,56 @@ fn get_fury_equivalent_to_ust(deps: Deps, ust_count: Uint128) -> StdResult<Uint1
 }
 
 pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
+    let quote = query_fee_quote(deps, msg)?;
+    return Ok(quote.platform_fee + quote.transaction_fee + quote.control_fee + quote.swap_fee);
+}
+
+pub fn query_fury_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
+    let required_ust_fees = query_platform_fees(deps, msg)?;
+    if required_ust_fees.is_zero() {
+        return Ok(Uint128::zero());
+    }
     let config = CONFIG.load(deps.storage)?;
-    let platform_fees_percentage;
+    let fury_fees = get_fury_equivalent_to_ust(deps, required_ust_fees)?;
+    return Ok(fury_fees - fury_fees.multiply_ratio(config.fury_fee_discount, HUNDRED_PERCENT));
+}
+
+pub fn query_fee_quote(deps: Deps, msg: Binary) -> StdResult<FeeQuoteResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let no_fees = FeeQuoteResponse {
+        platform_fee: Uint128::zero(),
+        transaction_fee: Uint128::zero(),
+        control_fee: Uint128::zero(),
+        swap_fee: Uint128::zero(),
+        denom: config.usdc_ibc_symbol.clone(),
+        fury_reference_amount: Uint128::zero(),
+        price_used: Decimal::zero(),
+    };
+    let mut swap_fees_percentage = Uint128::zero();
     let mut fury_amount_provided = Uint128::zero();
     let mut ust_amount_provided = Uint128::zero();
     match from_binary(&msg) {
         Ok(ExecuteMsg::HelloSub {}) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         },
         Ok(ExecuteMsg::Configure {
                pool_pair_address: _,
                liquidity_token: _,
                swap_opening_date: _,
            }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::Receive(_)) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
+        }
+        Ok(ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount: _ }) => {
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::ProvidePairForReward {
                assets,
                slippage_tolerance: _,
                auto_stake: _,
            }) => {
-            platform_fees_percentage = config.platform_fees + config.transaction_fees;
             for asset in assets {
                 if asset.info.is_native_token() {
                     ust_amount_provided = asset.amount;
@@ -1558,7 +1723,6 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                slippage_tolerance: _,
                auto_stake: _,
            }) => {
-            platform_fees_percentage = config.platform_fees + config.transaction_fees;
             if asset.info.is_native_token() {
                 ust_amount_provided = asset.amount;
             }
@@ -1568,7 +1732,7 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                slippage_tolerance: _,
                auto_stake: _,
            }) => {
-            return Ok(Uint128::zero());
+            return Ok(no_fees);
         }
         Ok(ExecuteMsg::Swap {
                offer_asset,
@@ -1576,8 +1740,7 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                max_spread: _,
                to: _,
            }) => {
-            platform_fees_percentage =
-                config.platform_fees + config.transaction_fees + config.swap_fees;
+            swap_fees_percentage = config.swap_fees;
             if offer_asset.info.is_native_token() {
                 ust_amount_provided = offer_asset.amount;
             }
@@ -1589,7 +1752,6 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
                receiver: _,
                withdrawal_amount,
            }) => {
-            platform_fees_percentage = config.platform_fees + config.transaction_fees;
             fury_amount_provided = withdrawal_amount;
         }
         Err(err) => {
@@ -1597,17 +1759,23 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
         }
     }
     let ust_equiv_for_fury = get_ust_equivalent_to_fury(deps, fury_amount_provided)?;
+    let mut price_used = Decimal::zero();
+    if !fury_amount_provided.is_zero() {
+        price_used = Decimal::from_ratio(ust_equiv_for_fury, fury_amount_provided);
+    }
 
-    let platform_fee = (ust_equiv_for_fury.checked_add(ust_amount_provided)?)
-        .checked_mul(platform_fees_percentage)?
-        .checked_div(Uint128::from(HUNDRED_PERCENT))?;
-    let pf_asset = Asset {
-        info: AssetInfo::NativeToken {
-            denom: String::from(config.usdc_ibc_symbol),
-        },
-        amount: platform_fee,
-    };
-
-    // let tax_on_pf = pf_asset.compute_tax(&deps.querier)?;
-    return Ok(platform_fee);
+    let fee_base = ust_equiv_for_fury.checked_add(ust_amount_provided)?;
+    let fees = split_fees(
+        fee_base,
+        &[config.platform_fees, config.transaction_fees, swap_fees_percentage],
+    )?;
+    return Ok(FeeQuoteResponse {
+        platform_fee: fees[0],
+        transaction_fee: fees[1],
+        control_fee: Uint128::zero(),
+        swap_fee: fees[2],
+        denom: config.usdc_ibc_symbol,
+        fury_reference_amount: fury_amount_provided,
+        price_used,
+    });
 }
@@ -16,4 +16,10 @@ pub enum ContractError {
         #[cfg(feature = "backtraces")]
         backtrace: Backtrace,
     },
+
+    #[error("Fees received = {received}fury whereas required = {required}fury")]
+    InsufficientFuryFees {
+        received: Uint128,
+        required: Uint128,
+    },
 }
@@ -3,3 +3,4 @@ mod error;
 pub mod msg;
 mod query;
 mod state;
+mod testing;
@@ -111,6 +111,10 @@ pub enum ExecuteMsg {
         receiver: String,
         withdrawal_amount: Uint128,
     },
+    /// Set the discount on fees paid in fury, in percentage multiplied by 100
+    UpdateFuryFeeDiscount {
+        fury_fee_discount: Uint128,
+    },
     HelloSub {},
 }
 
@@ -153,6 +157,28 @@ pub enum QueryMsg {
     QueryPlatformFees {
         msg: Binary,
     },
+    /// Returns the itemized fees charged for specific ExecuteMsg
+    FeeQuote {
+        msg: Binary,
+    },
+    /// Returns Platform Fee in fury, after the fury fee discount, for specific ExecuteMsg
+    QueryFuryFees {
+        msg: Binary,
+    },
+}
+
+/// Itemized fees for an ExecuteMsg, all amounts in `denom`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeQuoteResponse {
+    pub platform_fee: Uint128,
+    pub transaction_fee: Uint128,
+    pub control_fee: Uint128,
+    pub swap_fee: Uint128,
+    pub denom: String,
+    /// Fury amount the fees are computed on
+    pub fury_reference_amount: Uint128,
+    /// Price of one fury in `denom` from the liquidity pool
+    pub price_used: Decimal,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -176,4 +202,8 @@ pub enum ProxyCw20HookMsg {
     // },
     /// Withdrawing liquidity from the pool against the LP Tokens
     WithdrawLiquidity {},
+    /// Pays the platform fee for a Swap selling fury or a RewardClaim with the fury received
+    PayFeesInFury {
+        msg: ExecuteMsg,
+    },
 }
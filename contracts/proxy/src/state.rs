@@ -51,6 +51,9 @@ pub struct Config {
     pub swap_fees: Uint128,
     pub max_bonding_limit_per_user: u64,
     pub usdc_ibc_symbol: String,
+    ///Discount on fees paid in fury, specified in percentage multiplied by 100
+    #[serde(default)]
+    pub fury_fee_discount: Uint128,
 }
 
 // put the length bytes at the first for compatibility with legacy singleton store
@@ -0,0 +1,229 @@
+#![allow(non_snake_case)]
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Coin, ContractResult, CosmosMsg, Decimal, OwnedDeps, SystemResult, to_binary, Uint128, Uint64, WasmMsg, WasmQuery};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+
+    use terraswap::asset::{Asset, AssetInfo};
+    use terraswap::pair::PoolResponse;
+
+    use crate::contract::{execute, instantiate, query_fee_quote, query_fury_fees, query_platform_fees};
+    use crate::error::ContractError;
+    use crate::msg::{ExecuteMsg, InstantiateMsg, ProxyCw20HookMsg};
+
+    fn native_asset(amount: u128) -> Asset {
+        Asset {
+            info: AssetInfo::NativeToken { denom: "uusd".to_string() },
+            amount: Uint128::from(amount),
+        }
+    }
+
+    fn fury_asset(amount: u128) -> Asset {
+        Asset {
+            info: AssetInfo::Token { contract_addr: "fury_token".to_string() },
+            amount: Uint128::from(amount),
+        }
+    }
+
+    fn instantiate_msg() -> InstantiateMsg {
+        InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            custom_token_address: "fury_token".to_string(),
+            pair_discount_rate: 700,
+            pair_bonding_period_in_sec: 5 * 24 * 60 * 60,
+            pair_fury_reward_wallet: "pair_fury_reward_wallet".to_string(),
+            pair_lp_tokens_holder: "pair_lp_tokens_holder".to_string(),
+            native_discount_rate: 500,
+            native_bonding_period_in_sec: 7 * 24 * 60 * 60,
+            native_investment_reward_wallet: "native_investment_reward_wallet".to_string(),
+            native_investment_receive_wallet: "native_investment_receive_wallet".to_string(),
+            authorized_liquidity_provider: "authorized_liquidity_provider".to_string(),
+            swap_opening_date: Uint64::from(0u64),
+            pool_pair_address: Some("pool_pair".to_string()),
+            platform_fees_collector_wallet: "platform_fees_collector_wallet".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            swap_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10,
+            usdc_ibc_symbol: "uusd".to_string(),
+        }
+    }
+
+    fn mock_dependencies_with_pool() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        // 3 uusd for every 7 fury in the pool
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&PoolResponse {
+                    assets: [native_asset(3_000_000_000), fury_asset(7_000_000_000)],
+                    total_share: Uint128::from(1_000_000u128),
+                })
+                    .unwrap(),
+            )),
+            _ => panic!("unexpected query"),
+        });
+        deps
+    }
+
+    #[test]
+    fn test_fee_quote_buckets_add_up_to_platform_fees() {
+        let mut deps = mock_dependencies_with_pool();
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo, instantiate_msg()).unwrap();
+
+        // Fees as computed by query_platform_fees before they were itemized,
+        // rounded down once on the sum of the percentages
+        let total_fee = |fee_base: Uint128, total_percentage: u128| {
+            fee_base * Uint128::from(total_percentage) / Uint128::from(10000u128)
+        };
+        for amount in [1u128, 99, 999, 12345, 1_000_003, 987_654_321] {
+            let swap = ExecuteMsg::Swap {
+                offer_asset: native_asset(amount),
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            };
+            let quote = query_fee_quote(deps.as_ref(), to_binary(&swap).unwrap()).unwrap();
+            let sum = quote.platform_fee + quote.transaction_fee + quote.control_fee + quote.swap_fee;
+            assert_eq!(sum, total_fee(Uint128::from(amount), 100 + 30 + 50));
+            assert_eq!(query_platform_fees(deps.as_ref(), to_binary(&swap).unwrap()).unwrap(), sum);
+
+            // without a swap fee the remainder goes to the transaction fee
+            let provide = ExecuteMsg::ProvideNativeForReward {
+                asset: native_asset(amount),
+                slippage_tolerance: None,
+                auto_stake: None,
+            };
+            let quote = query_fee_quote(deps.as_ref(), to_binary(&provide).unwrap()).unwrap();
+            assert_eq!(quote.swap_fee, Uint128::zero());
+            assert_eq!(quote.platform_fee, Uint128::from(amount).multiply_ratio(100u128, 10000u128));
+            assert_eq!(quote.platform_fee + quote.transaction_fee, total_fee(Uint128::from(amount), 100 + 30));
+
+            // fury is priced through the pool before the fees are taken
+            let claim = ExecuteMsg::RewardClaim {
+                receiver: "staker001".to_string(),
+                withdrawal_amount: Uint128::from(amount),
+            };
+            let quote = query_fee_quote(deps.as_ref(), to_binary(&claim).unwrap()).unwrap();
+            assert_eq!(quote.fury_reference_amount, Uint128::from(amount));
+            let fee_base = Uint128::from(3_000_000_000u128)
+                - Uint128::from(7_000_000_000u128) * Uint128::from(3_000_000_000u128)
+                / (Uint128::from(7_000_000_000u128) + Uint128::from(amount));
+            assert_eq!(quote.price_used, Decimal::from_ratio(fee_base, amount));
+            assert_eq!(quote.platform_fee + quote.transaction_fee, total_fee(fee_base, 100 + 30));
+        }
+    }
+
+    #[test]
+    fn test_pay_fees_in_fury() {
+        let mut deps = mock_dependencies_with_pool();
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg()).unwrap();
+
+        let sell_fury = ExecuteMsg::Swap {
+            offer_asset: fury_asset(1_000_000),
+            belief_price: None,
+            max_spread: None,
+            to: None,
+        };
+        let undiscounted_fees = query_fury_fees(deps.as_ref(), to_binary(&sell_fury).unwrap()).unwrap();
+        assert!(!undiscounted_fees.is_zero());
+
+        // only the admin sets the discount
+        let discount = ExecuteMsg::UpdateFuryFeeDiscount { fury_fee_discount: Uint128::from(2000u128) };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("staker001", &[]), discount.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute(deps.as_mut(), mock_env(), adminInfo, discount).unwrap();
+        let fury_fees = query_fury_fees(deps.as_ref(), to_binary(&sell_fury).unwrap()).unwrap();
+        assert_eq!(fury_fees, undiscounted_fees - undiscounted_fees.multiply_ratio(2000u128, 10000u128));
+
+        let pay_fees = |msg: ExecuteMsg, amount: Uint128| {
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: "staker001".to_string(),
+                amount: amount,
+                msg: to_binary(&ProxyCw20HookMsg::PayFeesInFury { msg: msg }).unwrap(),
+            })
+        };
+
+        // fees are only accepted from the fury token
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_token", &[]),
+            pay_fees(sell_fury.clone(), fury_fees),
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // fees within 0.1% of the quote are accepted, anything less is not
+        let accepted_fees = fury_fees.multiply_ratio(9990u128, 10000u128);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fury_token", &[]),
+            pay_fees(sell_fury.clone(), accepted_fees),
+        ).unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fury_token", &[]),
+            pay_fees(sell_fury.clone(), accepted_fees - Uint128::from(1u128)),
+        ).unwrap_err();
+        assert_eq!(err, ContractError::InsufficientFuryFees {
+            required: fury_fees,
+            received: accepted_fees - Uint128::from(1u128),
+        });
+
+        // a native offer can not be sent along with the fury
+        let buy_fury = ExecuteMsg::Swap {
+            offer_asset: native_asset(1_000_000),
+            belief_price: None,
+            max_spread: None,
+            to: None,
+        };
+        let buy_fury_fees = query_fury_fees(deps.as_ref(), to_binary(&buy_fury).unwrap()).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fury_token", &[]),
+            pay_fees(buy_fury.clone(), buy_fury_fees),
+        ).unwrap_err();
+
+        let rsp = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fury_token", &[]),
+            pay_fees(sell_fury, fury_fees),
+        ).unwrap();
+        let fee_transfer = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "fury_token".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "platform_fees_collector_wallet".to_string(),
+                amount: fury_fees,
+            }).unwrap(),
+            funds: vec![],
+        });
+        assert!(rsp.messages.iter().any(|sub_msg| sub_msg.msg == fee_transfer));
+        // the fury being sold is still pulled from the seller
+        let sold_fury = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "fury_token".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: "staker001".to_string(),
+                recipient: mock_env().contract.address.to_string(),
+                amount: Uint128::from(1_000_000u128),
+            }).unwrap(),
+            funds: vec![],
+        });
+        assert!(rsp.messages.iter().any(|sub_msg| sub_msg.msg == sold_fury));
+        assert!(rsp.attributes.iter().any(|attr| attr.key == "fee_asset" && attr.value == "fury"));
+        assert!(rsp.attributes.iter().any(|attr| attr.key == "fee_amount" && attr.value == fury_fees.to_string()));
+
+        // fees in the stable denom are recorded with their denom
+        let ust_fees = query_platform_fees(deps.as_ref(), to_binary(&buy_fury).unwrap()).unwrap();
+        let funds = Coin::new(1_000_000u128 + ust_fees.u128(), "uusd");
+        let rsp = execute(deps.as_mut(), mock_env(), mock_info("staker001", &[funds]), buy_fury).unwrap();
+        assert!(rsp.attributes.iter().any(|attr| attr.key == "fee_asset" && attr.value == "uusd"));
+        assert!(rsp.attributes.iter().any(|attr| attr.key == "fee_amount" && attr.value == ust_fees.to_string()));
+    }
+}
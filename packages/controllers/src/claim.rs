@@ -1,9 +1,11 @@
 use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-use cosmwasm_std::{Addr, BlockInfo, Deps, StdResult, Storage, Uint128};
-use cw0::Expiration;
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, BlockInfo, Deps, Order, StdError, StdResult, Storage, Uint128};
+use cw0::{calc_range_start_string, Expiration};
+use cw_storage_plus::{Bound, Map};
 
 // TODO: pull into cw0?
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -27,6 +29,32 @@ impl Claim {
     }
 }
 
+/// Tokens that can be claimed once mature, implemented by the claims stored in ScopedClaims
+pub trait Claimable {
+    fn amount(&self) -> Uint128;
+    fn is_mature(&self, block: &BlockInfo) -> bool;
+}
+
+impl Claimable for Claim {
+    fn amount(&self) -> Uint128 {
+        self.amount
+    }
+
+    fn is_mature(&self, block: &BlockInfo) -> bool {
+        self.release_at.is_expired(block)
+    }
+}
+
+/// Errors returned from ScopedClaims
+#[derive(Error, Debug, PartialEq)]
+pub enum ClaimError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Too many pending claims, at most {max} allowed")]
+    TooManyClaims { max: u64 },
+}
+
 // TODO: revisit design (split each claim on own key?)
 pub struct Claims<'a>(Map<'a, &'a Addr, Vec<Claim>>);
 
@@ -92,4 +120,267 @@ impl<'a> Claims<'a> {
     }
 }
 
-// TODO: add test coverage
+/// Claims kept separately for each scope, e.g. the pool the tokens were unbonded from.
+/// The claims of an address in a scope are stored together under (scope, address)
+pub struct ScopedClaims<'a, T = Claim>(Map<'a, (&'a str, &'a Addr), Vec<T>>);
+
+impl<'a, T> ScopedClaims<'a, T> {
+    pub const fn new(storage_key: &'a str) -> Self {
+        ScopedClaims(Map::new(storage_key))
+    }
+}
+
+impl<'a, T> ScopedClaims<'a, T>
+where
+    T: Claimable + Serialize + DeserializeOwned + Clone,
+{
+    /// This creates a claim for the address in the scope, failing if the address already
+    /// has max_claims claims pending in the scope.
+    pub fn create_claim(
+        &self,
+        storage: &mut dyn Storage,
+        scope: &str,
+        addr: &Addr,
+        claim: T,
+        max_claims: Option<u64>,
+    ) -> Result<(), ClaimError> {
+        let mut claims = self.load_claims(storage, scope, addr)?;
+        if let Some(max) = max_claims {
+            if claims.len() as u64 >= max {
+                return Err(ClaimError::TooManyClaims { max });
+            }
+        }
+        claims.push(claim);
+        self.save_claims(storage, scope, addr, &claims)?;
+        Ok(())
+    }
+
+    /// This removes all mature claims of the address in the scope, up to an optional cap,
+    /// and returns the total amount of tokens to be released.
+    pub fn claim_tokens(
+        &self,
+        storage: &mut dyn Storage,
+        scope: &str,
+        addr: &Addr,
+        block: &BlockInfo,
+        cap: Option<Uint128>,
+    ) -> StdResult<Uint128> {
+        let claims = match self.may_load_claims(storage, scope, addr)? {
+            Some(claims) => claims,
+            None => return Ok(Uint128::zero()),
+        };
+        let mut to_send = Uint128::zero();
+        let mut waiting = Vec::new();
+        for c in claims {
+            // if mature and we can pay fully, then release it
+            let within_cap = cap.map_or(true, |limit| to_send + c.amount() <= limit);
+            if c.is_mature(block) && within_cap {
+                to_send += c.amount();
+            } else {
+                waiting.push(c);
+            }
+        }
+        if !to_send.is_zero() {
+            self.save_claims(storage, scope, addr, &waiting)?;
+        }
+        Ok(to_send)
+    }
+
+    pub fn may_load_claims(
+        &self,
+        storage: &dyn Storage,
+        scope: &str,
+        addr: &Addr,
+    ) -> StdResult<Option<Vec<T>>> {
+        self.0.may_load(storage, (scope, addr))
+    }
+
+    pub fn load_claims(&self, storage: &dyn Storage, scope: &str, addr: &Addr) -> StdResult<Vec<T>> {
+        Ok(self.may_load_claims(storage, scope, addr)?.unwrap_or_default())
+    }
+
+    /// Replaces the claims of the address in the scope, e.g. after releasing part of a claim
+    pub fn save_claims(
+        &self,
+        storage: &mut dyn Storage,
+        scope: &str,
+        addr: &Addr,
+        claims: &[T],
+    ) -> StdResult<()> {
+        self.0.save(storage, (scope, addr), &claims.to_vec())
+    }
+
+    /// Returns the addresses with claims in the scope, ordered by address
+    pub fn claimants(
+        &self,
+        storage: &dyn Storage,
+        scope: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> Vec<String> {
+        let start = calc_range_start_string(start_after).map(Bound::inclusive);
+        self.0
+            .prefix(scope)
+            .keys(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|k| String::from_utf8(k).unwrap())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    const CLAIMS: ScopedClaims<Claim> = ScopedClaims::new("claims");
+
+    fn block_at(height: u64) -> BlockInfo {
+        let mut block = mock_env().block;
+        block.height = height;
+        block
+    }
+
+    #[test]
+    fn create_claim_respects_max_claims_per_scope() {
+        let mut deps = mock_dependencies();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+
+        for amount in [10u128, 20] {
+            CLAIMS
+                .create_claim(&mut deps.storage, "pool1", &alice, Claim::new(amount, Expiration::AtHeight(10)), Some(2))
+                .unwrap();
+        }
+        let err = CLAIMS
+            .create_claim(&mut deps.storage, "pool1", &alice, Claim::new(30, Expiration::AtHeight(10)), Some(2))
+            .unwrap_err();
+        assert_eq!(err, ClaimError::TooManyClaims { max: 2 });
+
+        // the cap is per address and per scope
+        CLAIMS
+            .create_claim(&mut deps.storage, "pool1", &bob, Claim::new(30, Expiration::AtHeight(10)), Some(2))
+            .unwrap();
+        CLAIMS
+            .create_claim(&mut deps.storage, "pool2", &alice, Claim::new(30, Expiration::AtHeight(10)), Some(2))
+            .unwrap();
+        // and without a cap claims keep piling up
+        CLAIMS
+            .create_claim(&mut deps.storage, "pool1", &alice, Claim::new(30, Expiration::AtHeight(10)), None)
+            .unwrap();
+
+        let claims = CLAIMS.load_claims(&deps.storage, "pool1", &alice).unwrap();
+        assert_eq!(
+            claims.iter().map(|c| c.amount.u128()).collect::<Vec<_>>(),
+            vec![10, 20, 30]
+        );
+        assert_eq!(CLAIMS.load_claims(&deps.storage, "pool2", &alice).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn claim_tokens_releases_only_mature_claims() {
+        let mut deps = mock_dependencies();
+        let alice = Addr::unchecked("alice");
+
+        // nothing to claim without claims
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool1", &alice, &block_at(100), None)
+            .unwrap();
+        assert_eq!(amount, Uint128::zero());
+        assert_eq!(CLAIMS.may_load_claims(&deps.storage, "pool1", &alice).unwrap(), None);
+
+        for (amount, height) in [(10u128, 50u64), (20, 100), (40, 150)] {
+            CLAIMS
+                .create_claim(&mut deps.storage, "pool1", &alice, Claim::new(amount, Expiration::AtHeight(height)), None)
+                .unwrap();
+        }
+
+        // nothing has matured yet
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool1", &alice, &block_at(49), None)
+            .unwrap();
+        assert_eq!(amount, Uint128::zero());
+        assert_eq!(CLAIMS.load_claims(&deps.storage, "pool1", &alice).unwrap().len(), 3);
+
+        // a claim matures at its release height
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool1", &alice, &block_at(100), None)
+            .unwrap();
+        assert_eq!(amount, Uint128::new(30));
+        assert_eq!(
+            CLAIMS.load_claims(&deps.storage, "pool1", &alice).unwrap(),
+            vec![Claim::new(40, Expiration::AtHeight(150))]
+        );
+
+        // claims in other scopes are untouched
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool2", &alice, &block_at(200), None)
+            .unwrap();
+        assert_eq!(amount, Uint128::zero());
+    }
+
+    #[test]
+    fn claim_tokens_stays_within_cap() {
+        let mut deps = mock_dependencies();
+        let alice = Addr::unchecked("alice");
+        for amount in [10u128, 50, 20, 5] {
+            CLAIMS
+                .create_claim(&mut deps.storage, "pool1", &alice, Claim::new(amount, Expiration::AtHeight(10)), None)
+                .unwrap();
+        }
+
+        // claims that would go over the cap are skipped, later smaller ones still fit
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool1", &alice, &block_at(20), Some(Uint128::new(35)))
+            .unwrap();
+        assert_eq!(amount, Uint128::new(35));
+        assert_eq!(
+            CLAIMS.load_claims(&deps.storage, "pool1", &alice).unwrap(),
+            vec![Claim::new(50, Expiration::AtHeight(10))]
+        );
+
+        // a claim is never paid partially
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool1", &alice, &block_at(20), Some(Uint128::new(49)))
+            .unwrap();
+        assert_eq!(amount, Uint128::zero());
+        assert_eq!(CLAIMS.load_claims(&deps.storage, "pool1", &alice).unwrap().len(), 1);
+
+        // a cap equal to the claim releases it
+        let amount = CLAIMS
+            .claim_tokens(&mut deps.storage, "pool1", &alice, &block_at(20), Some(Uint128::new(50)))
+            .unwrap();
+        assert_eq!(amount, Uint128::new(50));
+        assert!(CLAIMS.load_claims(&deps.storage, "pool1", &alice).unwrap().is_empty());
+    }
+
+    #[test]
+    fn claimants_are_paginated_by_address() {
+        let mut deps = mock_dependencies();
+        for name in ["carol", "alice", "dave", "bob"] {
+            CLAIMS
+                .create_claim(&mut deps.storage, "pool1", &Addr::unchecked(name), Claim::new(10, Expiration::Never {}), None)
+                .unwrap();
+        }
+        CLAIMS
+            .create_claim(&mut deps.storage, "pool2", &Addr::unchecked("erin"), Claim::new(10, Expiration::Never {}), None)
+            .unwrap();
+
+        assert_eq!(
+            CLAIMS.claimants(&deps.storage, "pool1", None, 10),
+            vec!["alice", "bob", "carol", "dave"]
+        );
+        assert_eq!(CLAIMS.claimants(&deps.storage, "pool1", None, 2), vec!["alice", "bob"]);
+        // start_after is exclusive
+        assert_eq!(
+            CLAIMS.claimants(&deps.storage, "pool1", Some("bob".to_string()), 2),
+            vec!["carol", "dave"]
+        );
+        assert!(CLAIMS
+            .claimants(&deps.storage, "pool1", Some("dave".to_string()), 2)
+            .is_empty());
+        assert_eq!(CLAIMS.claimants(&deps.storage, "pool2", None, 10), vec!["erin"]);
+        assert!(CLAIMS.claimants(&deps.storage, "pool1", None, 0).is_empty());
+    }
+}
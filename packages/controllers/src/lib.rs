@@ -3,5 +3,5 @@ mod claim;
 mod hooks;
 
 pub use admin::{Admin, AdminError, AdminResponse};
-pub use claim::{Claim, Claims, ClaimsResponse};
+pub use claim::{Claim, ClaimError, Claimable, Claims, ClaimsResponse, ScopedClaims};
 pub use hooks::{HookError, Hooks};
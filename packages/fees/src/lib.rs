@@ -0,0 +1,54 @@
+use cosmwasm_std::{StdResult, Uint128};
+
+/// Fee percentages are expressed in basis points of this value.
+pub const HUNDRED_PERCENT: u128 = 10000u128;
+
+/// Share of a quoted fee that is accepted as full payment, so that a fee
+/// quoted just before a price update is not rejected for a rounding difference.
+pub const NINETY_NINE_NINE_PERCENT: u128 = 9990u128;
+
+/// Splits the fee on fee_base between buckets charged at the given percentages.
+/// The total is rounded down once, as it was before the fees were itemized,
+/// and the rounding remainder goes to the last charged bucket.
+pub fn split_fees(fee_base: Uint128, percentages: &[Uint128]) -> StdResult<Vec<Uint128>> {
+    let mut total_percentage = Uint128::zero();
+    for percentage in percentages {
+        total_percentage = total_percentage.checked_add(*percentage)?;
+    }
+    let total_fee = fee_base
+        .checked_mul(total_percentage)?
+        .checked_div(Uint128::from(HUNDRED_PERCENT))?;
+    let mut fees: Vec<Uint128> = percentages
+        .iter()
+        .map(|percentage| fee_base.multiply_ratio(*percentage, HUNDRED_PERCENT))
+        .collect();
+    let split_fee: Uint128 = fees.iter().sum();
+    if let Some(last) = percentages.iter().rposition(|percentage| !percentage.is_zero()) {
+        fees[last] += total_fee.checked_sub(split_fee)?;
+    }
+    Ok(fees)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remainder_goes_to_last_charged_bucket() {
+        let fees = split_fees(
+            Uint128::from(999u128),
+            &[Uint128::from(100u128), Uint128::from(200u128), Uint128::zero()],
+        )
+        .unwrap();
+        assert_eq!(
+            fees,
+            vec![Uint128::from(9u128), Uint128::from(20u128), Uint128::zero()]
+        );
+    }
+
+    #[test]
+    fn no_fees_when_nothing_is_charged() {
+        let fees = split_fees(Uint128::from(999u128), &[Uint128::zero(), Uint128::zero()]).unwrap();
+        assert_eq!(fees, vec![Uint128::zero(), Uint128::zero()]);
+    }
+}